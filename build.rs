@@ -0,0 +1,27 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use clap::CommandFactory;
+
+#[allow(dead_code)]
+#[path = "src/cli.rs"]
+mod cli;
+
+/// Renders the man pages (one per subcommand) into `$OUT_DIR/man`, and into
+/// `$JIRA_SYNC_MAN_DIR` as well when packagers set it.
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=src/cli.rs");
+    println!("cargo:rerun-if-env-changed=JIRA_SYNC_MAN_DIR");
+
+    let mut targets = vec![PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("man")];
+    if let Some(dir) = env::var_os("JIRA_SYNC_MAN_DIR") {
+        targets.push(PathBuf::from(dir));
+    }
+
+    for dir in targets {
+        fs::create_dir_all(&dir)?;
+        clap_mangen::generate_to(cli::Cli::command(), &dir)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,383 @@
+use chrono::DateTime;
+use serde_json::Value;
+
+/// Node types [`to_markdown`] converts without losing content. Anything else
+/// (extensions from Confluence macros, ...) only keeps its text.
+const KNOWN_NODES: [&str; 34] = [
+    "doc", "paragraph", "heading", "bulletList", "orderedList", "listItem", "taskList", "taskItem", "decisionList",
+    "decisionItem", "codeBlock", "blockquote", "rule", "panel", "expand", "nestedExpand", "table", "tableRow",
+    "tableHeader", "tableCell", "mediaSingle", "mediaGroup", "media", "mediaInline", "blockCard", "embedCard",
+    "inlineCard", "layoutSection", "layoutColumn", "text", "hardBreak", "mention", "emoji", "date",
+];
+
+/// Inline nodes that aren't text but may sit where blocks are expected.
+const INLINE_NODES: [&str; 8] = ["text", "hardBreak", "mention", "emoji", "date", "status", "inlineCard", "mediaInline"];
+
+/// Markdown for an ADF document (descriptions, comments, rich text fields).
+/// `render_node` gets the first say on every top-level node (used by plugins).
+pub fn to_markdown(doc: &Value, render_node: &dyn Fn(&Value) -> Option<String>) -> String {
+    let blocks: Vec<String> = children(doc)
+        .iter()
+        .map(|node| render_node(node).unwrap_or_else(|| block(node)))
+        .map(|block| block.trim_end().to_string())
+        .filter(|block| !block.trim().is_empty())
+        .collect();
+    if blocks.is_empty() {
+        return String::new();
+    }
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+/// Whether [`to_markdown`] keeps everything in `doc`: every node is of a
+/// known type and every image can be linked. Top-level nodes a plugin
+/// renders count as supported.
+pub fn is_supported(doc: &Value, render_node: &dyn Fn(&Value) -> Option<String>) -> bool {
+    children(doc).iter().all(|node| render_node(node).is_some() || node_supported(node))
+}
+
+fn node_supported(node: &Value) -> bool {
+    let known = match kind(node) {
+        "status" | "placeholder" => true,
+        "media" | "mediaInline" => !media(node).is_empty(),
+        other => KNOWN_NODES.contains(&other),
+    };
+    known && children(node).iter().all(node_supported)
+}
+
+fn kind(node: &Value) -> &str {
+    node["type"].as_str().unwrap_or_default()
+}
+
+fn children(node: &Value) -> &[Value] {
+    node["content"].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn block(node: &Value) -> String {
+    match kind(node) {
+        "paragraph" => inlines(node),
+        "heading" => {
+            let level = node["attrs"]["level"].as_u64().unwrap_or(1).clamp(1, 6) as usize;
+            format!("{} {}", "#".repeat(level), inlines(node).replace('\n', " "))
+        }
+        "bulletList" | "orderedList" | "taskList" | "decisionList" => list(node),
+        "codeBlock" => {
+            let language = node["attrs"]["language"].as_str().unwrap_or_default();
+            let code: String = children(node).iter().filter_map(|n| n["text"].as_str()).collect();
+            format!("```{}\n{}\n```", language, code.trim_end_matches('\n'))
+        }
+        "blockquote" => quote(&blocks(node, "\n\n")),
+        "rule" => "---".to_string(),
+        "panel" => {
+            let callout = match node["attrs"]["panelType"].as_str() {
+                Some("warning") => "warning",
+                Some("error") => "danger",
+                Some("success") => "success",
+                Some("note") => "note",
+                Some("tip") => "tip",
+                _ => "info",
+            };
+            format!("> [!{}]\n{}", callout, quote(&blocks(node, "\n\n")))
+        }
+        "expand" | "nestedExpand" => {
+            let title = node["attrs"]["title"].as_str().unwrap_or_default();
+            format!("> [!note]- {}\n{}", title, quote(&blocks(node, "\n\n"))).replace("- \n", "-\n")
+        }
+        "table" => table(node),
+        "mediaSingle" | "mediaGroup" => children(node).iter().map(media).filter(|m| !m.is_empty()).collect::<Vec<_>>().join("\n"),
+        "media" => media(node),
+        "blockCard" | "embedCard" => node["attrs"]["url"].as_str().map(|url| format!("<{}>", url)).unwrap_or_default(),
+        kind if INLINE_NODES.contains(&kind) => inline(node),
+        // Bilinmeyen kapsayıcılar (layoutSection, eklentiler): en azından içerikleri kalsın.
+        _ => blocks(node, "\n\n"),
+    }
+}
+
+/// The child blocks of `node`, each converted, joined by `separator`.
+fn blocks(node: &Value, separator: &str) -> String {
+    let blocks: Vec<String> = children(node).iter().map(block).filter(|b| !b.is_empty()).collect();
+    blocks.join(separator)
+}
+
+fn quote(text: &str) -> String {
+    text.lines().map(|line| if line.is_empty() { ">".to_string() } else { format!("> {}", line) }).collect::<Vec<_>>().join("\n")
+}
+
+/// A list with its items' markers in front of their first line and
+/// everything after that (more paragraphs, nested lists) indented under it.
+fn list(node: &Value) -> String {
+    let mut number = node["attrs"]["order"].as_u64().unwrap_or(1);
+    let mut lines = Vec::new();
+    for item in children(node) {
+        let (marker, checkbox) = match (kind(node), kind(item)) {
+            ("orderedList", _) => {
+                number += 1;
+                (format!("{}.", number - 1), "")
+            }
+            (_, "taskItem") if item["attrs"]["state"] == "DONE" => ("-".to_string(), "[x] "),
+            (_, "taskItem") => ("-".to_string(), "[ ] "),
+            _ => ("-".to_string(), ""),
+        };
+        // Görev ve karar maddeleri doğrudan satır içi düğüm taşır, liste maddeleri blok.
+        let body = if children(item).iter().all(|child| INLINE_NODES.contains(&kind(child))) {
+            inlines(item)
+        } else {
+            blocks(item, "\n")
+        };
+        let indent = " ".repeat(marker.len() + 1);
+        let mut body_lines = body.lines();
+        lines.push(format!("{} {}{}", marker, checkbox, body_lines.next().unwrap_or_default()).trim_end().to_string());
+        lines.extend(body_lines.map(|line| if line.is_empty() { String::new() } else { format!("{}{}", indent, line) }));
+    }
+    lines.join("\n")
+}
+
+/// A GFM table; the first row is the header whether or not Jira marked it
+/// as one. Cell blocks are joined with `<br>`, as tables can't hold newlines.
+fn table(node: &Value) -> String {
+    let rows: Vec<Vec<String>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    let text = blocks(cell, "\n");
+                    text.lines().filter(|l| !l.trim().is_empty()).collect::<Vec<_>>().join("<br>").replace('|', "\\|")
+                })
+                .collect()
+        })
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return String::new();
+    }
+    let line = |cells: &[String]| {
+        let mut cells = cells.to_vec();
+        cells.resize(width, String::new());
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut table = vec![line(&rows[0]), format!("|{}", "---|".repeat(width))];
+    table.extend(rows[1..].iter().map(|row| line(row)));
+    table.join("\n")
+}
+
+/// An embedded image or file: attachments by file name, so Obsidian finds the
+/// downloaded copy; external images by URL. Empty when neither is known.
+fn media(node: &Value) -> String {
+    let attrs = &node["attrs"];
+    match (attrs["type"].as_str(), attrs["alt"].as_str(), attrs["url"].as_str()) {
+        (Some("external"), alt, Some(url)) => format!("![{}]({})", alt.unwrap_or_default(), url),
+        (_, Some(alt), _) if !alt.is_empty() => format!("![[{}]]", alt),
+        _ => String::new(),
+    }
+}
+
+/// The inline content of `node`. Neighbouring text with the same marks is
+/// merged first, so `**a****b**` never happens.
+fn inlines(node: &Value) -> String {
+    let mut out = String::new();
+    let mut run: Option<(String, &Value)> = None;
+    for child in children(node) {
+        if kind(child) == "text" {
+            let text = child["text"].as_str().unwrap_or_default();
+            match &mut run {
+                Some((buffer, marks)) if *marks == &child["marks"] => buffer.push_str(text),
+                _ => {
+                    if let Some((buffer, marks)) = run.take() {
+                        out.push_str(&marked(&buffer, marks));
+                    }
+                    run = Some((text.to_string(), &child["marks"]));
+                }
+            }
+            continue;
+        }
+        if let Some((buffer, marks)) = run.take() {
+            out.push_str(&marked(&buffer, marks));
+        }
+        out.push_str(&inline(child));
+    }
+    if let Some((buffer, marks)) = run {
+        out.push_str(&marked(&buffer, marks));
+    }
+    out
+}
+
+fn inline(node: &Value) -> String {
+    let attrs = &node["attrs"];
+    match kind(node) {
+        "text" => marked(node["text"].as_str().unwrap_or_default(), &node["marks"]),
+        "hardBreak" => "\n".to_string(),
+        "mention" => match attrs["text"].as_str() {
+            Some(text) if !text.is_empty() => text.to_string(),
+            _ => format!("@{}", attrs["id"].as_str().unwrap_or_default()),
+        },
+        "emoji" => attrs["text"].as_str().or(attrs["shortName"].as_str()).unwrap_or_default().to_string(),
+        "date" => attrs["timestamp"]
+            .as_str()
+            .and_then(|ms| ms.parse().ok())
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        "status" => attrs["text"].as_str().map(|text| format!("`{}`", text)).unwrap_or_default(),
+        "inlineCard" => attrs["url"].as_str().map(|url| format!("<{}>", url)).unwrap_or_default(),
+        "mediaInline" => media(node),
+        _ => inlines(node),
+    }
+}
+
+/// `text` with its marks: emphasis and strikethrough hug the words (spaces
+/// stay outside the delimiters), code wins over the rest, a link goes around
+/// everything. Underline, colour and the like have no Markdown form.
+fn marked(text: &str, marks: &Value) -> String {
+    let marks = marks.as_array().map(Vec::as_slice).unwrap_or_default();
+    let has = |name: &str| marks.iter().any(|m| m["type"] == name);
+    let mut out = if has("code") {
+        format!("`{}`", text)
+    } else {
+        let core = text.trim();
+        if core.is_empty() {
+            return text.to_string();
+        }
+        let mut core = core.to_string();
+        for (mark, delimiter) in [("strike", "~~"), ("em", "*"), ("strong", "**")] {
+            if has(mark) {
+                core = format!("{}{}{}", delimiter, core, delimiter);
+            }
+        }
+        let start = text.len() - text.trim_start().len();
+        let end = text.trim_end().len();
+        format!("{}{}{}", &text[..start], core, &text[end..])
+    };
+    if let Some(href) = marks.iter().find(|m| m["type"] == "link").and_then(|m| m["attrs"]["href"].as_str()) {
+        out = format!("[{}]({})", out, href);
+    }
+    out
+}
+
+#[cfg(test)]
+fn doc(content: Value) -> Value {
+    serde_json::json!({ "type": "doc", "version": 1, "content": content })
+}
+
+#[cfg(test)]
+#[test]
+fn test_adf_paragraphs_and_marks() {
+    let none = |_: &Value| None;
+    let adf = doc(serde_json::json!([
+        { "type": "heading", "attrs": { "level": 2 }, "content": [{ "type": "text", "text": "Steps to reproduce" }] },
+        { "type": "paragraph", "content": [
+            { "type": "text", "text": "Open the " },
+            { "type": "text", "text": "login ", "marks": [{ "type": "strong" }] },
+            { "type": "text", "text": "page", "marks": [{ "type": "strong" }] },
+            { "type": "text", "text": " and run " },
+            { "type": "text", "text": "make test", "marks": [{ "type": "code" }] },
+            { "type": "text", "text": ", see " },
+            { "type": "text", "text": "the spec", "marks": [{ "type": "link", "attrs": { "href": "https://wiki/x" } }, { "type": "em" }] },
+            { "type": "hardBreak" },
+            { "type": "mention", "attrs": { "id": "5b10", "text": "@Ayşe" } },
+            { "type": "text", "text": " " },
+            { "type": "emoji", "attrs": { "shortName": ":tada:", "text": "🎉" } },
+            { "type": "text", "text": " by " },
+            { "type": "date", "attrs": { "timestamp": "1760572800000" } },
+            { "type": "text", "text": " " },
+            { "type": "status", "attrs": { "text": "IN REVIEW", "color": "blue" } }
+        ] },
+        { "type": "paragraph", "content": [{ "type": "text", "text": "old", "marks": [{ "type": "strike" }] }] },
+        { "type": "paragraph", "content": [] },
+        { "type": "rule" }
+    ]));
+    assert_eq!(
+        to_markdown(&adf, &none),
+        "## Steps to reproduce\n\n\
+         Open the **login page** and run `make test`, see [*the spec*](https://wiki/x)\n@Ayşe 🎉 by 2025-10-16 `IN REVIEW`\n\n\
+         ~~old~~\n\n---\n"
+    );
+    assert_eq!(to_markdown(&doc(serde_json::json!([])), &none), "");
+
+    let plugin = |node: &Value| (node["type"] == "rule").then(|| "***".to_string());
+    assert!(to_markdown(&adf, &plugin).ends_with("~~old~~\n\n***\n"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_adf_lists() {
+    // Jira'nın iç içe listeler için gönderdiği yapı: paragraf + alt liste aynı maddede.
+    let item = |text: &str, nested: Option<Value>| {
+        let mut content = vec![serde_json::json!({ "type": "paragraph", "content": [{ "type": "text", "text": text }] })];
+        content.extend(nested);
+        serde_json::json!({ "type": "listItem", "content": content })
+    };
+    let adf = doc(serde_json::json!([
+        { "type": "bulletList", "content": [
+            item("Backend", Some(serde_json::json!({ "type": "orderedList", "attrs": { "order": 3 }, "content": [
+                item("migrate", None),
+                item("deploy", Some(serde_json::json!({ "type": "bulletList", "content": [item("canary", None)] })))
+            ] }))),
+            item("Frontend", None)
+        ] },
+        { "type": "taskList", "attrs": { "localId": "t" }, "content": [
+            { "type": "taskItem", "attrs": { "state": "DONE" }, "content": [{ "type": "text", "text": "write tests" }] },
+            { "type": "taskItem", "attrs": { "state": "TODO" }, "content": [{ "type": "text", "text": "review" }] }
+        ] },
+        { "type": "orderedList", "content": [
+            { "type": "listItem", "content": [
+                { "type": "paragraph", "content": [{ "type": "text", "text": "first" }] },
+                { "type": "paragraph", "content": [{ "type": "text", "text": "more on first" }] }
+            ] },
+            item("second", None)
+        ] }
+    ]));
+    assert_eq!(
+        to_markdown(&adf, &|_| None),
+        "- Backend\n  3. migrate\n  4. deploy\n     - canary\n- Frontend\n\n\
+         - [x] write tests\n- [ ] review\n\n\
+         1. first\n   more on first\n2. second\n"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_adf_blocks() {
+    let paragraph = |text: &str| serde_json::json!({ "type": "paragraph", "content": [{ "type": "text", "text": text }] });
+    let cell = |kind: &str, text: &str| serde_json::json!({ "type": kind, "content": [paragraph(text)] });
+    let adf = doc(serde_json::json!([
+        { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [{ "type": "text", "text": "let x = 1;\nlet y = 2;\n" }] },
+        { "type": "blockquote", "content": [paragraph("quoted"), paragraph("twice")] },
+        { "type": "panel", "attrs": { "panelType": "error" }, "content": [paragraph("Prod is down")] },
+        { "type": "expand", "attrs": { "title": "Logs" }, "content": [paragraph("trace")] },
+        { "type": "table", "content": [
+            { "type": "tableRow", "content": [cell("tableHeader", "Env"), cell("tableHeader", "Result")] },
+            { "type": "tableRow", "content": [cell("tableCell", "prod"), { "type": "tableCell", "content": [paragraph("fails"), paragraph("a|b")] }] },
+            { "type": "tableRow", "content": [cell("tableCell", "dev")] }
+        ] },
+        { "type": "mediaSingle", "content": [{ "type": "media", "attrs": { "type": "file", "id": "abc", "alt": "screen.png" } }] },
+        { "type": "mediaSingle", "content": [{ "type": "media", "attrs": { "type": "external", "url": "https://img/x.png" } }] },
+        { "type": "blockCard", "attrs": { "url": "https://wiki/pages/1" } },
+        { "type": "text", "text": "[ek gizlendi]" }
+    ]));
+    assert_eq!(
+        to_markdown(&adf, &|_| None),
+        "```rust\nlet x = 1;\nlet y = 2;\n```\n\n\
+         > quoted\n>\n> twice\n\n\
+         > [!danger]\n> Prod is down\n\n\
+         > [!note]- Logs\n> trace\n\n\
+         | Env | Result |\n|---|---|\n| prod | fails<br>a\\|b |\n| dev |  |\n\n\
+         ![[screen.png]]\n\n![](https://img/x.png)\n\n<https://wiki/pages/1>\n\n[ek gizlendi]\n"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_adf_is_supported() {
+    let none = |_: &Value| None;
+    let simple = doc(serde_json::json!([
+        { "type": "bulletList", "content": [{ "type": "listItem", "content": [
+            { "type": "paragraph", "content": [{ "type": "text", "text": "x", "marks": [{ "type": "link", "attrs": { "href": "https://a" } }] }] }
+        ] }] }
+    ]));
+    assert!(is_supported(&simple, &none));
+    let macro_ = doc(serde_json::json!([{ "type": "bodiedExtension", "attrs": { "extensionKey": "jira-chart" }, "content": [] }]));
+    assert!(!is_supported(&macro_, &none));
+    assert!(is_supported(&macro_, &|node: &Value| (node["type"] == "bodiedExtension").then(String::new)));
+    let unnamed = doc(serde_json::json!([{ "type": "mediaSingle", "content": [{ "type": "media", "attrs": { "type": "file", "id": "abc" } }] }]));
+    assert!(!is_supported(&unnamed, &none));
+}
@@ -0,0 +1,291 @@
+//! Anonymize mode: issues are scrubbed before any note is rendered, so a vault
+//! can be screen-shared or published without leaking tracker data.
+//!
+//! - people become "Person A", "Person B", ... (stable across syncs), also
+//!   where the changelog names them
+//! - e-mail addresses are masked wherever they appear
+//! - attachments and embedded media lose their content and links
+//! - the configured `hash_fields` are replaced by a short hash, in the
+//!   changelog too
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use crate::people::USER_FIELDS;
+use crate::state::State;
+
+const PEOPLE_FILE: &str = "anonymize.json";
+const MASKED_EMAIL: &str = "***@***";
+/// User fields that identify or locate a person beyond their pseudonym.
+const PERSON_DETAILS: [&str; 4] = ["emailAddress", "avatarUrls", "self", "timeZone"];
+
+/// `[anonymize]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AnonymizeConfig {
+    pub enabled: bool,
+    /// Fields identifying customers, e.g. `customfield_10050`; their values are hashed.
+    pub hash_fields: Vec<String>,
+    /// Word in front of the pseudonym letter (default "Person").
+    pub person_label: Option<String>,
+}
+
+pub struct Anonymizer {
+    config: AnonymizeConfig,
+    email: Regex,
+    /// Pseudonym per hashed user id. Only hashes are stored, never names.
+    people: People,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct People {
+    #[serde(default)]
+    pseudonyms: BTreeMap<String, String>,
+}
+
+impl Anonymizer {
+    pub fn load(config: &AnonymizeConfig, vault_path: &str) -> Result<Anonymizer, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(PEOPLE_FILE);
+        let mut anonymizer = Anonymizer::new(config);
+        if path.exists() {
+            anonymizer.people = serde_json::from_str(&fs::read_to_string(path)?)?;
+        }
+        Ok(anonymizer)
+    }
+
+    fn new(config: &AnonymizeConfig) -> Anonymizer {
+        Anonymizer {
+            config: config.clone(),
+            email: Regex::new(r"[\w.+-]+@[\w-]+(\.[\w-]+)+").unwrap(),
+            people: People::default(),
+        }
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(PEOPLE_FILE), serde_json::to_string_pretty(&self.people)?)?;
+        Ok(())
+    }
+
+    /// Scrubs one issue as returned by Jira.
+    pub fn apply(&mut self, issue: &mut Value) {
+        // Jira'nın ürettiği HTML'deki adlar ve bağlantılar ayıklanamaz; hiç kullanılmasın.
+        if let Some(issue) = issue.as_object_mut() {
+            issue.remove("renderedFields");
+        }
+        for comment in issue["fields"]["comment"]["comments"].as_array_mut().into_iter().flatten() {
+            if let Some(comment) = comment.as_object_mut() {
+                comment.remove("renderedBody");
+            }
+        }
+        // Şu anki değeri bir kişi olan alanlar (özel kullanıcı seçiciler dahil).
+        let people_fields: Vec<String> = issue["fields"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(_, value)| {
+                let first = value.as_array().and_then(|v| v.first()).unwrap_or(value);
+                first.as_object().and_then(person_id).is_some()
+            })
+            .map(|(field, _)| field.clone())
+            .collect();
+        if let Some(fields) = issue.get_mut("fields").and_then(Value::as_object_mut) {
+            for field in &self.config.hash_fields {
+                if let Some(value) = fields.get_mut(field) {
+                    if !value.is_null() {
+                        *value = Value::String(format!("anon-{}", &hash(&value.to_string())[..8]));
+                    }
+                }
+            }
+            if let Some(attachments) = fields.get_mut("attachment").and_then(Value::as_array_mut) {
+                for attachment in attachments {
+                    if let Some(attachment) = attachment.as_object_mut() {
+                        attachment.retain(|k, _| matches!(k.as_str(), "id" | "filename" | "mimeType" | "size" | "author"));
+                    }
+                }
+            }
+        }
+        for history in issue["changelog"]["histories"].as_array_mut().into_iter().flatten() {
+            for item in history["items"].as_array_mut().into_iter().flatten() {
+                self.scrub_change(item, &people_fields);
+            }
+        }
+        self.scrub(issue);
+    }
+
+    /// Changelog items name people by bare id (`from`/`to`) and name
+    /// (`fromString`/`toString`), without a user object [`scrub`](Self::scrub)
+    /// would recognise. A field counts as a person field when it's a built-in
+    /// one, currently holds a user, or is a custom field whose ids aren't
+    /// numbers (option, version and issue ids are). `hash_fields` are hashed.
+    fn scrub_change(&mut self, item: &mut Value, people_fields: &[String]) {
+        let field = item["field"].as_str().unwrap_or_default();
+        let field_id = item["fieldId"].as_str().unwrap_or(field);
+        if self.config.hash_fields.iter().any(|f| f == field_id) {
+            for key in ["from", "fromString", "to", "toString"] {
+                if let Some(text) = item[key].as_str().filter(|t| !t.is_empty()) {
+                    item[key] = Value::String(format!("anon-{}", &hash(text)[..8]));
+                }
+            }
+            return;
+        }
+        let named_by_id = |key: &str| item[key].as_str().is_some_and(|id| !id.is_empty() && !id.chars().all(|c| c.is_ascii_digit()));
+        let person = USER_FIELDS.contains(&field)
+            || people_fields.iter().any(|f| f == field_id)
+            || (item["fieldtype"] == "custom" && (named_by_id("from") || named_by_id("to")));
+        if !person {
+            return;
+        }
+        for (id, text) in [("from", "fromString"), ("to", "toString")] {
+            match item[id].as_str().map(str::to_string) {
+                Some(real) => {
+                    item[text] = Value::String(self.pseudonym(&real));
+                    item[id] = Value::String(hash(&real)[..12].to_string());
+                }
+                None => {
+                    if let Some(name) = item[text].as_str().filter(|t| !t.is_empty()).map(str::to_string) {
+                        item[text] = Value::String(self.pseudonym(&name));
+                    }
+                }
+            }
+        }
+    }
+
+    fn scrub(&mut self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if is_media(map) {
+                    *value = serde_json::json!({ "type": "text", "text": "[ek gizlendi]" });
+                    return;
+                }
+                if let Some(id) = person_id(map) {
+                    let pseudonym = self.pseudonym(&id);
+                    for detail in PERSON_DETAILS {
+                        map.remove(detail);
+                    }
+                    for name in ["displayName", "name", "key"] {
+                        if map.contains_key(name) {
+                            map.insert(name.to_string(), Value::String(pseudonym.clone()));
+                        }
+                    }
+                    if map.contains_key("accountId") {
+                        map.insert("accountId".to_string(), Value::String(hash(&id)[..12].to_string()));
+                    }
+                }
+                if let Some(id) = mention_id(map) {
+                    let pseudonym = self.pseudonym(&id);
+                    if let Some(attrs) = map.get_mut("attrs").and_then(Value::as_object_mut) {
+                        attrs.insert("text".to_string(), Value::String(format!("@{}", pseudonym)));
+                        attrs.insert("id".to_string(), Value::String(hash(&id)[..12].to_string()));
+                    }
+                }
+                for child in map.values_mut() {
+                    self.scrub(child);
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.scrub(item)),
+            Value::String(text) if self.email.is_match(text) => {
+                *text = self.email.replace_all(text, MASKED_EMAIL).into_owned();
+            }
+            _ => {}
+        }
+    }
+
+    fn pseudonym(&mut self, id: &str) -> String {
+        let next = letters(self.people.pseudonyms.len());
+        let letter = self.people.pseudonyms.entry(hash(id)).or_insert(next).clone();
+        format!("{} {}", self.config.person_label.as_deref().unwrap_or("Person"), letter)
+    }
+}
+
+/// A Jira user object: has a display name and some id.
+fn person_id(map: &Map<String, Value>) -> Option<String> {
+    if !map.contains_key("displayName") {
+        return None;
+    }
+    ["accountId", "key", "name"].iter().find_map(|k| map.get(*k).and_then(Value::as_str)).map(str::to_string)
+}
+
+fn mention_id(map: &Map<String, Value>) -> Option<String> {
+    if map.get("type").and_then(Value::as_str) != Some("mention") {
+        return None;
+    }
+    map.get("attrs")?.get("id")?.as_str().map(str::to_string)
+}
+
+fn is_media(map: &Map<String, Value>) -> bool {
+    matches!(map.get("type").and_then(Value::as_str), Some("mediaSingle" | "mediaGroup" | "media" | "mediaInline"))
+}
+
+fn hash(value: &str) -> String {
+    Sha256::digest(value.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 0 -> A, 25 -> Z, 26 -> AA, ...
+fn letters(mut n: usize) -> String {
+    let mut out = Vec::new();
+    loop {
+        out.push(b'A' + (n % 26) as u8);
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
+
+#[cfg(test)]
+#[test]
+fn test_anonymize_issue() {
+    let config = AnonymizeConfig { enabled: true, hash_fields: vec!["customfield_1".into()], person_label: None };
+    let mut anonymizer = Anonymizer::new(&config);
+    let mut issue = serde_json::json!({
+        "key": "CS-1",
+        "fields": {
+            "summary": "Call back jane.doe@acme.com",
+            "assignee": { "accountId": "abc", "displayName": "Ali Veli", "emailAddress": "ali@corp.io" },
+            "reporter": { "accountId": "def", "displayName": "Jane Doe" },
+            "customfield_1": "ACME Corp",
+            "attachment": [{ "id": "1", "filename": "log.txt", "content": "https://x/secure/1" }],
+            "description": { "type": "doc", "content": [
+                { "type": "paragraph", "content": [{ "type": "mention", "attrs": { "id": "abc", "text": "@Ali Veli" } }] },
+                { "type": "mediaSingle", "content": [] }
+            ]}
+        }
+    });
+    anonymizer.apply(&mut issue);
+    let fields = &issue["fields"];
+
+    assert_eq!(fields["summary"], "Call back ***@***");
+    assert_eq!(fields["assignee"]["displayName"], "Person A");
+    assert!(fields["assignee"].get("emailAddress").is_none());
+    assert_eq!(fields["reporter"]["displayName"], "Person B");
+    assert_eq!(fields["description"]["content"][0]["content"][0]["attrs"]["text"], "@Person A");
+    assert_eq!(fields["description"]["content"][1]["text"], "[ek gizlendi]");
+    assert!(fields["customfield_1"].as_str().unwrap().starts_with("anon-"));
+    assert!(fields["attachment"][0].get("content").is_none());
+    assert_eq!(letters(26), "AA");
+
+    let mut changed = serde_json::json!({ "key": "CS-2", "changelog": { "histories": [{ "items": [
+        { "field": "assignee", "from": "def", "fromString": "Jane Doe", "to": "abc", "toString": "Ali Veli" },
+        { "field": "status", "from": "1", "fromString": "To Do", "to": "3", "toString": "Done" },
+        { "field": "Reviewer", "fieldtype": "custom", "fieldId": "customfield_2", "from": null, "fromString": null, "to": "def", "toString": "Jane Doe" },
+        { "field": "Customer", "fieldtype": "custom", "fieldId": "customfield_1", "from": null, "fromString": "Globex", "to": null, "toString": "ACME Corp" },
+        { "field": "Severity", "fieldtype": "custom", "fieldId": "customfield_3", "from": "10001", "fromString": "Minor", "to": "10002", "toString": "Major" }
+    ] }] } });
+    anonymizer.apply(&mut changed);
+    let items = &changed["changelog"]["histories"][0]["items"];
+    assert_eq!((items[0]["fromString"].as_str(), items[0]["toString"].as_str()), (Some("Person B"), Some("Person A")));
+    assert_eq!(items[0]["to"], fields["assignee"]["accountId"]);
+    assert_eq!(items[1]["toString"], "Done");
+    assert_eq!(items[2]["toString"], "Person B");
+    assert!(items[3]["fromString"].as_str().unwrap().starts_with("anon-"));
+    assert_ne!(items[3]["toString"], "ACME Corp");
+    assert_eq!(items[4]["toString"], "Major");
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::jira::JiraClient;
+use crate::layout::sanitize;
+use crate::{Issue, NoteContext};
+use tracing::{debug, warn};
+
+/// `[attachments]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AttachmentsConfig {
+    /// Download attachments into the vault (default true); when off, notes
+    /// link to Jira instead.
+    pub download: Option<bool>,
+    /// Vault folder holding one subfolder per issue (default "Jira Tickets/attachments").
+    pub folder: Option<String>,
+    /// Larger files are linked instead of downloaded (default 20).
+    pub max_size_mb: Option<u64>,
+}
+
+impl AttachmentsConfig {
+    pub fn download(&self) -> bool {
+        self.download.unwrap_or(true)
+    }
+
+    pub fn folder(&self) -> &str {
+        self.folder.as_deref().unwrap_or("Jira Tickets/attachments")
+    }
+
+    fn max_bytes(&self) -> u64 {
+        self.max_size_mb.unwrap_or(20) * 1024 * 1024
+    }
+}
+
+/// One entry of the issue's `attachment` field.
+struct Attachment<'a> {
+    /// File name inside the issue's folder.
+    file: String,
+    title: &'a str,
+    url: Option<&'a str>,
+    mime_type: &'a str,
+    size: u64,
+}
+
+/// The issue's attachments; names repeated within an issue get the
+/// attachment id in front so they don't overwrite each other.
+fn list(issue: &Issue) -> Vec<Attachment<'_>> {
+    let Some(items) = issue.fields.extra.get("attachment").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .map(|item| {
+            let title = item["filename"].as_str().unwrap_or_default();
+            let mut file = sanitize(title);
+            if !seen.insert(file.clone()) {
+                file = format!("{}-{}", item["id"].as_str().unwrap_or_default(), file);
+            }
+            Attachment {
+                file,
+                title,
+                url: item["content"].as_str(),
+                mime_type: item["mimeType"].as_str().unwrap_or_default(),
+                size: item["size"].as_u64().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// Saves the attachments of `issue` that aren't in the vault yet. Files
+/// already there are kept; a failed download only costs that file.
+pub async fn download(ctx: &NoteContext<'_>, client: &JiraClient, issue: &Issue) {
+    let settings = &ctx.config.attachments;
+    if !settings.download() || ctx.config.dry_run {
+        return;
+    }
+    let dir = Path::new(&ctx.config.vault_path).join(settings.folder()).join(&issue.key);
+    for attachment in list(issue) {
+        let path = dir.join(&attachment.file);
+        let Some(url) = attachment.url else { continue };
+        if path.exists() || attachment.size > settings.max_bytes() {
+            continue;
+        }
+        match client.download(url).await {
+            Ok((bytes, _)) => {
+                if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&path, bytes)) {
+                    warn!("⚠️  {} eki {} yazılamadı: {}", issue.key, attachment.title, e);
+                } else {
+                    debug!(key = %issue.key, file = %attachment.file, "ek indirildi");
+                }
+            }
+            Err(e) => warn!("⚠️  {} eki {} indirilemedi: {}", issue.key, attachment.title, e),
+        }
+    }
+}
+
+/// The `## 📎` section of a ticket note: images and PDFs in the vault are
+/// embedded, other files linked; files that weren't downloaded link to Jira.
+pub fn section(ctx: &NoteContext, issue: &Issue) -> String {
+    let attachments = list(issue);
+    if attachments.is_empty() {
+        return String::new();
+    }
+    let settings = &ctx.config.attachments;
+    let folder = format!("{}/{}", settings.folder(), issue.key);
+    let mut section = format!("\n## 📎 {}\n", ctx.labels.attachments);
+    for attachment in attachments {
+        let local = Path::new(&ctx.config.vault_path).join(&folder).join(&attachment.file);
+        section.push_str(&entry(&attachment, &folder, local.exists()));
+    }
+    section
+}
+
+fn entry(attachment: &Attachment, folder: &str, in_vault: bool) -> String {
+    let target = format!("{}/{}", folder, attachment.file);
+    match (in_vault, attachment.url) {
+        (true, _) if attachment.mime_type.starts_with("image/") || attachment.mime_type == "application/pdf" => {
+            format!("![[{}]]\n", target)
+        }
+        (true, _) => format!("- [[{}|{}]]\n", target, attachment.title),
+        (false, Some(url)) => format!("- [{}]({})\n", attachment.title, url),
+        (false, None) => format!("- {}\n", attachment.title),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_attachment_entries() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "attachment": [
+                { "id": "10", "filename": "ekran.png", "mimeType": "image/png", "size": 10, "content": "https://x/att/10" },
+                { "id": "11", "filename": "log.txt", "mimeType": "text/plain", "size": 10, "content": "https://x/att/11" },
+                { "id": "12", "filename": "log.txt", "mimeType": "text/plain", "size": 10 }
+            ]
+        }
+    }))
+    .unwrap();
+    let attachments = list(&issue);
+    let files: Vec<&str> = attachments.iter().map(|a| a.file.as_str()).collect();
+    assert_eq!(files, ["ekran.png", "log.txt", "12-log.txt"]);
+
+    let folder = "Jira Tickets/attachments/A-1";
+    assert_eq!(entry(&attachments[0], folder, true), "![[Jira Tickets/attachments/A-1/ekran.png]]\n");
+    assert_eq!(entry(&attachments[1], folder, true), "- [[Jira Tickets/attachments/A-1/log.txt|log.txt]]\n");
+    assert_eq!(entry(&attachments[1], folder, false), "- [log.txt](https://x/att/11)\n");
+    assert_eq!(entry(&attachments[2], folder, false), "- log.txt\n");
+}
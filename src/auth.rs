@@ -0,0 +1,155 @@
+use std::error::Error;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::debug;
+
+/// How requests prove who they are.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMethod {
+    /// E-mail and API token on Cloud, username and password on Server.
+    Basic,
+    /// Personal access token (Server/Data Center 8.14+).
+    Bearer,
+    /// Session cookie from `/rest/auth/1/session`, for servers that refuse
+    /// basic auth on the REST API.
+    Cookie,
+}
+
+impl AuthMethod {
+    /// Reads `JIRA_AUTH`; `pat` is accepted for `bearer`.
+    pub fn parse(value: &str) -> Option<AuthMethod> {
+        match value.trim().to_lowercase().as_str() {
+            "basic" => Some(AuthMethod::Basic),
+            "bearer" | "pat" => Some(AuthMethod::Bearer),
+            "cookie" => Some(AuthMethod::Cookie),
+            _ => None,
+        }
+    }
+}
+
+/// Credentials ready to be put on a request.
+#[derive(Debug, Clone)]
+pub enum Auth {
+    Basic { user: String, token: String },
+    Bearer(String),
+    /// `JSESSIONID=...`
+    Cookie(String),
+}
+
+impl Auth {
+    pub fn method(&self) -> AuthMethod {
+        match self {
+            Auth::Basic { .. } => AuthMethod::Basic,
+            Auth::Bearer(_) => AuthMethod::Bearer,
+            Auth::Cookie(_) => AuthMethod::Cookie,
+        }
+    }
+
+    pub fn apply(&self, builder: RequestBuilder) -> RequestBuilder {
+        match self {
+            Auth::Basic { user, token } => builder.basic_auth(user, Some(token)),
+            Auth::Bearer(token) => builder.bearer_auth(token),
+            Auth::Cookie(cookie) => builder.header(reqwest::header::COOKIE, cookie),
+        }
+    }
+
+    /// Credentials for `method`; a cookie means logging in first.
+    pub async fn new(method: AuthMethod, http: &Client, base_url: &str, user: &str, token: &str) -> Result<Auth, Box<dyn Error>> {
+        Ok(match method {
+            AuthMethod::Basic => Auth::Basic { user: user.to_string(), token: token.to_string() },
+            AuthMethod::Bearer => Auth::Bearer(token.to_string()),
+            AuthMethod::Cookie => Auth::Cookie(session_cookie(http, base_url, user, token).await?),
+        })
+    }
+}
+
+/// What [`detect`] found out about the server.
+#[derive(Debug)]
+pub struct Detected {
+    /// `Cloud`, `Server` or `DataCenter`; empty if the server didn't say.
+    pub deployment_type: String,
+    pub api_version: u8,
+    pub auth: Auth,
+    /// Whether the result is worth remembering: the server said what it is
+    /// and the method got through (or was the only one to try).
+    pub confirmed: bool,
+}
+
+/// Works out the deployment from the anonymous `serverInfo` and the auth
+/// method that gets through. Cloud takes e-mail + API token on API v3;
+/// Server/Data Center is tried with the token as a personal access token,
+/// then as a password, then through a session cookie, on API v2. If nothing
+/// works, the old rule applies (basic with a user, bearer without) so the
+/// real request reports the error.
+pub async fn detect(http: &Client, base_url: &str, user: &str, token: &str) -> Detected {
+    let info: Option<Value> = match http.get(format!("{}/rest/api/2/serverInfo", base_url)).send().await {
+        Ok(resp) if resp.status().is_success() => resp.json().await.ok(),
+        _ => None,
+    };
+    let deployment_type = info.as_ref().and_then(|i| i["deploymentType"].as_str()).unwrap_or_default().to_string();
+    let cloud = match deployment_type.as_str() {
+        "" => base_url.contains(".atlassian.net"),
+        kind => kind == "Cloud",
+    };
+    let api_version = if cloud { 3 } else { 2 };
+    let fallback = if user.is_empty() { AuthMethod::Bearer } else { AuthMethod::Basic };
+
+    let candidates = candidates(cloud, user);
+    if let [method] = candidates[..] {
+        let auth = Auth::new(method, http, base_url, user, token).await.unwrap_or(Auth::Bearer(token.to_string()));
+        let confirmed = info.is_some();
+        return Detected { deployment_type, api_version, auth, confirmed };
+    }
+    for method in candidates {
+        let Ok(auth) = Auth::new(method, http, base_url, user, token).await else { continue };
+        let probe = auth.apply(http.get(format!("{}/rest/api/{}/myself", base_url, api_version)));
+        match probe.send().await.map(|resp| resp.status()) {
+            Ok(status) if status.is_success() => {
+                debug!(?method, deployment_type, api_version, "kimlik doğrulama yöntemi seçildi");
+                let confirmed = info.is_some();
+                return Detected { deployment_type, api_version, auth, confirmed };
+            }
+            Ok(status) => debug!(?method, %status, "kimlik doğrulama yöntemi reddedildi"),
+            Err(e) => debug!(?method, error = %e, "kimlik doğrulama denemesi başarısız"),
+        }
+    }
+    let auth = Auth::new(fallback, http, base_url, user, token).await.unwrap_or(Auth::Bearer(token.to_string()));
+    Detected { deployment_type, api_version, auth, confirmed: false }
+}
+
+/// Methods worth trying, safest first: a wrong personal access token doesn't
+/// count as a failed login, a wrong password does (and can trigger CAPTCHA).
+fn candidates(cloud: bool, user: &str) -> Vec<AuthMethod> {
+    match (cloud, user.is_empty()) {
+        (true, false) => vec![AuthMethod::Basic],
+        (_, true) => vec![AuthMethod::Bearer],
+        (false, false) => vec![AuthMethod::Bearer, AuthMethod::Basic, AuthMethod::Cookie],
+    }
+}
+
+async fn session_cookie(http: &Client, base_url: &str, user: &str, password: &str) -> Result<String, Box<dyn Error>> {
+    let body = serde_json::json!({ "username": user, "password": password });
+    let resp = http.post(format!("{}/rest/auth/1/session", base_url)).json(&body).send().await?;
+    if resp.status() != StatusCode::OK {
+        return Err(format!("Oturum açılamadı: {}", resp.status()).into());
+    }
+    let session: Value = resp.json().await?;
+    match (session["session"]["name"].as_str(), session["session"]["value"].as_str()) {
+        (Some(name), Some(value)) => Ok(format!("{}={}", name, value)),
+        _ => Err("Oturum yanıtında çerez yok".into()),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_auth_candidates() {
+    assert_eq!(AuthMethod::parse(" PAT "), Some(AuthMethod::Bearer));
+    assert_eq!(AuthMethod::parse("cookie"), Some(AuthMethod::Cookie));
+    assert_eq!(AuthMethod::parse("digest"), None);
+
+    assert_eq!(candidates(true, "ayse@example.com"), [AuthMethod::Basic]);
+    assert_eq!(candidates(false, ""), [AuthMethod::Bearer]);
+    assert_eq!(candidates(false, "ayse"), [AuthMethod::Bearer, AuthMethod::Basic, AuthMethod::Cookie]);
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+use serde_json::Value;
+use crate::cli::BulkAction;
+use crate::config::Config;
+use crate::jira::JiraClient;
+use crate::people::Directory;
+use crate::push::{self, Mutation};
+use crate::restricted;
+use crate::{Issue, User};
+
+/// `bulk`: plans one mutation per issue the JQL returns, always shows the
+/// plan, and sends it only after an explicit yes. Synced notes are refreshed
+/// afterwards.
+pub async fn run(config: &Config, action: &BulkAction) -> Result<(), Box<dyn Error>> {
+    let target = match action {
+        BulkAction::Transition { target, .. } | BulkAction::Assign { target, .. } | BulkAction::Label { target, .. } => target,
+    };
+    let client = JiraClient::new(config)?;
+    let Some(raw) = crate::search(&client, &target.jql, &["summary", "status", "assignee", "labels"]).await? else {
+        return Err(crate::SEARCH_REJECTED.into());
+    };
+    let issues = raw.into_iter().map(restricted::from_raw).collect::<Result<Vec<Issue>, _>>()?;
+
+    let people = Directory::load(&config.vault_path, &config.people)?;
+    let assignee = match action {
+        BulkAction::Assign { assignee, .. } => Some(resolve_assignee(&client, &people, assignee).await?),
+        _ => None,
+    };
+    let mutations = plan(action, &issues, assignee, &people);
+
+    println!("🔎 {} iş bulundu, {} değişiklik:", issues.len(), mutations.len());
+    for mutation in &mutations {
+        println!("   {}", mutation.describe());
+    }
+    if mutations.is_empty() || target.dry_run || !confirm(&format!("{} değişiklik Jira'ya gönderilsin mi?", mutations.len()))? {
+        return Ok(());
+    }
+
+    push::apply(config, &mutations).await?;
+    crate::sync(config, crate::Steps::ALL, None).await.map(|_| ())
+}
+
+/// `(account id, name)` for the assign action; `None` id unassigns.
+async fn resolve_assignee(client: &JiraClient, people: &Directory, name: &str) -> Result<(Option<String>, String), Box<dyn Error>> {
+    match name.to_lowercase().as_str() {
+        "none" => Ok((None, String::new())),
+        "me" => {
+            let me: Value = client.get_json("/rest/api/3/myself").await?;
+            let id = me["accountId"].as_str().ok_or("Hesap kimliği (accountId) okunamadı")?;
+            Ok((Some(id.to_string()), people.name(id)))
+        }
+        _ => {
+            let id = people.find(name).ok_or_else(|| format!("'{}' kişisi tanınmıyor; hesap kimliğini verin ya da önce sync çalıştırın", name))?;
+            Ok((Some(id.clone()), people.name(&id)))
+        }
+    }
+}
+
+/// One mutation per issue the action would actually change.
+fn plan(action: &BulkAction, issues: &[Issue], assignee: Option<(Option<String>, String)>, people: &Directory) -> Vec<Mutation> {
+    let mut mutations = Vec::new();
+    for issue in issues {
+        let key = issue.key.clone();
+        match action {
+            BulkAction::Transition { status, .. } => {
+                if !issue.fields.status.name.eq_ignore_ascii_case(status) {
+                    mutations.push(Mutation::Transition { key, from: issue.fields.status.name.clone(), to: status.clone() });
+                }
+            }
+            BulkAction::Assign { .. } => {
+                let Some((account_id, to)) = &assignee else { continue };
+                let current = issue.fields.assignee.as_ref().map(User::id);
+                if current != account_id.as_deref() {
+                    let from = current.map(|id| people.name(id)).unwrap_or_default();
+                    mutations.push(Mutation::Assign { key, from, to: to.clone(), account_id: account_id.clone() });
+                }
+            }
+            BulkAction::Label { add, remove, .. } => {
+                let labels = &issue.fields.labels;
+                let add: Vec<String> = add.iter().filter(|l| !labels.contains(l)).cloned().collect();
+                let remove: Vec<String> = remove.iter().filter(|l| labels.contains(l)).cloned().collect();
+                if !add.is_empty() || !remove.is_empty() {
+                    mutations.push(Mutation::Labels { key, add, remove });
+                }
+            }
+        }
+    }
+    mutations
+}
+
+/// Asks a yes/no question on the terminal; anything but yes is a no.
+pub fn confirm(question: &str) -> Result<bool, Box<dyn Error>> {
+    print!("❓ {} [y/N]: ", question);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(matches!(line.trim().to_lowercase().as_str(), "y" | "yes" | "e" | "evet"))
+}
+
+#[cfg(test)]
+#[test]
+fn test_bulk_plan() {
+    let issue = |key: &str, status: &str, labels: &[&str]| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "status": { "name": status }, "labels": labels }
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("A-1", "To Do", &["old"]), issue("A-2", "Done", &["sprint-9"])];
+    let target = || crate::cli::BulkTarget { jql: String::new(), dry_run: true };
+    let people = Directory::default();
+
+    let done = plan(&BulkAction::Transition { target: target(), status: "done".into() }, &issues, None, &people);
+    assert_eq!(done, [Mutation::Transition { key: "A-1".into(), from: "To Do".into(), to: "done".into() }]);
+
+    let label = BulkAction::Label { target: target(), add: vec!["sprint-9".into()], remove: vec!["old".into()] };
+    assert_eq!(
+        plan(&label, &issues, None, &people),
+        [Mutation::Labels { key: "A-1".into(), add: vec!["sprint-9".into()], remove: vec!["old".into()] }]
+    );
+
+    let assign = BulkAction::Assign { target: target(), assignee: "none".into() };
+    assert!(plan(&assign, &issues, Some((None, String::new())), &people).is_empty());
+}
@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use chrono::Local;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::auth::AuthMethod;
+use crate::config::Config;
+use crate::jira::JiraClient;
+use crate::state::State;
+
+const CAPABILITIES_FILE: &str = "capabilities.json";
+
+/// What the Jira instance offers, as found by the `capabilities` command.
+/// Kept in the state folder; sync uses it to fill in settings left unset.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Capabilities {
+    pub probed_at: String,
+    /// `Cloud`, `Server` or `DataCenter`.
+    pub deployment_type: String,
+    pub version: String,
+    /// REST API version that answered (3 or 2).
+    pub api_version: u8,
+    /// Auth method that got through.
+    pub auth: Option<AuthMethod>,
+    pub agile: bool,
+    pub service_desk: bool,
+    pub graphql: bool,
+    /// Users are only known by accountId (no usernames, emails may be hidden).
+    pub gdpr_strict: bool,
+    /// Field name -> field id, e.g. "Sprint" -> "customfield_10020".
+    pub fields: BTreeMap<String, String>,
+}
+
+impl Capabilities {
+    pub fn load(vault_path: &str) -> Result<Capabilities, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(CAPABILITIES_FILE);
+        if !path.exists() {
+            return Ok(Capabilities::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(CAPABILITIES_FILE), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Id of the field with this name (case-insensitive), if the instance has it.
+    pub fn field_id(&self, name: &str) -> Option<String> {
+        self.fields.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, id)| id.clone())
+    }
+
+    /// Fills settings the user left unset from what was detected.
+    pub fn apply(&self, config: &mut Config) {
+        if config.api_version.is_none() && self.api_version != 0 {
+            config.api_version = Some(self.api_version);
+        }
+        if config.auth.is_none() {
+            config.auth = self.auth;
+        }
+        if config.board.flagged_field.is_none() {
+            config.board.flagged_field = self.field_id("Flagged");
+        }
+        if config.board.rank_field.is_none() {
+            config.board.rank_field = self.field_id("Rank");
+        }
+        if config.board.points_fields.is_empty() {
+            config.board.points_fields = ["Story Points", "Story point estimate"].iter().filter_map(|name| self.field_id(name)).collect();
+        }
+        if config.epics.link_field.is_none() {
+            config.epics.link_field = self.field_id("Epic Link");
+        }
+        if config.sprints.field.is_none() {
+            config.sprints.field = self.field_id("Sprint");
+        }
+        if config.velocity.points_field.is_none() {
+            config.velocity.points_field = self.field_id("Story Points").or_else(|| self.field_id("Story point estimate"));
+        }
+    }
+}
+
+/// `capabilities`: probes the instance, prints a report and stores it.
+pub async fn run(config: &Config) -> Result<(), Box<dyn Error>> {
+    // Yoklama her iki sürümü de açıkça dener; ayarlı sürüm yolları değiştirmesin.
+    let client = JiraClient::new(&Config { api_version: Some(3), auth: None, ..config.clone() })?;
+    let mut caps = Capabilities { probed_at: Local::now().to_rfc3339(), ..Default::default() };
+
+    for api_version in [3, 2] {
+        if let Ok(info) = client.get_json::<Value>(&format!("/rest/api/{}/serverInfo", api_version)).await {
+            caps.api_version = api_version;
+            caps.deployment_type = info["deploymentType"].as_str().unwrap_or_default().to_string();
+            caps.version = info["version"].as_str().unwrap_or_default().to_string();
+            break;
+        }
+    }
+    caps.auth = client.auth_method().await;
+    if caps.api_version == 0 {
+        return Err("Jira'ya ulaşılamadı: serverInfo ne v3 ne de v2 ile yanıt verdi".into());
+    }
+    let api = format!("/rest/api/{}", caps.api_version);
+
+    caps.agile = responds(&client, Method::GET, "/rest/agile/1.0/board?maxResults=1", None).await;
+    caps.service_desk = responds(&client, Method::GET, "/rest/servicedeskapi/servicedesk?limit=1", None).await;
+    let query = serde_json::json!({ "query": "{ __typename }" });
+    caps.graphql = responds(&client, Method::POST, "/gateway/api/graphql", Some(&query)).await;
+
+    if let Ok(myself) = client.get_json::<Value>(&format!("{}/myself", api)).await {
+        caps.gdpr_strict = gdpr_strict(&myself);
+    }
+    if let Ok(fields) = client.get_json::<Vec<Value>>(&format!("{}/field", api)).await {
+        caps.fields = fields
+            .iter()
+            .filter_map(|f| Some((f["name"].as_str()?.to_string(), f["id"].as_str()?.to_string())))
+            .collect();
+    }
+
+    let yes_no = |b: bool| if b { "✅" } else { "❌" };
+    println!("🧭 Jira {} {} (REST API v{})", caps.deployment_type, caps.version, caps.api_version);
+    println!("   Agile (board/sprint): {}", yes_no(caps.agile));
+    println!("   Service Management:   {}", yes_no(caps.service_desk));
+    println!("   GraphQL:              {}", yes_no(caps.graphql));
+    println!("   GDPR katı mod:        {}", yes_no(caps.gdpr_strict));
+    if let Some(auth) = caps.auth {
+        println!("   Kimlik doğrulama:     {:?}", auth);
+    }
+    println!("   {} alan bulundu", caps.fields.len());
+    for name in ["Sprint", "Story Points", "Story point estimate", "Epic Link", "Flagged", "Rank"] {
+        if let Some(id) = caps.field_id(name) {
+            println!("   {}: {}", name, id);
+        }
+    }
+
+    caps.save(&config.vault_path)?;
+    println!("💾 Sonuç .jira-sync/{} dosyasına kaydedildi.", CAPABILITIES_FILE);
+    Ok(())
+}
+
+async fn responds(client: &JiraClient, method: Method, path: &str, body: Option<&Value>) -> bool {
+    let mut request = client.request(method, path);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    client.send(request).await.is_ok_and(|resp| resp.status().is_success())
+}
+
+/// Cloud in GDPR strict mode identifies users by `accountId` only.
+fn gdpr_strict(myself: &Value) -> bool {
+    myself.get("accountId").is_some() && myself.get("name").is_none() && myself.get("key").is_none()
+}
+
+#[cfg(test)]
+#[test]
+fn test_capabilities_fill_config() {
+    let mut caps = Capabilities { api_version: 2, ..Default::default() };
+    caps.fields.insert("Flagged".to_string(), "customfield_10100".to_string());
+    caps.fields.insert("Rank".to_string(), "customfield_10019".to_string());
+
+    let mut config = Config::default();
+    caps.apply(&mut config);
+    assert_eq!(config.board.flagged_field(), "customfield_10100");
+    assert_eq!(config.board.rank_field.as_deref(), Some("customfield_10019"));
+    assert_eq!(config.api_version(), 2);
+
+    assert!(gdpr_strict(&serde_json::json!({ "accountId": "5b10a" })));
+    assert!(!gdpr_strict(&serde_json::json!({ "name": "jdoe", "key": "jdoe" })));
+}
@@ -0,0 +1,123 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use chrono::Local;
+use crate::i18n::Labels;
+use crate::kanban;
+use crate::state::State;
+use crate::{Issue, NoteContext};
+use tracing::info;
+
+const BOARD_FILE: &str = "board.json";
+
+/// How a card differs from the previous sync.
+#[derive(Debug, PartialEq)]
+enum Change {
+    Appeared { key: String, column: String },
+    Disappeared { key: String, column: String },
+    Moved { key: String, from: String, to: String },
+}
+
+/// Compares the main board with the one from the previous sync and adds the
+/// differences to the top of the change feed note. The first sync only
+/// remembers the board.
+pub fn update(ctx: &NoteContext<'_>, issues: &[Issue], note_links: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+    let vault_path = &ctx.config.vault_path;
+    let current: BTreeMap<String, String> = kanban::columns(issues, &ctx.config.board)
+        .into_iter()
+        .flat_map(|(column, cards)| cards.into_iter().map(move |i| (i.key.clone(), column.clone())))
+        .collect();
+
+    let snapshot = State::dir(vault_path).join(BOARD_FILE);
+    if snapshot.exists() {
+        let previous: BTreeMap<String, String> = serde_json::from_str(&fs::read_to_string(&snapshot)?)?;
+        let changes = diff(&previous, &current);
+        if !changes.is_empty() {
+            let file = ctx.config.board.changes_file.clone().unwrap_or_else(|| "JiraChanges.md".to_string());
+            let path = Path::new(vault_path).join(&file);
+            let existing = fs::read_to_string(&path).unwrap_or_default();
+            let time = Local::now().format("%Y-%m-%d %H:%M").to_string();
+            let section = render(&ctx.labels, &changes, note_links, &time);
+            ctx.writer.write(&path, &prepend(&ctx.labels, &existing, &section))?;
+            info!("🔀 {} pano değişikliği kaydedildi: {}", changes.len(), file);
+        }
+    }
+
+    if !ctx.config.dry_run {
+        fs::create_dir_all(State::dir(vault_path))?;
+        fs::write(snapshot, serde_json::to_string_pretty(&current)?)?;
+    }
+    Ok(())
+}
+
+fn diff(previous: &BTreeMap<String, String>, current: &BTreeMap<String, String>) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (key, column) in current {
+        match previous.get(key) {
+            None => changes.push(Change::Appeared { key: key.clone(), column: column.clone() }),
+            Some(from) if from != column => {
+                changes.push(Change::Moved { key: key.clone(), from: from.clone(), to: column.clone() })
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, column) in previous {
+        if !current.contains_key(key) {
+            changes.push(Change::Disappeared { key: key.clone(), column: column.clone() });
+        }
+    }
+    changes
+}
+
+fn render(labels: &Labels, changes: &[Change], note_links: &HashMap<String, String>, time: &str) -> String {
+    let link = |key: &str| match note_links.get(key) {
+        Some(target) if target != key => format!("[[{}|{}]]", target, key),
+        _ => format!("[[{}]]", key),
+    };
+    let mut section = format!("## {}\n\n", time);
+    for change in changes {
+        section.push_str(&match change {
+            Change::Appeared { key, column } => format!("- {} {} **{}**\n", link(key), labels.appeared, column),
+            Change::Disappeared { key, column } => format!("- {} {} **{}**\n", link(key), labels.disappeared, column),
+            Change::Moved { key, from, to } => format!("- {} {} **{}** → **{}**\n", link(key), labels.moved, from, to),
+        });
+    }
+    section.push('\n');
+    section
+}
+
+/// Newest entries first; everything above the first entry is regenerated.
+fn prepend(labels: &Labels, existing: &str, section: &str) -> String {
+    let older = existing.find("\n## ").map(|i| &existing[i + 1..]).unwrap_or_default();
+    format!("---\ntags: [jira, changes]\n---\n# {}\n\n{}{}", labels.changes, section, older)
+}
+
+#[cfg(test)]
+#[test]
+fn test_board_changes() {
+    let board = |cards: &[(&str, &str)]| -> BTreeMap<String, String> {
+        cards.iter().map(|(k, c)| (k.to_string(), c.to_string())).collect()
+    };
+    let previous = board(&[("A-1", "To Do"), ("A-2", "To Do"), ("A-3", "Doing")]);
+    let current = board(&[("A-1", "Doing"), ("A-3", "Doing"), ("A-4", "To Do")]);
+    let changes = diff(&previous, &current);
+    assert_eq!(
+        changes,
+        vec![
+            Change::Moved { key: "A-1".into(), from: "To Do".into(), to: "Doing".into() },
+            Change::Appeared { key: "A-4".into(), column: "To Do".into() },
+            Change::Disappeared { key: "A-2".into(), column: "To Do".into() },
+        ]
+    );
+
+    let labels = Labels::for_locale("en");
+    let links: HashMap<String, String> = [("A-1".to_string(), "A/A-1".to_string())].into();
+    let section = render(&labels, &changes, &links, "2025-06-02 09:00");
+    assert!(section.contains("- [[A/A-1|A-1]] moved **To Do** → **Doing**\n"));
+    assert!(section.contains("- [[A-2]] left the board from **To Do**\n"));
+
+    let existing = "---\ntags: [jira, changes]\n---\n# Board Changes\n\n## 2025-06-01 09:00\n\n- [[A-9]] appeared in **To Do**\n\n";
+    let feed = prepend(&labels, existing, &section);
+    assert!(feed.find("## 2025-06-02").unwrap() < feed.find("## 2025-06-01").unwrap());
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use crate::config::Config;
+use crate::state::State;
+use crate::vault::leftover_temp_files;
+use tracing::info;
+
+/// `clean`: deletes temp files of interrupted writes and drops state entries
+/// whose note the user deleted, so the next sync recreates them cleanly.
+pub fn run(config: &Config, dry_run: bool) -> Result<(), Box<dyn Error>> {
+    let vault = Path::new(&config.vault_path);
+    let temp_files = leftover_temp_files(vault);
+    for file in &temp_files {
+        info!("🗑️  {}", file.display());
+        if !dry_run {
+            fs::remove_file(file)?;
+        }
+    }
+
+    let mut state = State::load(&config.vault_path)?;
+    let gone: Vec<String> = state.notes.iter().filter(|(_, n)| !vault.join(&n.path).exists()).map(|(k, _)| k.clone()).collect();
+    for key in &gone {
+        info!("🧾 {} durum kaydından çıkarıldı (not bulunamadı)", key);
+        state.notes.remove(key);
+    }
+    if !dry_run && !gone.is_empty() {
+        state.save(&config.vault_path)?;
+    }
+
+    let verb = if dry_run { "silinecekti" } else { "silindi" };
+    info!("🧹 {} geçici dosya ve {} kayıt {}.", temp_files.len(), gone.len(), verb);
+    Ok(())
+}
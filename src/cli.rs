@@ -0,0 +1,278 @@
+// Also compiled by build.rs to render the man page, so keep this file free of crate imports.
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+
+/// Sync Jira issues into an Obsidian vault. Without a subcommand a full sync runs.
+#[derive(Parser)]
+#[command(name = "jira_obsidian_sync", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub overrides: Overrides,
+    /// More detail: -v adds request timings and per-file results, -vv everything
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Only warnings and errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// For scheduled runs: silent unless notes changed or something failed; exits
+    /// 0 when nothing changed, 3 when changes were written and 1 on errors
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    pub cron: bool,
+    /// Summary format of sync, sync/board subcommands
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub output: OutputFormat,
+}
+
+/// Settings given on the command line; they win over the config file and the environment.
+#[derive(Args, Default)]
+pub struct Overrides {
+    /// Profile from the config file's `[profiles.<name>]` (or JIRA_SYNC_PROFILE)
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    /// Jira host, e.g. your-domain.atlassian.net (overrides JIRA_SERVER)
+    #[arg(long, global = true)]
+    pub server: Option<String>,
+    /// Jira user e-mail (overrides JIRA_USER)
+    #[arg(long, global = true)]
+    pub user: Option<String>,
+    /// Obsidian vault folder (overrides OBSIDIAN_PATH)
+    #[arg(long, global = true)]
+    pub vault: Option<String>,
+    /// Language of generated text, `tr` or `en` (overrides NOTE_LOCALE)
+    #[arg(long, global = true)]
+    pub locale: Option<String>,
+    /// Search to sync instead of the configured queries
+    #[arg(long, global = true)]
+    pub jql: Option<String>,
+    /// Extra Jira fields to fetch into note frontmatter, comma-separated
+    #[arg(long, global = true, value_delimiter = ',')]
+    pub fields: Vec<String>,
+    /// Folder for ticket notes inside the vault (overrides NOTE_FOLDER_TEMPLATE)
+    #[arg(long, global = true)]
+    pub tickets_dir: Option<String>,
+    /// File name of the main board (default JiraKanban.md)
+    #[arg(long, global = true)]
+    pub board_name: Option<String>,
+    /// Fetch and convert, but only print which files would change, with diffs
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Refetch every issue instead of only those updated since the last sync
+    #[arg(long, global = true)]
+    pub full: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Fetch issues and write their notes only, without boards or dashboards
+    Sync {
+        /// Sync only this issue (e.g. PROJ-123) instead of running the queries
+        #[arg(long)]
+        issue: Option<String>,
+        /// With --issue, also move its card on the main board
+        #[arg(long, requires = "issue")]
+        board: bool,
+    },
+    /// Fetch issues and regenerate boards and dashboards only, leaving notes untouched
+    Board,
+    /// Fetch issues and print them without writing anything
+    Fetch,
+    /// Fetch issues and write them as a CSV or JSON table instead of notes
+    Export {
+        #[arg(long, value_enum, default_value_t)]
+        format: ExportFormat,
+        /// File to write (default: stdout)
+        #[arg(long)]
+        out: Option<String>,
+    },
+    /// Remove leftovers of interrupted writes and forget notes that were deleted
+    Clean {
+        /// Only print what would be removed
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Send status changes and outbox comments from notes back to Jira
+    Push {
+        /// List the Jira changes that would be made without making them
+        #[arg(long)]
+        dry_run: bool,
+        /// Ask before each change is sent
+        #[arg(long, conflicts_with = "dry_run")]
+        confirm: bool,
+        /// Keep running and push whenever notes in the vault change
+        #[arg(long, conflicts_with_all = ["dry_run", "confirm"])]
+        watch: bool,
+    },
+    /// Keep running and sync again every `daemon_interval_minutes`
+    Daemon,
+    /// Apply one change to every issue a JQL query returns, after a preview and confirmation
+    Bulk {
+        #[command(subcommand)]
+        action: BulkAction,
+    },
+    /// Inspect the changes waiting to be sent to Jira
+    Queue {
+        #[command(subcommand)]
+        action: QueueAction,
+    },
+    /// Generate a report note
+    Report {
+        #[command(subcommand)]
+        kind: ReportKind,
+    },
+    /// List synced issues from the local state, without asking Jira
+    List {
+        /// Only issues in this status
+        #[arg(long)]
+        status: Option<String>,
+        /// Only issues with this label
+        #[arg(long)]
+        label: Option<String>,
+        /// Only issues with this priority
+        #[arg(long)]
+        priority: Option<String>,
+        /// Order of the output
+        #[arg(long, value_enum, default_value = "key")]
+        sort: SortKey,
+    },
+    /// Start watching an issue in Jira
+    Watch {
+        /// Issue key, e.g. PROJ-123
+        key: String,
+    },
+    /// Stop watching an issue in Jira
+    Unwatch {
+        /// Issue key, e.g. PROJ-123
+        key: String,
+    },
+    /// Check the connection, queries, vault and managed files, and suggest fixes
+    Doctor,
+    /// Store the Jira API token in the OS keyring instead of .env
+    Login {
+        /// Sign in through the browser with OAuth 2.0 (needs an `[oauth]` app)
+        #[arg(long)]
+        oauth: bool,
+    },
+    /// Remove the token stored by `login`
+    Logout,
+    /// Probe the Jira instance (API version, optional APIs, fields) and remember the result
+    Capabilities,
+    /// List notes of issues the queries no longer return; archive or delete them
+    Prune {
+        /// Move the notes into this vault folder, keeping their layout
+        #[arg(long, num_args = 0..=1, default_missing_value = "Archive", conflicts_with = "delete")]
+        archive: Option<String>,
+        /// Delete the notes
+        #[arg(long)]
+        delete: bool,
+        /// Also take notes of issues resolved more than this many days ago
+        #[arg(long, value_name = "DAYS")]
+        resolved_days: Option<i64>,
+        /// Don't ask before archiving or deleting
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Show when the last sync ran, what failed and which local edits wait to be pushed
+    Status,
+    /// Move managed notes to the current folder/filename templates and fix links
+    Migrate {
+        /// Only print what would be moved
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Download and install the latest release from GitHub
+    SelfUpdate {
+        /// Only report whether a newer release exists
+        #[arg(long)]
+        check: bool,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate the script for
+        shell: Shell,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum QueueAction {
+    /// Show queued changes in the order they will be sent
+    List,
+    /// Send every queued change now, ignoring the retry delay
+    Flush,
+    /// Drop every queued change without sending it
+    Clear,
+}
+
+#[derive(Subcommand)]
+pub enum ReportKind {
+    /// A teammate's open issues by status and priority, overdue items and recent completions
+    Person {
+        /// Display name, name from the `[people]` table or account id
+        name: String,
+    },
+}
+
+#[derive(Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Progress messages only
+    #[default]
+    Text,
+    /// A JSON summary of the sync on stdout
+    Json,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SortKey {
+    Key,
+    Status,
+    Priority,
+    /// Most recently updated first
+    Updated,
+}
+
+#[derive(Subcommand)]
+pub enum BulkAction {
+    /// Move every matching issue to a status
+    Transition {
+        #[command(flatten)]
+        target: BulkTarget,
+        /// Target status, e.g. "Done"
+        status: String,
+    },
+    /// Assign every matching issue
+    Assign {
+        #[command(flatten)]
+        target: BulkTarget,
+        /// Display name, account id, "me", or "none" to unassign
+        assignee: String,
+    },
+    /// Add or remove labels on every matching issue
+    Label {
+        #[command(flatten)]
+        target: BulkTarget,
+        /// Label to add (repeatable)
+        #[arg(long)]
+        add: Vec<String>,
+        /// Label to remove (repeatable)
+        #[arg(long)]
+        remove: Vec<String>,
+    },
+}
+
+#[derive(Args)]
+pub struct BulkTarget {
+    /// Issues to change
+    #[arg(long)]
+    pub jql: String,
+    /// Only show the preview
+    #[arg(long)]
+    pub dry_run: bool,
+}
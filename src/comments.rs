@@ -0,0 +1,54 @@
+use chrono::FixedOffset;
+use serde_json::Value;
+use crate::metrics::parse_time;
+use crate::people::Directory;
+use crate::Issue;
+
+/// The `## 💬` section of a ticket note: one callout per comment, oldest
+/// first, with the author, the time in `offset` and the body as Markdown
+/// (`render_body` gets the whole comment). Empty when nobody has commented.
+pub fn section(issue: &Issue, title: &str, people: &Directory, offset: FixedOffset, render_body: &dyn Fn(&Value) -> String) -> String {
+    let Some(comments) = issue.fields.extra.get("comment").and_then(|c| c["comments"].as_array()).filter(|c| !c.is_empty()) else {
+        return String::new();
+    };
+    let mut section = format!("\n## 💬 {}\n", title);
+    for comment in comments {
+        let author = &comment["author"];
+        let author = match author["accountId"].as_str().or(author["name"].as_str()) {
+            Some(id) => people.name(id),
+            None => author["displayName"].as_str().unwrap_or("?").to_string(),
+        };
+        let time = comment["created"].as_str().and_then(parse_time)
+            .map(|t| t.with_timezone(&offset).format(" · %Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        section.push_str(&format!("\n> [!quote] {}{}\n", author, time));
+        for line in render_body(comment).trim().lines() {
+            section.push_str(&format!("> {}\n", line).replace("> \n", ">\n"));
+        }
+    }
+    section
+}
+
+#[cfg(test)]
+#[test]
+fn test_comments_section() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "comment": { "total": 2, "comments": [
+                { "author": { "accountId": "abc", "displayName": "Ayşe" }, "created": "2024-01-15T10:30:00.000+0000", "body": "İlk satır\n\nikinci" },
+                { "author": { "displayName": "Bot" }, "created": "bozuk", "body": "Tamam" }
+            ] }
+        }
+    }))
+    .unwrap();
+    let people = Directory::default();
+    let render = |comment: &Value| comment["body"].as_str().unwrap_or_default().to_string();
+    let offset = FixedOffset::east_opt(3 * 3600).unwrap();
+    assert_eq!(
+        section(&issue, "Yorumlar", &people, offset, &render),
+        "\n## 💬 Yorumlar\n\n> [!quote] abc · 2024-01-15 13:30\n> İlk satır\n>\n> ikinci\n\n> [!quote] Bot\n> Tamam\n"
+    );
+}
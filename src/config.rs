@@ -0,0 +1,598 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use toml::Table;
+use crate::anonymize::AnonymizeConfig;
+use crate::attachments::AttachmentsConfig;
+use crate::auth::AuthMethod;
+use crate::capabilities::Capabilities;
+use crate::cli::Overrides;
+use crate::credentials;
+use crate::icons::IconsConfig;
+use crate::jira::HttpConfig;
+use crate::kanban::{BoardConfig, BoardView};
+use crate::oauth::OAuthConfig;
+use crate::dashboards::DashboardsConfig;
+use crate::service_desk::ServiceDeskConfig;
+use crate::development::DevelopmentConfig;
+use crate::epics::EpicsConfig;
+use crate::flow::FlowConfig;
+use crate::person_notes::PersonNotesConfig;
+use crate::project_notes::ProjectNotesConfig;
+use crate::plugins::FieldRenderer;
+use crate::sprints::SprintsConfig;
+use crate::velocity::VelocityConfig;
+
+/// Runtime settings. Read from the optional config file first, then overridden
+/// by environment variables (and `.env`), then by command-line flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub jira_server: String,
+    pub jira_user: String,
+    pub jira_token: String,
+    pub vault_path: String,
+    /// Folder (relative to the vault) a ticket note is written into, e.g. `{project}`.
+    pub folder_template: String,
+    /// File name of a ticket note without the `.md` extension, e.g. `{key} {summary}`.
+    pub filename_template: String,
+    /// Language of the generated note labels (`tr`, `en`).
+    pub locale: String,
+    /// Per-label overrides on top of the locale, e.g. `status = "Estado"`.
+    pub labels: HashMap<String, String>,
+    /// Extra Jira fields fetched and written to note frontmatter as `jira_<field>`.
+    pub fields: Vec<String>,
+    /// Jira field (name or id) -> frontmatter key, e.g. `"Story Points" = "story_points"`.
+    pub custom_fields: BTreeMap<String, String>,
+    /// External commands that render extra fields into note sections.
+    pub field_renderers: Vec<FieldRenderer>,
+    /// Paths of `.wasm` plugins, run in order (needs the `wasm-plugins` feature).
+    pub wasm_plugins: Vec<String>,
+    /// Rhai script run for every issue after its note is rendered.
+    pub script: Option<String>,
+    /// Local Rhai expression an issue must satisfy on top of the JQL.
+    pub filter: Option<String>,
+    /// Target status name -> transition name to use when pushing status changes.
+    pub transitions: HashMap<String, String>,
+    /// Master switch for anything that changes data in Jira. Off by default.
+    pub allow_writes: bool,
+    /// Per-capability override of `allow_writes` for workflow transitions.
+    pub allow_transitions: Option<bool>,
+    /// Per-capability override of `allow_writes` for posting outbox comments.
+    pub allow_comments: Option<bool>,
+    /// Per-capability override of `allow_writes` for field edits (e.g. priority).
+    pub allow_edits: Option<bool>,
+    /// How often a vault write is retried when the file is locked (default 3).
+    pub write_retries: Option<u32>,
+    /// Pause between those retries in milliseconds (default 500).
+    pub write_retry_delay_ms: Option<u64>,
+    /// Only fetch issues updated since the last sync in full (default true);
+    /// `--full` refetches everything.
+    pub incremental: Option<bool>,
+    /// `--dry-run`: print what would be written instead of writing.
+    #[serde(skip)]
+    pub dry_run: bool,
+    /// `push --watch`: quiet time before collected edits are pushed (default 2000).
+    pub watch_debounce_ms: Option<u64>,
+    /// `push --watch`: vault-relative globs whose changes are ignored.
+    pub watch_ignore: Vec<String>,
+    /// Issue keys always shown at the top of their board column.
+    pub pinned: Vec<String>,
+    /// Issue type and priority icons in front of note titles and board cards.
+    pub icons: IconsConfig,
+    /// Column layout of the generated board.
+    pub board: BoardConfig,
+    /// Extra boards built from subsets of the same issues.
+    pub views: Vec<BoardView>,
+    /// Named JQL searches synced together; empty means the built-in "assigned to me".
+    pub queries: Vec<Query>,
+    /// Saved Jira filter searched instead of "assigned to me" when no queries are set.
+    pub filter_id: Option<u64>,
+    /// Request types and SLA timers for Jira Service Management projects.
+    pub service_desk: ServiceDeskConfig,
+    /// Write who watches each issue (`jira_watcher_names`), not just how many.
+    pub watcher_names: bool,
+    /// List each issue's remote links (Confluence pages, web links) in its note.
+    pub remote_links: bool,
+    /// Branches, commits and pull requests linked to each issue.
+    pub development: DevelopmentConfig,
+    /// Where issue attachments are downloaded to.
+    pub attachments: AttachmentsConfig,
+    /// Scrub people, e-mails, attachments and customer fields from generated notes.
+    pub anonymize: AnonymizeConfig,
+    /// accountId or Jira display name -> the name to show in notes.
+    pub people: HashMap<String, String>,
+    /// One note per assignee, optionally with their avatar.
+    pub person_notes: PersonNotesConfig,
+    /// One index note per project of the synced issues.
+    pub project_notes: ProjectNotesConfig,
+    /// One rollup note per epic of the synced issues.
+    pub epics: EpicsConfig,
+    /// Sprint name, state and dates in note frontmatter.
+    pub sprints: SprintsConfig,
+    /// Sprint velocity note from an Agile board.
+    pub velocity: VelocityConfig,
+    /// Summary notes regenerated on every sync.
+    pub dashboards: DashboardsConfig,
+    /// Daily per-status counts for a cumulative flow diagram.
+    pub flow: FlowConfig,
+    /// Minutes between syncs in `daemon` mode (default 60).
+    pub daemon_interval_minutes: Option<u64>,
+    /// REST API version: 3 on Cloud, 2 on Server/Data Center. Default: what
+    /// `capabilities` detected, else 3.
+    pub api_version: Option<u8>,
+    /// How to authenticate; default: detected on the first request and remembered.
+    pub auth: Option<AuthMethod>,
+    /// Proxy and TLS settings for reaching Jira.
+    pub http: HttpConfig,
+    /// Atlassian OAuth 2.0 app; when set, `login --oauth` replaces the API token.
+    pub oauth: OAuthConfig,
+    /// Further Jira hosts, each synced into its own subfolder of the vault.
+    pub instances: Vec<Instance>,
+    /// More vaults that get the same notes and boards from a single fetch.
+    pub vaults: Vec<VaultTarget>,
+}
+
+/// One of several JQL searches; issues found by more than one are synced once.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Query {
+    pub name: String,
+    #[serde(default)]
+    pub jql: String,
+    /// Saved Jira filter whose JQL is used instead of `jql`.
+    #[serde(default)]
+    pub filter_id: Option<u64>,
+    /// Optional board file listing only this query's issues.
+    #[serde(default)]
+    pub board: Option<String>,
+}
+
+/// Another Jira host synced after the main one. Everything it writes (notes,
+/// boards, `.jira-sync`) lives in its own subfolder, so keys can't collide.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Instance {
+    pub name: String,
+    pub jira_server: String,
+    pub jira_user: String,
+    pub jira_token: String,
+    /// Environment variable holding the token; keeps secrets out of the file.
+    pub token_env: Option<String>,
+    /// Subfolder of the vault (default: `name`).
+    pub folder: Option<String>,
+    /// Searches for this host; empty means "assigned to me".
+    pub queries: Vec<Query>,
+    pub auth: Option<AuthMethod>,
+    pub api_version: Option<u8>,
+}
+
+/// An extra vault written from the same fetch as `vault_path`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct VaultTarget {
+    pub path: String,
+    /// Note layout for this vault (default: the top-level `folder_template`).
+    pub folder_template: Option<String>,
+}
+
+/// Kinds of changes the tool can make in Jira, each individually gated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Capability {
+    Transitions,
+    Comments,
+    Edits,
+}
+
+impl Config {
+    /// `$JIRA_SYNC_CONFIG`, or `~/.config/jira-obsidian-sync/config.toml`.
+    pub fn file_path() -> Option<PathBuf> {
+        match env::var("JIRA_SYNC_CONFIG") {
+            Ok(path) => Some(PathBuf::from(path)),
+            Err(_) => dirs::config_dir().map(|d| d.join("jira-obsidian-sync").join("config.toml")),
+        }
+    }
+
+    /// Reads the config file, then the environment, then `overrides` from the
+    /// command line; a token missing from all of them comes from the keyring.
+    /// Every problem with the result is reported at once.
+    pub fn load(overrides: &Overrides) -> Result<Config, Box<dyn Error>> {
+        let mut config = Config::layered(overrides)?;
+        config.fill_defaults();
+        let problems = config.problems();
+        if !problems.is_empty() {
+            return Err(format!("Ayarlarda {} sorun var:\n  - {}", problems.len(), problems.join("\n  - ")).into());
+        }
+        if let Ok(capabilities) = Capabilities::load(&config.vault_path) {
+            capabilities.apply(&mut config);
+        }
+        Ok(config)
+    }
+
+    /// Takes a token missing from every layer from the keyring and fills in
+    /// the default file name template and locale.
+    pub fn fill_defaults(&mut self) {
+        if self.jira_token.is_empty() && !self.jira_server.is_empty() {
+            self.jira_token = credentials::token(self).unwrap_or_default();
+        }
+        if self.filename_template.is_empty() {
+            self.filename_template = "{key}".to_string();
+        }
+        if self.locale.is_empty() {
+            self.locale = "tr".to_string();
+        }
+    }
+
+    /// Missing and invalid settings, named the way they are set in `.env`
+    /// or the config file.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.jira_server.is_empty() {
+            problems.push("JIRA_SERVER (jira_server) eksik".to_string());
+        } else if self.jira_server.contains("://") && !self.jira_server.starts_with("http://") && !self.jira_server.starts_with("https://") {
+            problems.push(format!("JIRA_SERVER (jira_server) '{}' http:// ya da https:// ile başlamalı", self.jira_server));
+        }
+        if self.jira_token.is_empty() && !self.oauth.enabled() {
+            problems.push("JIRA_API_TOKEN (jira_token) eksik; token'ı anahtar zincirine kaydetmek için `login` çalıştırın".to_string());
+        }
+        if self.oauth.enabled() && self.oauth.client_secret.is_empty() {
+            problems.push("JIRA_OAUTH_CLIENT_SECRET (oauth.client_secret) eksik".to_string());
+        }
+        if self.vault_path.is_empty() {
+            problems.push("OBSIDIAN_PATH (vault_path) eksik".to_string());
+        }
+        if !["tr", "en"].contains(&self.locale.as_str()) {
+            problems.push(format!("NOTE_LOCALE (locale) '{}' desteklenmiyor; tr ya da en olmalı", self.locale));
+        }
+        for (i, query) in self.queries.iter().enumerate() {
+            if query.name.is_empty() || (query.jql.trim().is_empty() && query.filter_id.is_none()) {
+                problems.push(format!("queries[{}]: name ile jql ya da filter_id gerekli", i));
+            } else if !query.jql.trim().is_empty() && query.filter_id.is_some() {
+                problems.push(format!("queries[{}]: jql ve filter_id birlikte kullanılamaz", i));
+            } else if self.queries[..i].iter().any(|q| q.name == query.name) {
+                problems.push(format!("queries[{}]: '{}' adı birden fazla sorguda kullanılmış", i, query.name));
+            }
+        }
+        for (i, instance) in self.instances.iter().enumerate() {
+            if instance.name.is_empty() || instance.jira_server.is_empty() {
+                problems.push(format!("instances[{}]: name ve jira_server boş olamaz", i));
+            }
+        }
+        for (i, target) in self.vaults.iter().enumerate() {
+            if target.path.is_empty() {
+                problems.push(format!("vaults[{}]: path boş olamaz", i));
+            }
+        }
+        if let Some(version) = self.api_version.filter(|v| ![2, 3].contains(v)) {
+            problems.push(format!("JIRA_API_VERSION (api_version) {} desteklenmiyor; 2 ya da 3 olmalı", version));
+        }
+        if let Some(path) = self.http.ca_cert.as_deref().filter(|p| !Path::new(p).is_file()) {
+            problems.push(format!("JIRA_CA_CERT (http.ca_cert) {} bulunamadı", path));
+        }
+        problems
+    }
+
+    /// The settings from every layer, without checks or defaults.
+    pub fn layered(overrides: &Overrides) -> Result<Config, Box<dyn Error>> {
+        let profile = overrides.profile.clone().or_else(|| env::var("JIRA_SYNC_PROFILE").ok());
+        let mut config = match Config::file_path().filter(|p| p.exists()) {
+            Some(path) => {
+                let content = fs::read_to_string(&path).map_err(|e| format!("{} okunamadı: {}", path.display(), e))?;
+                let table: Table = toml::from_str(&content).map_err(|e| format!("{} geçersiz: {}", path.display(), e))?;
+                let table = select_profile(table, profile.as_deref()).map_err(|e| format!("{}: {}", path.display(), e))?;
+                table.try_into().map_err(|e| format!("{} geçersiz: {}", path.display(), e))?
+            }
+            None if profile.is_some() => return Err("--profile için bir config dosyası gerekli".into()),
+            None => Config::default(),
+        };
+        config.apply_env();
+        if let Ok(script) = env::var("JIRA_SYNC_SCRIPT") {
+            config.script = Some(script);
+        }
+        if let Ok(filter) = env::var("JIRA_FILTER") {
+            config.filter = Some(filter);
+        }
+        if let Some(id) = env::var("JIRA_FILTER_ID").ok().and_then(|v| v.trim().parse().ok()) {
+            config.filter_id = Some(id);
+        }
+        if let Ok(auth) = env::var("JIRA_AUTH") {
+            config.auth = Some(AuthMethod::parse(&auth).ok_or_else(|| format!("JIRA_AUTH '{}' geçersiz (basic, bearer, cookie)", auth))?);
+        }
+        if let Some(version) = env::var("JIRA_API_VERSION").ok().and_then(|v| v.trim().parse().ok()) {
+            config.api_version = Some(version);
+        }
+        if let Some(allow) = env_bool("JIRA_ALLOW_WRITES") {
+            config.allow_writes = allow;
+        }
+        if let Some(allow) = env_bool("JIRA_ALLOW_TRANSITIONS") {
+            config.allow_transitions = Some(allow);
+        }
+        if let Some(allow) = env_bool("JIRA_ALLOW_COMMENTS") {
+            config.allow_comments = Some(allow);
+        }
+        if let Some(anonymize) = env_bool("JIRA_ANONYMIZE") {
+            config.anonymize.enabled = anonymize;
+        }
+        if let Some(allow) = env_bool("JIRA_ALLOW_EDITS") {
+            config.allow_edits = Some(allow);
+        }
+        let flags = [
+            (&overrides.server, &mut config.jira_server),
+            (&overrides.user, &mut config.jira_user),
+            (&overrides.vault, &mut config.vault_path),
+            (&overrides.locale, &mut config.locale),
+            (&overrides.tickets_dir, &mut config.folder_template),
+        ];
+        for (flag, field) in flags {
+            if let Some(value) = flag {
+                *field = value.clone();
+            }
+        }
+        if let Some(jql) = &overrides.jql {
+            config.queries = vec![Query { name: "cli".to_string(), jql: jql.clone(), filter_id: None, board: None }];
+        }
+        if !overrides.fields.is_empty() {
+            config.fields = overrides.fields.clone();
+        }
+        config.dry_run = overrides.dry_run;
+        if overrides.full {
+            config.incremental = Some(false);
+        }
+        if overrides.board_name.is_some() {
+            config.board.file = overrides.board_name.clone();
+        }
+        Ok(config)
+    }
+
+    /// The settings to sync `instance` with: its host and credentials, its
+    /// queries, and its subfolder as the vault. Everything else is shared.
+    pub fn for_instance(&self, instance: &Instance) -> Result<Config, Box<dyn Error>> {
+        let mut config = self.clone();
+        config.jira_server = instance.jira_server.clone();
+        config.jira_user = instance.jira_user.clone();
+        config.jira_token = match &instance.token_env {
+            Some(name) => env::var(name).map_err(|_| format!("{} örneği için {} ortam değişkeni eksik", instance.name, name))?,
+            None => instance.jira_token.clone(),
+        };
+        if config.jira_token.is_empty() {
+            config.jira_token = credentials::token(&config).unwrap_or_default();
+        }
+        let folder = instance.folder.as_deref().unwrap_or(&instance.name);
+        config.vault_path = PathBuf::from(&self.vault_path).join(folder).to_string_lossy().into_owned();
+        config.queries = instance.queries.clone();
+        config.instances = Vec::new();
+        // Bu sunucunun kendi algılanan sürümü ve kimlik doğrulama yöntemi geçerli.
+        config.auth = instance.auth;
+        config.api_version = instance.api_version;
+        if let Ok(capabilities) = Capabilities::load(&config.vault_path) {
+            capabilities.apply(&mut config);
+        }
+        for target in &mut config.vaults {
+            target.path = PathBuf::from(&target.path).join(folder).to_string_lossy().into_owned();
+        }
+        Ok(config)
+    }
+
+    /// The settings to write into `target`; only the vault and layout differ.
+    pub fn for_vault(&self, target: &VaultTarget) -> Config {
+        let mut config = self.clone();
+        config.vault_path = target.path.clone();
+        if let Some(template) = &target.folder_template {
+            config.folder_template = template.clone();
+        }
+        config.vaults = Vec::new();
+        config
+    }
+
+    /// Where Jira lives: `jira_server` when it is a full URL such as
+    /// `http://jira.internal:8080/jira`, otherwise `https://` plus the host.
+    pub fn base_url(&self) -> String {
+        if self.jira_server.contains("://") {
+            self.jira_server.trim_end_matches('/').to_string()
+        } else {
+            format!("https://{}", self.jira_server.trim_end_matches('/'))
+        }
+    }
+
+    pub fn incremental(&self) -> bool {
+        self.incremental.unwrap_or(true)
+    }
+
+    pub fn api_version(&self) -> u8 {
+        self.api_version.unwrap_or(3)
+    }
+
+    pub fn allows(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Transitions => self.allow_transitions.unwrap_or(self.allow_writes),
+            Capability::Comments => self.allow_comments.unwrap_or(self.allow_writes),
+            Capability::Edits => self.allow_edits.unwrap_or(self.allow_writes),
+        }
+    }
+
+    /// Whether any outbound capability is enabled at all.
+    pub fn allows_any_write(&self) -> bool {
+        [Capability::Transitions, Capability::Comments, Capability::Edits]
+            .into_iter()
+            .any(|capability| self.allows(capability))
+    }
+
+    fn apply_env(&mut self) {
+        let vars = [
+            ("JIRA_SERVER", &mut self.jira_server),
+            ("JIRA_USER", &mut self.jira_user),
+            ("JIRA_API_TOKEN", &mut self.jira_token),
+            ("OBSIDIAN_PATH", &mut self.vault_path),
+            ("NOTE_FOLDER_TEMPLATE", &mut self.folder_template),
+            ("NOTE_FILENAME_TEMPLATE", &mut self.filename_template),
+            ("NOTE_LOCALE", &mut self.locale),
+            ("JIRA_OAUTH_CLIENT_ID", &mut self.oauth.client_id),
+            ("JIRA_OAUTH_CLIENT_SECRET", &mut self.oauth.client_secret),
+        ];
+        for (name, field) in vars {
+            if let Ok(value) = env::var(name) {
+                *field = value;
+            }
+        }
+        if let Ok(pairs) = env::var("NOTE_LABELS") {
+            for pair in pairs.split(';') {
+                if let Some((name, value)) = pair.split_once('=') {
+                    self.labels.insert(name.trim().to_string(), value.trim().to_string());
+                }
+            }
+        }
+        if let Ok(path) = env::var("JIRA_CA_CERT") {
+            self.http.ca_cert = Some(path);
+        }
+    }
+}
+
+/// Lays the chosen profile from `[profiles.<name>]` over the top-level
+/// settings; without `--profile`, `default_profile` picks one.
+fn select_profile(mut table: Table, name: Option<&str>) -> Result<Table, String> {
+    let profiles = match table.remove("profiles") {
+        Some(toml::Value::Table(profiles)) => profiles,
+        Some(_) => return Err("`profiles` bir tablo olmalı".to_string()),
+        None => Table::new(),
+    };
+    let default = table.remove("default_profile");
+    let Some(name) = name.map(str::to_string).or_else(|| default.and_then(|v| v.as_str().map(str::to_string))) else {
+        return Ok(table);
+    };
+    match profiles.get(&name) {
+        Some(toml::Value::Table(profile)) => {
+            merge(&mut table, profile.clone());
+            Ok(table)
+        }
+        _ => {
+            let known: Vec<&String> = profiles.keys().collect();
+            Err(format!("'{}' profili yok (tanımlı profiller: {:?})", name, known))
+        }
+    }
+}
+
+/// Nested tables are merged key by key; any other value replaces the base one.
+fn merge(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => merge(base, overlay),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn env_bool(name: &str) -> Option<bool> {
+    env::var(name).ok().map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+}
+
+#[cfg(test)]
+#[test]
+fn test_write_capabilities() {
+    let mut config = Config::default();
+    assert!(!config.allows(Capability::Transitions));
+    assert!(!config.allows_any_write());
+
+    config.allow_writes = true;
+    assert!(config.allows(Capability::Transitions));
+
+    config.allow_transitions = Some(false);
+    assert!(!config.allows(Capability::Transitions));
+    assert!(config.allows_any_write());
+
+    config.allow_comments = Some(false);
+    config.allow_edits = Some(false);
+    assert!(!config.allows_any_write());
+}
+
+#[cfg(test)]
+#[test]
+fn test_select_profile() {
+    let file: Table = toml::from_str(
+        r#"
+        jira_server = "company.atlassian.net"
+        default_profile = "work"
+        [board]
+        unmapped_column = "Other"
+        [profiles.work]
+        vault_path = "/vaults/work"
+        [profiles.home]
+        jira_server = "jira.home.lan"
+        vault_path = "/vaults/home"
+        [profiles.home.board]
+        flagged_field = "customfield_1"
+        "#,
+    )
+    .unwrap();
+
+    let config: Config = select_profile(file.clone(), None).unwrap().try_into().unwrap();
+    assert_eq!((config.jira_server.as_str(), config.vault_path.as_str()), ("company.atlassian.net", "/vaults/work"));
+
+    let config: Config = select_profile(file.clone(), Some("home")).unwrap().try_into().unwrap();
+    assert_eq!((config.jira_server.as_str(), config.vault_path.as_str()), ("jira.home.lan", "/vaults/home"));
+    assert_eq!(config.board.unmapped_column.as_deref(), Some("Other"));
+    assert_eq!(config.board.flagged_field(), "customfield_1");
+
+    assert!(select_profile(file, Some("missing")).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn test_derived_configs() {
+    let base = Config {
+        jira_server: "company.atlassian.net".into(),
+        jira_token: "cloud-token".into(),
+        vault_path: "/vault".into(),
+        locale: "en".into(),
+        ..Default::default()
+    };
+    let instance = Instance {
+        name: "onprem".into(),
+        jira_server: "jira.corp.lan".into(),
+        jira_token: "pat".into(),
+        ..Default::default()
+    };
+    let config = base.for_instance(&instance).unwrap();
+    assert_eq!(config.jira_server, "jira.corp.lan");
+    assert_eq!(config.jira_token, "pat");
+    assert_eq!(Path::new(&config.vault_path), Path::new("/vault/onprem"));
+    assert_eq!(config.locale, "en");
+
+    assert_eq!(base.base_url(), "https://company.atlassian.net");
+    let proxied = Config { jira_server: "http://jira.internal:8080/jira/".into(), ..Default::default() };
+    assert_eq!(proxied.base_url(), "http://jira.internal:8080/jira");
+
+    let personal = base.for_vault(&VaultTarget { path: "/personal".into(), folder_template: Some("Work/{project}".into()) });
+    assert_eq!(personal.vault_path, "/personal");
+    assert_eq!(personal.folder_template, "Work/{project}");
+    assert_eq!(personal.jira_token, "cloud-token");
+}
+
+#[cfg(test)]
+#[test]
+fn test_config_problems() {
+    let config = Config {
+        locale: "de".into(),
+        queries: vec![
+            Query { name: "mine".into(), jql: "assignee = currentUser()".into(), filter_id: None, board: None },
+            Query { name: "mine".into(), jql: String::new(), filter_id: Some(10042), board: None },
+        ],
+        ..Default::default()
+    };
+    let problems = config.problems();
+    assert_eq!(problems.len(), 5, "{:?}", problems);
+    assert!(problems[0].starts_with("JIRA_SERVER"));
+    assert!(problems[4].starts_with("queries[1]"));
+
+    let config = Config {
+        jira_server: "company.atlassian.net".into(),
+        vault_path: "/vault".into(),
+        locale: "en".into(),
+        oauth: OAuthConfig { client_id: "app".into(), client_secret: "secret".into(), ..Default::default() },
+        ..Default::default()
+    };
+    assert!(config.problems().is_empty());
+}
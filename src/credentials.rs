@@ -0,0 +1,88 @@
+use std::error::Error;
+use keyring::Entry;
+use serde_json::Value;
+use crate::cli::Overrides;
+use crate::config::Config;
+use crate::jira::JiraClient;
+
+/// Service name the token is filed under in the OS keyring.
+const SERVICE: &str = "jira-obsidian-sync";
+
+/// One keyring entry per Jira host and user, so profiles and instances don't
+/// overwrite each other's tokens.
+pub fn account(config: &Config) -> String {
+    account_name(&config.jira_server, &config.jira_user)
+}
+
+fn account_name(server: &str, user: &str) -> String {
+    if user.is_empty() {
+        server.to_string()
+    } else {
+        format!("{}@{}", user, server)
+    }
+}
+
+/// The secret stored under `account`, if any. A missing or unavailable
+/// keyring just means there is none.
+pub fn get(account: &str) -> Option<String> {
+    Entry::new(SERVICE, account).ok()?.get_password().ok()
+}
+
+pub fn set(account: &str, secret: &str) -> keyring::Result<()> {
+    Entry::new(SERVICE, account)?.set_password(secret)
+}
+
+/// The token `login` stored for this host and user.
+pub fn token(config: &Config) -> Option<String> {
+    get(&account(config))
+}
+
+/// `login`: asks for the API token, checks it against Jira and keeps it in
+/// the OS keyring (Keychain, Credential Manager or Secret Service).
+/// With `oauth`, signs in through the browser instead (see [`crate::oauth`]).
+pub async fn login(overrides: &Overrides, oauth: bool) -> Result<(), Box<dyn Error>> {
+    let mut config = Config::layered(overrides)?;
+    if config.jira_server.is_empty() {
+        return Err("Önce JIRA_SERVER (ya da --server) ayarlayın".into());
+    }
+    if oauth {
+        return crate::oauth::login(&config).await;
+    }
+    let who = account(&config);
+    config.jira_token = rpassword::prompt_password(format!("🔑 {} için API token: ", who))?.trim().to_string();
+    if config.jira_token.is_empty() {
+        return Err("Token boş".into());
+    }
+
+    let me: Value = JiraClient::new(&config)?.get_json("/rest/api/3/myself").await?;
+    set(&who, &config.jira_token)?;
+    println!(
+        "✅ {} olarak giriş yapıldı; token anahtar zincirine kaydedildi ({}).",
+        me["displayName"].as_str().unwrap_or("?"),
+        who
+    );
+    println!("   Artık .env dosyasındaki JIRA_API_TOKEN satırını silebilirsiniz.");
+    Ok(())
+}
+
+/// `logout`: removes the stored token and OAuth session.
+pub fn logout(overrides: &Overrides) -> Result<(), Box<dyn Error>> {
+    let config = Config::layered(overrides)?;
+    let who = account(&config);
+    for key in [crate::oauth::account(&config), who.clone()] {
+        if let Err(e) = Entry::new(SERVICE, &key)?.delete_credential() {
+            if !matches!(e, keyring::Error::NoEntry) {
+                return Err(e.into());
+            }
+        }
+    }
+    println!("🗝️  {} için kayıtlı oturum ve token silindi.", who);
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn test_keyring_account() {
+    assert_eq!(account_name("company.atlassian.net", "ayse@example.com"), "ayse@example.com@company.atlassian.net");
+    assert_eq!(account_name("jira.corp.lan", ""), "jira.corp.lan");
+}
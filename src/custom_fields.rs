@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use serde_json::Value;
+use crate::config::Config;
+use crate::frontmatter;
+use crate::jira::JiraClient;
+use crate::Issue;
+use tracing::warn;
+
+/// `config` with the field names in `custom_fields` replaced by their ids,
+/// looked up with one request to `/field`. Names Jira doesn't know are
+/// reported and dropped.
+pub async fn resolve(config: &Config, client: &JiraClient) -> Config {
+    let mut config = config.clone();
+    if config.custom_fields.keys().all(|field| is_id(field)) {
+        return config;
+    }
+    let fields: Vec<Value> = match client.get_json("/rest/api/3/field").await {
+        Ok(fields) => fields,
+        Err(e) => {
+            warn!("⚠️  Alan listesi alınamadı, özel alanlar atlanıyor: {}", e);
+            config.custom_fields.retain(|field, _| is_id(field));
+            return config;
+        }
+    };
+    let known: Vec<(&str, &str)> = fields.iter().filter_map(|f| Some((f["id"].as_str()?, f["name"].as_str()?))).collect();
+    let (resolved, unknown) = resolve_names(&config.custom_fields, &known);
+    for name in unknown {
+        warn!("⚠️  '{}' adında bir alan yok; custom_fields içinden atlandı", name);
+    }
+    config.custom_fields = resolved;
+    config
+}
+
+fn is_id(field: &str) -> bool {
+    field.strip_prefix("customfield_").is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Maps each configured field (id or case-insensitive name) to its id.
+fn resolve_names(custom: &BTreeMap<String, String>, known: &[(&str, &str)]) -> (BTreeMap<String, String>, Vec<String>) {
+    let mut resolved = BTreeMap::new();
+    let mut unknown = Vec::new();
+    for (field, key) in custom {
+        match known.iter().find(|(id, name)| *id == field || name.eq_ignore_ascii_case(field)) {
+            Some((id, _)) => {
+                resolved.insert(id.to_string(), key.clone());
+            }
+            None if is_id(field) => {
+                resolved.insert(field.clone(), key.clone());
+            }
+            None => unknown.push(field.clone()),
+        }
+    }
+    (resolved, unknown)
+}
+
+/// One `key: value` frontmatter line per mapped field that has a value.
+pub fn frontmatter(issue: &Issue, config: &Config) -> String {
+    config
+        .custom_fields
+        .iter()
+        .filter_map(|(id, key)| Some(format!("{}: {}\n", key, frontmatter::value(issue.fields.extra.get(id)?)?)))
+        .collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_resolve_custom_field_names() {
+    let custom: BTreeMap<String, String> = [("Story Points", "story_points"), ("customfield_10050", "customer"), ("Team", "team"), ("Nope", "nope")]
+        .map(|(f, k)| (f.to_string(), k.to_string()))
+        .into();
+    let known = [("customfield_10016", "Story Points"), ("customfield_10001", "team")];
+
+    let (resolved, unknown) = resolve_names(&custom, &known);
+    let expected: BTreeMap<String, String> = [("customfield_10016", "story_points"), ("customfield_10050", "customer"), ("customfield_10001", "team")]
+        .map(|(f, k)| (f.to_string(), k.to_string()))
+        .into();
+    assert_eq!(resolved, expected);
+    assert_eq!(unknown, ["Nope"]);
+    assert!(!is_id("customfield_") && !is_id("Story Points"));
+}
@@ -0,0 +1,405 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::path::Path;
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate};
+use serde::Deserialize;
+use serde_json::Value;
+use crate::i18n::Labels;
+use crate::jira::JiraClient;
+use crate::kanban::is_flagged;
+use crate::metrics::parse_time;
+use crate::{Issue, NoteContext, User};
+use tracing::{info, warn};
+
+/// `[dashboards]` in the config file: summary notes regenerated on every sync.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DashboardsConfig {
+    /// "Jira Dependencies.md": synced issues blocked by another project's issues.
+    pub dependencies: bool,
+    /// "Jira Blocked.md": flagged issues and issues with unresolved blockers.
+    pub blocked: bool,
+    /// "Jira Due.md": overdue issues and those due within `due_days`.
+    pub due: bool,
+    /// How far ahead the due dashboard looks (default 7 days).
+    pub due_days: Option<i64>,
+    /// "Jira Tags.md": every label and component with links to its issues.
+    pub tags: bool,
+    /// "Jira Index.md": Dataview tables over all ticket notes.
+    pub index: bool,
+}
+
+impl DashboardsConfig {
+    fn any(&self) -> bool {
+        self.dependencies || self.blocked || self.due || self.tags || self.index
+    }
+}
+
+/// An unresolved issue another issue "is blocked by".
+#[derive(Debug, PartialEq)]
+struct Blocker {
+    key: String,
+    summary: String,
+    status: String,
+}
+
+/// Writes the enabled dashboards. Blocker owners are looked up with one
+/// extra search, since issue links carry no assignee.
+pub async fn write(
+    ctx: &NoteContext<'_>,
+    client: &JiraClient,
+    issues: &[Issue],
+    note_links: &HashMap<String, String>,
+) -> Result<(), Box<dyn Error>> {
+    let settings = &ctx.config.dashboards;
+    if !settings.any() {
+        return Ok(());
+    }
+
+    let blocked: Vec<(&Issue, Vec<Blocker>)> = issues.iter().map(|i| (i, blockers(i))).collect();
+    let mut keys: Vec<&str> = blocked.iter().flat_map(|(_, b)| b.iter().map(|b| b.key.as_str())).collect();
+    keys.sort();
+    keys.dedup();
+    let owners = owners(ctx, client, &keys).await?;
+    let link = |key: &str| match note_links.get(key) {
+        Some(target) if target != key => format!("[[{}|{}]]", target, key),
+        Some(_) => format!("[[{}]]", key),
+        None => format!("[{}]({}/browse/{})", key, ctx.config.base_url(), key),
+    };
+
+    if settings.dependencies {
+        let note = dependencies(&ctx.labels.dependencies, &blocked, &owners, link);
+        ctx.writer.write(&Path::new(&ctx.config.vault_path).join("Jira Dependencies.md"), &note)?;
+        info!("🔗 Bağımlılık notu güncellendi: Jira Dependencies.md");
+    }
+    if settings.blocked {
+        let mut entries = Vec::new();
+        for (issue, blockers) in &blocked {
+            let flagged = is_flagged(issue, &ctx.config.board);
+            if flagged || !blockers.is_empty() {
+                let since = blocked_since(client, &issue.key).await.unwrap_or_else(|e| {
+                    warn!("⚠️  {} geçmişi okunamadı: {}", issue.key, e);
+                    None
+                });
+                entries.push(BlockedEntry { issue, flagged, blockers, since });
+            }
+        }
+        let note = blocked_note(&ctx.labels, &entries, &owners, Local::now().fixed_offset(), link);
+        ctx.writer.write(&Path::new(&ctx.config.vault_path).join("Jira Blocked.md"), &note)?;
+        info!("⛔ Engellenen işler notu güncellendi: Jira Blocked.md ({} iş)", entries.len());
+    }
+    if settings.due {
+        let note = due_note(&ctx.labels, issues, Local::now().date_naive(), settings.due_days.unwrap_or(7), link);
+        ctx.writer.write(&Path::new(&ctx.config.vault_path).join("Jira Due.md"), &note)?;
+        info!("📅 Teslim tarihleri notu güncellendi: Jira Due.md");
+    }
+    if settings.tags {
+        let note = tags_note(&ctx.labels, issues, link);
+        ctx.writer.write(&Path::new(&ctx.config.vault_path).join("Jira Tags.md"), &note)?;
+        info!("🏷️  Etiket dizini güncellendi: Jira Tags.md");
+    }
+    if settings.index {
+        ctx.writer.write(&Path::new(&ctx.config.vault_path).join("Jira Index.md"), &index_note(&ctx.labels))?;
+        info!("🗂️  Dataview dizini güncellendi: Jira Index.md");
+    }
+    Ok(())
+}
+
+/// A synced issue that is flagged, blocked by another issue, or both.
+struct BlockedEntry<'a> {
+    issue: &'a Issue,
+    flagged: bool,
+    blockers: &'a [Blocker],
+    /// When the flag or the latest "is blocked by" link was added.
+    since: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChangelogPage {
+    #[serde(default)]
+    values: Vec<Value>,
+    #[serde(rename = "isLast", default)]
+    is_last: bool,
+}
+
+/// Time of the last history entry that flagged the issue or added a blocker.
+async fn blocked_since(client: &JiraClient, key: &str) -> Result<Option<DateTime<FixedOffset>>, Box<dyn Error>> {
+    let mut since = None;
+    let mut start = 0;
+    loop {
+        let page: ChangelogPage = client
+            .get_json(&format!("/rest/api/3/issue/{}/changelog?startAt={}&maxResults=100", key, start))
+            .await?;
+        for entry in &page.values {
+            let blocking = entry["items"].as_array().into_iter().flatten().any(|item| {
+                let added = item["toString"].as_str().unwrap_or_default();
+                match item["field"].as_str() {
+                    Some("Flagged") => !added.is_empty(),
+                    Some("Link") => added.to_lowercase().contains("is blocked by"),
+                    _ => false,
+                }
+            });
+            if blocking {
+                since = entry["created"].as_str().and_then(parse_time).or(since);
+            }
+        }
+        start += page.values.len();
+        if page.is_last || page.values.is_empty() {
+            return Ok(since);
+        }
+    }
+}
+
+/// Unresolved issues linked to `issue` with "is blocked by".
+fn blockers(issue: &Issue) -> Vec<Blocker> {
+    let Some(Value::Array(links)) = issue.fields.extra.get("issuelinks") else {
+        return Vec::new();
+    };
+    links
+        .iter()
+        .filter(|link| link["type"]["inward"].as_str().is_some_and(|t| t.eq_ignore_ascii_case("is blocked by")))
+        .filter_map(|link| link.get("inwardIssue"))
+        .filter(|other| other["fields"]["status"]["statusCategory"]["key"] != "done")
+        .filter_map(|other| {
+            Some(Blocker {
+                key: other["key"].as_str()?.to_string(),
+                summary: other["fields"]["summary"].as_str().unwrap_or_default().to_string(),
+                status: other["fields"]["status"]["name"].as_str().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Display name of each issue's assignee.
+async fn owners(ctx: &NoteContext<'_>, client: &JiraClient, keys: &[&str]) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    if keys.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let jql = format!("key in ({})", keys.join(","));
+    let Some(found) = crate::search(client, &jql, &["assignee"]).await? else {
+        return Ok(HashMap::new());
+    };
+    Ok(found
+        .iter()
+        .filter_map(|issue| {
+            let user: User = serde_json::from_value(issue["fields"]["assignee"].clone()).ok()?;
+            Some((issue["key"].as_str()?.to_string(), ctx.people.name(user.id())))
+        })
+        .collect())
+}
+
+fn project(key: &str) -> &str {
+    key.rsplit_once('-').map_or(key, |(project, _)| project)
+}
+
+/// Blockers from other projects, grouped by the blocking project.
+fn dependencies(
+    title: &str,
+    blocked: &[(&Issue, Vec<Blocker>)],
+    owners: &HashMap<String, String>,
+    link: impl Fn(&str) -> String,
+) -> String {
+    let mut by_project: BTreeMap<&str, Vec<(&Issue, &Blocker)>> = BTreeMap::new();
+    for (issue, blockers) in blocked {
+        for blocker in blockers.iter().filter(|b| project(&b.key) != project(&issue.key)) {
+            by_project.entry(project(&blocker.key)).or_default().push((issue, blocker));
+        }
+    }
+
+    let mut note = format!("---\ntags: [jira, dependencies]\n---\n# {}\n", title);
+    for (project, entries) in by_project {
+        note.push_str(&format!("\n## {} ({})\n\n", project, entries.len()));
+        for (issue, blocker) in entries {
+            let owner = owners.get(&blocker.key).map(|o| format!(" · 👤 {}", o)).unwrap_or_default();
+            note.push_str(&format!(
+                "- {} ⛔ {} {} · `{}`{}\n",
+                link(&issue.key),
+                link(&blocker.key),
+                blocker.summary,
+                blocker.status,
+                owner
+            ));
+        }
+    }
+    note
+}
+
+/// Longest-blocked first; blockers are listed under each issue.
+fn blocked_note(
+    labels: &Labels,
+    entries: &[BlockedEntry],
+    owners: &HashMap<String, String>,
+    now: DateTime<FixedOffset>,
+    link: impl Fn(&str) -> String,
+) -> String {
+    let mut entries: Vec<&BlockedEntry> = entries.iter().collect();
+    entries.sort_by_key(|e| e.since.unwrap_or(now));
+
+    let mut note = format!("---\ntags: [jira, blocked]\n---\n# {} ({})\n\n", labels.blocked, entries.len());
+    for entry in entries {
+        let days = entry.since.map(|since| format!(" · ⏳ {} {}", (now - since).num_days(), labels.days)).unwrap_or_default();
+        note.push_str(&format!("- {} {}{}\n", link(&entry.issue.key), entry.issue.fields.summary, days));
+        if entry.flagged {
+            note.push_str(&format!("    - 🚩 {}\n", labels.flagged));
+        }
+        for blocker in entry.blockers {
+            let owner = owners.get(&blocker.key).map(|o| format!(" · 👤 {}", o)).unwrap_or_default();
+            note.push_str(&format!("    - ⛔ {} {} · `{}`{}\n", link(&blocker.key), blocker.summary, blocker.status, owner));
+        }
+    }
+    note
+}
+
+/// Open issues past their due date, then those due in the next `days` days
+/// grouped by day, then a Tasks plugin query for the same window.
+fn due_note(labels: &Labels, issues: &[Issue], today: NaiveDate, days: i64, link: impl Fn(&str) -> String) -> String {
+    let mut by_day: BTreeMap<NaiveDate, Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        if issue.fields.status.status_category.as_ref().is_some_and(|c| c.key == "done") {
+            continue;
+        }
+        let due = issue.fields.duedate.as_deref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+        if let Some(due) = due.filter(|due| *due <= today + Duration::days(days)) {
+            by_day.entry(due).or_default().push(issue);
+        }
+    }
+    let line = |issue: &Issue| format!("- {} {}\n", link(&issue.key), issue.fields.summary);
+
+    let overdue: Vec<&Issue> = by_day.range(..today).flat_map(|(_, issues)| issues.iter().copied()).collect();
+    let mut note = format!("---\ntags: [jira, due]\n---\n# {}\n\n## {} ({})\n\n", labels.deadlines, labels.overdue_issues, overdue.len());
+    for issue in overdue {
+        note.push_str(&format!("- {} {} · 📅 {}\n", link(&issue.key), issue.fields.summary, issue.fields.duedate.as_deref().unwrap_or_default()));
+    }
+    note.push_str(&format!("\n## {}\n", labels.due_soon));
+    for (day, issues) in by_day.range(today..) {
+        note.push_str(&format!("\n### {}\n\n", day.format("%Y-%m-%d (%a)")));
+        issues.iter().for_each(|issue| note.push_str(&line(issue)));
+    }
+    note.push_str(&format!("\n```tasks\nnot done\ndue before in {} days\ngroup by due\n```\n", days + 1));
+    note
+}
+
+/// Each label and component with its issue count and links, alphabetically.
+fn tags_note(labels: &Labels, issues: &[Issue], link: impl Fn(&str) -> String) -> String {
+    let mut by_label: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    let mut by_component: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        for label in &issue.fields.labels {
+            by_label.entry(label).or_default().push(issue);
+        }
+        let components = issue.fields.extra.get("components").and_then(Value::as_array);
+        for component in components.into_iter().flatten().filter_map(|c| c["name"].as_str()) {
+            by_component.entry(component).or_default().push(issue);
+        }
+    }
+
+    let mut note = format!("---\ntags: [jira, index]\n---\n# {}\n", labels.tag_index);
+    for (heading, groups) in [(&labels.labels, by_label), (&labels.components, by_component)] {
+        note.push_str(&format!("\n## {}\n", heading));
+        for (name, issues) in groups {
+            note.push_str(&format!("\n### {} ({})\n\n", name, issues.len()));
+            for issue in issues {
+                note.push_str(&format!("- {} {}\n", link(&issue.key), issue.fields.summary));
+            }
+        }
+    }
+    note
+}
+
+/// Dataview queries over the ticket notes' frontmatter. The note itself never
+/// changes between syncs; Dataview keeps the tables current.
+fn index_note(labels: &Labels) -> String {
+    let table = |filter: &str, sort: &str| {
+        format!(
+            "```dataview\nTABLE jira_status AS \"{}\", jira_priority AS \"{}\", jira_due AS \"{}\", jira_assignee AS \"{}\"\nFROM #jira AND #task\n{}SORT {}\n```\n",
+            labels.status, labels.priority, labels.due, labels.assignee, filter, sort
+        )
+    };
+    let mut note = format!("---\ntags: [jira, index]\n---\n# {}\n", labels.index);
+    for (heading, filter, sort) in [
+        (&labels.open_issues, "WHERE jira_status_category != \"done\"\n", "jira_status ASC"),
+        (&labels.overdue_issues, "WHERE jira_due AND jira_due < date(today) AND jira_status_category != \"done\"\n", "jira_due ASC"),
+        (&labels.recently_updated, "WHERE jira_days_since_update <= 7\n", "jira_days_since_update ASC"),
+        (&labels.unassigned, "WHERE !jira_assignee AND jira_status_category != \"done\"\n", "jira_priority ASC"),
+        (&labels.all_issues, "", "jira_key ASC"),
+    ] {
+        note.push_str(&format!("\n## {}\n\n{}", heading, table(filter, sort)));
+    }
+    note
+}
+
+#[cfg(test)]
+#[test]
+fn test_cross_project_dependencies() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "APP-1",
+        "fields": {
+            "summary": "x",
+            "status": { "name": "To Do" },
+            "issuelinks": [
+                { "type": { "inward": "is blocked by", "outward": "blocks" },
+                  "inwardIssue": { "key": "API-7", "fields": { "summary": "New endpoint", "status": { "name": "In Progress", "statusCategory": { "key": "indeterminate" } } } } },
+                { "type": { "inward": "is blocked by", "outward": "blocks" },
+                  "inwardIssue": { "key": "API-2", "fields": { "summary": "Old", "status": { "name": "Done", "statusCategory": { "key": "done" } } } } },
+                { "type": { "inward": "is blocked by", "outward": "blocks" },
+                  "inwardIssue": { "key": "APP-3", "fields": { "summary": "Same team", "status": { "name": "To Do" } } } },
+                { "type": { "inward": "is cloned by", "outward": "clones" },
+                  "inwardIssue": { "key": "WEB-1", "fields": { "summary": "Clone", "status": { "name": "To Do" } } } }
+            ]
+        }
+    }))
+    .unwrap();
+    let found = blockers(&issue);
+    assert_eq!(found.iter().map(|b| b.key.as_str()).collect::<Vec<_>>(), ["API-7", "APP-3"]);
+
+    let owners: HashMap<String, String> = [("API-7".to_string(), "Bora".to_string())].into();
+    let note = dependencies("Deps", &[(&issue, found)], &owners, |key| format!("[[{}]]", key));
+    assert!(note.contains("## API (1)\n\n- [[APP-1]] ⛔ [[API-7]] New endpoint · `In Progress` · 👤 Bora\n"));
+    assert!(!note.contains("## APP"));
+
+    let now = parse_time("2025-06-10T09:00:00.000+0000").unwrap();
+    let found = blockers(&issue);
+    let entries = [BlockedEntry { issue: &issue, flagged: true, blockers: &found, since: parse_time("2025-06-01T09:00:00.000+0000") }];
+    let note = blocked_note(&Labels::for_locale("en"), &entries, &owners, now, |key| format!("[[{}]]", key));
+    assert!(note.contains("- [[APP-1]] x · ⏳ 9 days\n    - 🚩 flagged\n    - ⛔ [[API-7]] New endpoint · `In Progress` · 👤 Bora\n"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_due_note() {
+    let issue = |key: &str, due: &str, category: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "duedate": due, "status": { "name": "s", "statusCategory": { "key": category, "name": "" } } }
+        }))
+        .unwrap()
+    };
+    let issues = vec![
+        issue("A-1", "2025-05-30", "new"),
+        issue("A-2", "2025-05-30", "done"),
+        issue("A-3", "2025-06-03", "indeterminate"),
+        issue("A-4", "2025-06-20", "new"),
+    ];
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let note = due_note(&Labels::for_locale("en"), &issues, today, 7, |key| format!("[[{}]]", key));
+    assert!(note.contains("## Overdue Issues (1)\n\n- [[A-1]] A-1 · 📅 2025-05-30\n"));
+    assert!(note.contains("### 2025-06-03 (Tue)\n\n- [[A-3]] A-3\n"));
+    assert!(!note.contains("A-2") && !note.contains("A-4"));
+    assert!(note.contains("due before in 8 days"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_tags_note() {
+    let issue = |key: &str, labels: &[&str], component: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "labels": labels, "components": [{ "name": component }], "status": { "name": "s" } }
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("A-1", &["infra", "api"], "Backend"), issue("A-2", &["infra"], "Web")];
+    let note = tags_note(&Labels::for_locale("en"), &issues, |key| format!("[[{}]]", key));
+    assert!(note.contains("## Labels\n\n### api (1)\n\n- [[A-1]] A-1\n\n### infra (2)\n\n- [[A-1]] A-1\n- [[A-2]] A-2\n"));
+    assert!(note.contains("## Components\n\n### Backend (1)\n"));
+}
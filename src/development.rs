@@ -0,0 +1,137 @@
+use std::error::Error;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use crate::i18n::Labels;
+use crate::jira::JiraClient;
+use crate::Issue;
+use tracing::warn;
+
+/// `[development]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DevelopmentConfig {
+    /// Ask the dev-status API for each issue's branches, commits and pull
+    /// requests. Costs one request per issue, plus one per connected tool.
+    pub enabled: bool,
+}
+
+/// Kinds of development data: the dev-status `dataType`, and where its
+/// entries sit in a detail response.
+const DATA_TYPES: [(&str, &str); 3] = [("pullrequest", "pullRequests"), ("branch", "branches"), ("repository", "repositories")];
+
+/// Adds `development` (pull requests, branches, repositories with their
+/// commits) to every issue Jira knows development work for. Failures only
+/// cost that issue's section.
+pub async fn resolve(client: &JiraClient, issues: &mut [Value]) {
+    for issue in issues {
+        let Some(id) = issue["id"].as_str().map(str::to_string) else { continue };
+        match fetch(client, &id).await {
+            Ok(Some(development)) => issue["development"] = development,
+            Ok(None) => {}
+            Err(e) => warn!("⚠️  {} geliştirme bilgisi alınamadı: {}", issue["key"].as_str().unwrap_or_default(), e),
+        }
+    }
+}
+
+/// The summary says which tools (GitHub, Bitbucket, ...) have what; details
+/// are only asked for where there is something.
+async fn fetch(client: &JiraClient, issue_id: &str) -> Result<Option<Value>, Box<dyn Error>> {
+    let summary: Value = client.get_json(&format!("/rest/dev-status/latest/issue/summary?issueId={}", issue_id)).await?;
+    let mut development = json!({ "pullRequests": [], "branches": [], "repositories": [] });
+    let mut found = false;
+    for (data_type, list) in DATA_TYPES {
+        let Some(tools) = summary["summary"][data_type]["byInstanceType"].as_object() else { continue };
+        for (tool, info) in tools {
+            if info["count"].as_u64().unwrap_or(0) == 0 {
+                continue;
+            }
+            let path = format!(
+                "/rest/dev-status/latest/issue/detail?issueId={}&applicationType={}&dataType={}",
+                issue_id, tool, data_type
+            );
+            let detail: Value = client.get_json(&path).await?;
+            for entry in detail["detail"].as_array().into_iter().flatten() {
+                if let (Some(items), Some(all)) = (entry[list].as_array(), development[list].as_array_mut()) {
+                    found |= !items.is_empty();
+                    all.extend(items.iter().cloned());
+                }
+            }
+        }
+    }
+    Ok(found.then_some(development))
+}
+
+/// The `## 🛠️` section of a ticket note: pull requests with their state and
+/// branches, branches, and commits with their first line.
+pub fn section(issue: &Issue, labels: &Labels) -> String {
+    let Some(development) = &issue.development else {
+        return String::new();
+    };
+    let list = |name: &str| development[name].as_array().cloned().unwrap_or_default();
+    let mut section = format!("\n## 🛠️ {}\n", labels.development);
+
+    let pull_requests = list("pullRequests");
+    if !pull_requests.is_empty() {
+        section.push_str(&format!("\n**{}**\n", labels.pull_requests));
+        for pr in &pull_requests {
+            section.push_str(&format!(
+                "- {} `{}` ({} → {})\n",
+                link(pr["name"].as_str().unwrap_or_default(), &pr["url"]),
+                pr["status"].as_str().unwrap_or_default(),
+                pr["source"]["branch"].as_str().unwrap_or("?"),
+                pr["destination"]["branch"].as_str().unwrap_or("?")
+            ));
+        }
+    }
+    let branches = list("branches");
+    if !branches.is_empty() {
+        section.push_str(&format!("\n**{}**\n", labels.branches));
+        for branch in &branches {
+            section.push_str(&format!(
+                "- {} ({})\n",
+                link(branch["name"].as_str().unwrap_or_default(), &branch["url"]),
+                branch["repository"]["name"].as_str().unwrap_or_default()
+            ));
+        }
+    }
+    let commits: Vec<Value> = list("repositories").iter().flat_map(|r| r["commits"].as_array().cloned().unwrap_or_default()).collect();
+    if !commits.is_empty() {
+        section.push_str(&format!("\n**{}**\n", labels.commits));
+        for commit in &commits {
+            let id = commit["displayId"].as_str().or(commit["id"].as_str()).unwrap_or_default();
+            let message = commit["message"].as_str().unwrap_or_default().lines().next().unwrap_or_default();
+            section.push_str(&format!("- {} {}\n", link(&format!("`{}`", id), &commit["url"]), message));
+        }
+    }
+    section
+}
+
+fn link(text: &str, url: &Value) -> String {
+    match url.as_str() {
+        Some(url) => format!("[{}]({})", text, url),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_development_section() {
+    let issue: Issue = serde_json::from_value(json!({
+        "key": "A-1",
+        "fields": { "summary": "s", "status": { "name": "In Review" } },
+        "development": {
+            "pullRequests": [{ "name": "A-1 Fix login", "url": "https://git/pr/7", "status": "OPEN",
+                               "source": { "branch": "feature/A-1" }, "destination": { "branch": "main" } }],
+            "branches": [{ "name": "feature/A-1", "url": "https://git/tree/feature/A-1", "repository": { "name": "web" } }],
+            "repositories": [{ "name": "web", "commits": [{ "displayId": "abc1234", "url": "https://git/c/abc1234", "message": "Fix login\n\nDetails" }] }]
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        section(&issue, &Labels::for_locale("en")),
+        "\n## 🛠️ Development\n\
+         \n**Pull Requests**\n- [A-1 Fix login](https://git/pr/7) `OPEN` (feature/A-1 → main)\n\
+         \n**Branches**\n- [feature/A-1](https://git/tree/feature/A-1) (web)\n\
+         \n**Commits**\n- [`abc1234`](https://git/c/abc1234) Fix login\n"
+    );
+}
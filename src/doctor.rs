@@ -0,0 +1,272 @@
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use regex::Regex;
+use reqwest::Method;
+use serde_json::Value;
+use crate::cli::Overrides;
+use crate::config::Config;
+use crate::frontmatter;
+use crate::jira::{is_offline, status_of, JiraClient};
+use crate::state::State;
+use crate::vault::markdown_files;
+
+/// Counts failed checks while printing each result with a suggested fix.
+#[derive(Default)]
+struct Checks {
+    failed: usize,
+}
+
+impl Checks {
+    fn pass(&self, what: &str) {
+        println!("✅ {}", what);
+    }
+
+    fn fail(&mut self, what: &str, fix: &str) {
+        println!("❌ {}\n   👉 {}", what, fix);
+        self.failed += 1;
+    }
+}
+
+/// `doctor`: checks the settings, the connection, the queries, the vault and
+/// the files sync manages, and says how to fix whatever is wrong. Works even
+/// when the settings are incomplete, checking whatever they allow.
+pub async fn run(overrides: &Overrides) -> Result<(), Box<dyn Error>> {
+    let mut checks = Checks::default();
+    let mut config = Config::layered(overrides)?;
+    config.fill_defaults();
+
+    println!("🩺 Ayarlar");
+    let problems = config.problems();
+    if problems.is_empty() {
+        checks.pass("Ayarlar eksiksiz");
+    }
+    for problem in problems {
+        checks.fail(&problem, "Ayarı .env dosyasında, config dosyasında ya da komut satırında düzeltin");
+    }
+
+    if !config.jira_server.is_empty() && (!config.jira_token.is_empty() || config.oauth.enabled()) {
+        println!("\n🩺 Jira");
+        check_jira(&config, &mut checks).await;
+    }
+    if !config.vault_path.is_empty() {
+        println!("\n🩺 Kasa");
+        check_vault(&config, &mut checks);
+    }
+
+    println!();
+    if checks.failed > 0 {
+        return Err(format!("{} kontrol başarısız", checks.failed).into());
+    }
+    println!("🎉 Her şey yolunda.");
+    Ok(())
+}
+
+async fn check_jira(config: &Config, checks: &mut Checks) {
+    let client = match JiraClient::new(config) {
+        Ok(client) => client,
+        Err(e) => {
+            checks.fail(&format!("HTTP istemcisi kurulamadı: {}", e), "http bölümündeki proxy ve sertifika ayarlarını kontrol edin");
+            return;
+        }
+    };
+    match client.get_json::<Value>("/rest/api/3/myself").await {
+        Ok(me) => {
+            checks.pass(&format!("{} olarak giriş yapıldı", me["displayName"].as_str().unwrap_or("?")));
+            check_queries(&client, config, checks).await;
+        }
+        Err(e) => {
+            let fix = if is_offline(e.as_ref()) {
+                "JIRA_SERVER adresini, ağ bağlantısını ve varsa proxy/VPN ayarlarını kontrol edin"
+            } else {
+                match status_of(e.as_ref()) {
+                    Some(401) => "JIRA_USER ve JIRA_API_TOKEN eşleşmiyor; yeni bir API token oluşturup .env dosyasına yazın",
+                    Some(403) => "Hesabın REST API erişimi kısıtlı ya da CAPTCHA bekliyor; tarayıcıdan bir kez giriş yapıp tekrar deneyin",
+                    _ => "JIRA_SERVER, JIRA_USER ve JIRA_API_TOKEN değerlerini kontrol edin",
+                }
+            };
+            checks.fail(&format!("Kimlik doğrulama başarısız: {}", e), fix);
+        }
+    }
+}
+
+fn check_vault(config: &Config, checks: &mut Checks) {
+    let vault = Path::new(&config.vault_path);
+    let probe = vault.join(".jira-sync-doctor.tmp");
+    if !vault.is_dir() {
+        checks.fail(
+            &format!("{} bulunamadı", vault.display()),
+            "Klasörü oluşturun ya da OBSIDIAN_PATH değerini kasanızdaki bir klasöre çevirin",
+        );
+        return;
+    }
+    match fs::write(&probe, "ok").and_then(|_| fs::remove_file(&probe)) {
+        Ok(()) => checks.pass(&format!("{} yazılabilir", vault.display())),
+        Err(e) => checks.fail(
+            &format!("{} yazılamıyor: {}", vault.display(), e),
+            "Klasör izinlerini kontrol edin; bulut klasörlerinde dosyaların çevrimdışı kullanılabilir olduğundan emin olun",
+        ),
+    }
+
+    match State::load(&config.vault_path) {
+        Ok(state) => {
+            checks.pass(&format!("Durum dosyası okundu ({} not)", state.notes.len()));
+            check_notes(vault, &state, checks);
+        }
+        Err(e) => checks.fail(
+            &format!("Durum dosyası bozuk: {}", e),
+            "`.jira-sync/state.json` dosyasını silin; sonraki sync notları şablondaki konumlarında yeniden kaydeder",
+        ),
+    }
+
+    let files = markdown_files(vault);
+    let duplicates = duplicate_keys(&files);
+    if duplicates.is_empty() {
+        checks.pass("Aynı jira_key ile birden fazla not yok");
+    }
+    for (key, paths) in duplicates {
+        let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+        checks.fail(
+            &format!("{} birden fazla notta: {}", key, paths.join(", ")),
+            "Fazla kopyaları silin ya da birleştirin; yönetilen not `.jira-sync/state.json` içinde kayıtlı olandır",
+        );
+    }
+
+    let stems: HashSet<String> = files.iter().filter_map(|p| p.file_stem()).map(|s| s.to_string_lossy().into_owned()).collect();
+    let mut boards = vec![config.board.file().to_string()];
+    boards.extend(config.views.iter().map(|v| v.file_name()));
+    boards.extend(config.queries.iter().filter_map(|q| q.board.clone()));
+    for board in boards {
+        let Ok(content) = fs::read_to_string(vault.join(&board)) else { continue };
+        let exists = |target: &str| {
+            vault.join(format!("{}.md", target)).exists() || stems.contains(target.rsplit('/').next().unwrap_or(target))
+        };
+        let missing = missing_link_targets(&content, exists);
+        if missing.is_empty() {
+            checks.pass(&format!("{} içindeki bağlantılar geçerli", board));
+        } else {
+            checks.fail(
+                &format!("{} içinde karşılığı olmayan bağlantılar: {}", board, missing.join(", ")),
+                "sync çalıştırın; notlar taşındıysa önce `migrate` komutunu kullanın",
+            );
+        }
+    }
+}
+
+async fn check_queries(client: &JiraClient, config: &Config, checks: &mut Checks) {
+    let mut queries = Vec::new();
+    for query in crate::queries(config) {
+        let jql = match crate::query_jql(client, &query).await {
+            Ok(jql) => crate::expand_jql(client, &jql).await,
+            Err(e) => Err(e),
+        };
+        match jql {
+            Ok(jql) => queries.push((query.name, jql)),
+            Err(e) => checks.fail(
+                &format!("'{}' sorgusu: {}", query.name, e),
+                "Filtre numarasını, filtrenin hesabınızla paylaşıldığını ve JQL yer tutucularını kontrol edin",
+            ),
+        }
+    }
+    if queries.is_empty() {
+        return;
+    }
+    let body = serde_json::json!({ "queries": queries.iter().map(|(_, jql)| jql).collect::<Vec<_>>() });
+    let resp = match client.send(client.request(Method::POST, "/rest/api/3/jql/parse?validation=strict").json(&body)).await {
+        Ok(resp) => resp,
+        Err(e) => {
+            checks.fail(&format!("JQL doğrulanamadı: {}", e), "Ağ bağlantısını kontrol edip tekrar deneyin");
+            return;
+        }
+    };
+    let status = resp.status();
+    let Some(parsed) = resp.json::<Value>().await.ok().filter(|_| status.is_success()) else {
+        checks.fail(
+            &format!("JQL doğrulanamadı (HTTP {})", status),
+            "Bu Jira sürümü JQL doğrulama uç noktasını sunmuyor olabilir; sorguyu Jira'nın gelişmiş arama ekranında deneyin",
+        );
+        return;
+    };
+    for ((name, _), result) in queries.iter().zip(parsed["queries"].as_array().into_iter().flatten()) {
+        let errors: Vec<&str> = result["errors"].as_array().into_iter().flatten().filter_map(Value::as_str).collect();
+        if errors.is_empty() {
+            checks.pass(&format!("'{}' sorgusu geçerli", name));
+        } else {
+            checks.fail(&format!("'{}' sorgusu geçersiz: {}", name, errors.join("; ")), "Sorguyu Jira'nın gelişmiş arama ekranında düzeltip config'e geri kopyalayın");
+        }
+    }
+}
+
+/// Managed notes that are gone or whose frontmatter no longer names their key.
+fn check_notes(vault: &Path, state: &State, checks: &mut Checks) {
+    let mut missing = Vec::new();
+    let mut broken = Vec::new();
+    for (key, note) in &state.notes {
+        match fs::read_to_string(vault.join(&note.path)) {
+            Ok(content) if frontmatter::parse(&content).get("jira_key") != Some(key) => broken.push(note.path.clone()),
+            Ok(_) => {}
+            Err(_) => missing.push(key.clone()),
+        }
+    }
+    if missing.is_empty() && broken.is_empty() {
+        checks.pass("Tüm yönetilen notlar yerinde ve frontmatter'ları sağlam");
+    }
+    if !missing.is_empty() {
+        checks.fail(&format!("Kayıtlı ama bulunamayan notlar: {}", missing.join(", ")), "sync çalıştırın; eksik notlar yeniden oluşturulur");
+    }
+    if !broken.is_empty() {
+        checks.fail(
+            &format!("Frontmatter'ı bozuk notlar: {}", broken.join(", ")),
+            "Notun başındaki `---` bloğunu düzeltin ya da notu silip sync çalıştırın (güvenli bölgenin altını önce yedekleyin)",
+        );
+    }
+}
+
+/// `jira_key` values that appear in more than one note.
+fn duplicate_keys(files: &[PathBuf]) -> BTreeMap<String, Vec<PathBuf>> {
+    let mut by_key: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for file in files {
+        if let Some(key) = fs::read_to_string(file).ok().and_then(|c| frontmatter::parse(&c).remove("jira_key")) {
+            by_key.entry(key).or_default().push(file.clone());
+        }
+    }
+    by_key.retain(|_, paths| paths.len() > 1);
+    by_key
+}
+
+/// Wikilink targets on a board that `exists` doesn't know.
+fn missing_link_targets(board: &str, exists: impl Fn(&str) -> bool) -> Vec<String> {
+    let link = Regex::new(r"\[\[([^\]|#]+)").unwrap();
+    let mut missing: Vec<String> = link
+        .captures_iter(board)
+        .map(|c| c[1].trim().to_string())
+        .filter(|target| !exists(target))
+        .collect();
+    missing.dedup();
+    missing
+}
+
+#[cfg(test)]
+#[test]
+fn test_doctor_checks() {
+    let board = "## To Do (2)\n\n- [ ] [[Jira/A-1|A-1]]\n- [ ] 📌 [[A-2]]\n- [ ] [[A-3#Notes]]\n";
+    let known = ["Jira/A-1", "A-3"];
+    assert_eq!(missing_link_targets(board, |t| known.contains(&t)), ["A-2"]);
+
+    /// Removed on drop, so a failed assert doesn't leave it behind.
+    struct TempDir(PathBuf);
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+    let dir = TempDir(std::env::temp_dir().join(format!("jira-doctor-duplicates-{}", std::process::id())));
+    fs::create_dir_all(&dir.0).unwrap();
+    fs::write(dir.0.join("A-1.md"), "---\njira_key: A-1\n---\n").unwrap();
+    fs::write(dir.0.join("A-1 2.md"), "---\njira_key: A-1\n---\n").unwrap();
+    fs::write(dir.0.join("A-2.md"), "---\njira_key: A-2\n---\n").unwrap();
+    let duplicates = duplicate_keys(&markdown_files(&dir.0));
+    assert_eq!(duplicates.keys().collect::<Vec<_>>(), ["A-1"]);
+    assert_eq!(duplicates["A-1"].len(), 2);
+}
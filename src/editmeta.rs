@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::error::Error;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::jira::JiraClient;
+
+#[derive(Deserialize)]
+struct EditMeta {
+    #[serde(default)]
+    fields: HashMap<String, FieldMeta>,
+}
+
+/// What `/issue/{key}/editmeta` says about one field the user may edit.
+#[derive(Debug, Deserialize)]
+pub struct FieldMeta {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub schema: Value,
+    #[serde(default, rename = "allowedValues")]
+    pub allowed_values: Vec<Value>,
+}
+
+/// Sets `field` on `key` to the note's text `value`. Fields missing from the
+/// issue's editmeta are refused here with a readable message instead of
+/// letting Jira answer with a bare 400.
+pub async fn update_field(client: &JiraClient, key: &str, field: &str, value: &str) -> Result<(), Box<dyn Error>> {
+    let meta: EditMeta = client.get_json(&format!("/rest/api/3/issue/{}/editmeta", key)).await?;
+    let Some(field_meta) = meta.fields.get(field) else {
+        return Err(format!("{}: '{}' alanı bu iş için sizin tarafınızdan düzenlenemiyor (ekran veya yetki)", key, field).into());
+    };
+    let json = field_value(field_meta, value).map_err(|e| format!("{}: {}", key, e))?;
+    client
+        .put_json(&format!("/rest/api/3/issue/{}", key), &serde_json::json!({ "fields": { field: json } }))
+        .await?;
+    Ok(())
+}
+
+/// Turns the text from the note into the JSON Jira expects for the field.
+pub fn field_value(meta: &FieldMeta, value: &str) -> Result<Value, String> {
+    if !meta.allowed_values.is_empty() {
+        let allowed = meta.allowed_values.iter().find(|v| {
+            ["name", "value"].iter().any(|k| v[k].as_str().is_some_and(|n| n.eq_ignore_ascii_case(value)))
+        });
+        return match allowed {
+            Some(v) => Ok(serde_json::json!({ "id": v["id"] })),
+            None => {
+                let names: Vec<&str> = meta
+                    .allowed_values
+                    .iter()
+                    .filter_map(|v| v["name"].as_str().or(v["value"].as_str()))
+                    .collect();
+                Err(format!("'{}' {} için geçerli değil (seçenekler: {})", value, meta.name, names.join(", ")))
+            }
+        };
+    }
+    match meta.schema["type"].as_str() {
+        Some("number") => value
+            .parse::<f64>()
+            .map(|n| serde_json::json!(n))
+            .map_err(|_| format!("{} bir sayı bekliyor, '{}' verildi", meta.name, value)),
+        Some("array") => Ok(serde_json::json!(value
+            .split(',')
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .collect::<Vec<_>>())),
+        Some("string") | Some("date") => Ok(Value::String(value.to_string())),
+        _ => Ok(serde_json::json!({ "name": value })),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_field_value() {
+    let meta: FieldMeta = serde_json::from_value(serde_json::json!({
+        "name": "Priority",
+        "schema": { "type": "priority" },
+        "allowedValues": [{ "id": "2", "name": "High" }, { "id": "3", "name": "Medium" }]
+    }))
+    .unwrap();
+    assert_eq!(field_value(&meta, "high").unwrap(), serde_json::json!({ "id": "2" }));
+    assert!(field_value(&meta, "Urgent").unwrap_err().contains("High, Medium"));
+
+    let meta: FieldMeta = serde_json::from_value(serde_json::json!({
+        "name": "Story Points",
+        "schema": { "type": "number" }
+    }))
+    .unwrap();
+    assert_eq!(field_value(&meta, "5").unwrap(), serde_json::json!(5.0));
+    assert!(field_value(&meta, "five").is_err());
+}
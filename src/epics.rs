@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::config::Config;
+use crate::jira::JiraClient;
+use crate::{with_safe_zone, Issue, NoteContext};
+use tracing::info;
+
+/// `[epics]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct EpicsConfig {
+    pub enabled: bool,
+    /// Vault folder for the epic notes (default "Epics").
+    pub folder: Option<String>,
+    /// Legacy "Epic Link" field for company-managed projects on Server/Data
+    /// Center; found by `capabilities` when unset. Elsewhere the epic is the
+    /// issue's `parent`.
+    pub link_field: Option<String>,
+}
+
+impl EpicsConfig {
+    pub fn folder(&self) -> &str {
+        self.folder.as_deref().unwrap_or("Epics")
+    }
+}
+
+/// Key of the epic `issue` belongs to: its parent when that is an epic, else
+/// the value of the Epic Link field.
+pub fn epic_key<'a>(issue: &'a Issue, config: &EpicsConfig) -> Option<&'a str> {
+    let parent = issue.fields.extra.get("parent").filter(|p| {
+        let issue_type = &p["fields"]["issuetype"];
+        issue_type["hierarchyLevel"] == 1 || issue_type["name"].as_str().is_some_and(|n| n.eq_ignore_ascii_case("epic"))
+    });
+    parent
+        .and_then(|p| p["key"].as_str())
+        .or_else(|| config.link_field.as_ref().and_then(|field| issue.fields.extra.get(field)?.as_str()))
+}
+
+/// `epic:` frontmatter line linking a child to its epic's note.
+pub fn frontmatter(issue: &Issue, config: &Config) -> String {
+    let settings = &config.epics;
+    match epic_key(issue, settings) {
+        Some(key) if settings.enabled => format!("epic: \"[[{}/{}|{}]]\"\n", settings.folder(), key, key),
+        _ => String::new(),
+    }
+}
+
+/// One note per epic of the synced issues, listing its children grouped by
+/// status. Epic summaries and statuses take one extra search; the part below
+/// the safe-zone line is the user's and survives regeneration.
+pub async fn write(
+    ctx: &NoteContext<'_>,
+    client: &JiraClient,
+    issues: &[Issue],
+    note_links: &HashMap<String, String>,
+) -> Result<(), Box<dyn Error>> {
+    let settings = &ctx.config.epics;
+    let mut children: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        if let Some(epic) = epic_key(issue, settings) {
+            children.entry(epic).or_default().push(issue);
+        }
+    }
+    if children.is_empty() {
+        return Ok(());
+    }
+    let keys: Vec<&str> = children.keys().copied().collect();
+    let epics: HashMap<String, Value> = crate::search(client, &format!("key in ({})", keys.join(",")), &["summary", "status"])
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|epic| Some((epic["key"].as_str()?.to_string(), epic)))
+        .collect();
+
+    let folder = Path::new(&ctx.config.vault_path).join(settings.folder());
+    for (key, issues) in &children {
+        let epic = epics.get(*key).unwrap_or(&Value::Null);
+        let path = folder.join(format!("{}.md", key));
+        let note = render(ctx, key, epic, issues, note_links);
+        ctx.writer.write(&path, &with_safe_zone(&note, fs::read_to_string(&path).ok().as_deref()))?;
+    }
+    info!("🏔️  {} epik notu güncellendi.", children.len());
+    Ok(())
+}
+
+fn render(ctx: &NoteContext, key: &str, epic: &Value, issues: &[&Issue], note_links: &HashMap<String, String>) -> String {
+    let fields = &epic["fields"];
+    let status = fields["status"]["name"].as_str().unwrap_or_default();
+    let mut note = format!(
+        "---\njira_key: {}\njira_status: {}\njira_link: {}/browse/{}\ntags: [jira, epic]\n---\n# {}: {}\n\n",
+        key,
+        status,
+        ctx.config.base_url(),
+        key,
+        key,
+        fields["summary"].as_str().unwrap_or_default()
+    );
+    let done = issues.iter().filter(|i| i.fields.status.status_category.as_ref().is_some_and(|c| c.key == "done")).count();
+    note.push_str(&format!("> **{}:** {}/{}\n", ctx.labels.completed, done, issues.len()));
+
+    // Önce yapılacaklar, sonra devam edenler, en son bitenler.
+    let mut by_status: BTreeMap<(u8, &str), Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        let rank = match issue.fields.status.status_category.as_ref().map(|c| c.key.as_str()) {
+            Some("new") => 0,
+            Some("done") => 2,
+            _ => 1,
+        };
+        by_status.entry((rank, issue.fields.status.name.as_str())).or_default().push(issue);
+    }
+    for ((_, status), issues) in by_status {
+        note.push_str(&format!("\n## {}\n", status));
+        for issue in issues {
+            let link = match note_links.get(&issue.key) {
+                Some(target) if *target != issue.key => format!("[[{}|{}]]", target, issue.key),
+                _ => format!("[[{}]]", issue.key),
+            };
+            note.push_str(&format!("- {} {}\n", link, issue.fields.summary));
+        }
+    }
+    note
+}
+
+#[cfg(test)]
+#[test]
+fn test_epic_key() {
+    let issue = |fields: Value| -> Issue {
+        let mut all = serde_json::json!({ "summary": "s", "status": { "name": "To Do" } });
+        all.as_object_mut().unwrap().extend(fields.as_object().unwrap().clone());
+        serde_json::from_value(serde_json::json!({ "key": "A-2", "fields": all })).unwrap()
+    };
+    let settings = EpicsConfig { link_field: Some("customfield_10014".into()), ..Default::default() };
+
+    let cloud = issue(serde_json::json!({ "parent": { "key": "A-1", "fields": { "issuetype": { "name": "Epic", "hierarchyLevel": 1 } } } }));
+    assert_eq!(epic_key(&cloud, &settings), Some("A-1"));
+    let subtask = issue(serde_json::json!({ "parent": { "key": "A-5", "fields": { "issuetype": { "name": "Story", "hierarchyLevel": 0 } } } }));
+    assert_eq!(epic_key(&subtask, &settings), None);
+    let legacy = issue(serde_json::json!({ "customfield_10014": "A-9" }));
+    assert_eq!(epic_key(&legacy, &settings), Some("A-9"));
+    assert_eq!(epic_key(&legacy, &EpicsConfig::default()), None);
+
+    let config = Config { epics: EpicsConfig { enabled: true, ..settings }, ..Default::default() };
+    assert_eq!(frontmatter(&cloud, &config), "epic: \"[[Epics/A-1|A-1]]\"\n");
+}
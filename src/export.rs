@@ -0,0 +1,104 @@
+use std::error::Error;
+use std::fs;
+use serde::Serialize;
+use tracing::info;
+use crate::cli::ExportFormat;
+use crate::config::Config;
+use crate::jira::JiraClient;
+use crate::restricted;
+use crate::Issue;
+
+/// One line of the export; dates as Jira sends them.
+#[derive(Debug, Serialize)]
+struct Row {
+    key: String,
+    summary: String,
+    status: String,
+    priority: String,
+    assignee: String,
+    reporter: String,
+    created: String,
+    updated: String,
+    due: String,
+}
+
+impl Row {
+    fn from_issue(issue: &Issue) -> Row {
+        let fields = &issue.fields;
+        let date = |name: &str| fields.extra.get(name).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        Row {
+            key: issue.key.clone(),
+            summary: fields.summary.clone(),
+            status: fields.status.name.clone(),
+            priority: fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+            assignee: fields.assignee.as_ref().map(|u| u.display_name.clone()).unwrap_or_default(),
+            reporter: fields.reporter.as_ref().map(|u| u.display_name.clone()).unwrap_or_default(),
+            created: date("created"),
+            updated: date("updated"),
+            due: fields.duedate.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// `export`: runs the queries and writes the issues as a flat table to `out`
+/// (or stdout) without touching the vault.
+pub async fn run(config: &Config, format: ExportFormat, out: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let Some(fetched) = crate::fetch(config, &client).await? else {
+        return Err("Sorgular çalıştırılamadı".into());
+    };
+    let issues = fetched.issues.into_iter().map(restricted::from_raw).collect::<Result<Vec<Issue>, _>>()?;
+    let rows: Vec<Row> = issues.iter().map(Row::from_issue).collect();
+    let content = match format {
+        ExportFormat::Csv => csv(&rows),
+        ExportFormat::Json => serde_json::to_string_pretty(&rows)? + "\n",
+    };
+    match out {
+        Some(path) => {
+            fs::write(path, content)?;
+            info!("📤 {} iş {} dosyasına yazıldı", rows.len(), path);
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
+fn csv(rows: &[Row]) -> String {
+    let mut out = String::from("key,summary,status,priority,assignee,reporter,created,updated,due\n");
+    for row in rows {
+        let cells = [&row.key, &row.summary, &row.status, &row.priority, &row.assignee, &row.reporter, &row.created, &row.updated, &row.due];
+        out.push_str(&cells.map(|cell| csv_cell(cell)).join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quotes a cell when it contains a separator, quote or line break (RFC 4180).
+fn csv_cell(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_export_csv() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "Fix \"login\", again",
+            "status": { "name": "To Do" },
+            "assignee": { "accountId": "abc", "displayName": "Ayşe" },
+            "created": "2024-01-15T10:30:00.000+0000",
+            "duedate": "2024-02-01"
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        csv(&[Row::from_issue(&issue)]),
+        "key,summary,status,priority,assignee,reporter,created,updated,due\n\
+         A-1,\"Fix \"\"login\"\", again\",To Do,,Ayşe,,2024-01-15T10:30:00.000+0000,,2024-02-01\n"
+    );
+}
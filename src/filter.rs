@@ -0,0 +1,73 @@
+use std::error::Error;
+use regex::Regex;
+use rhai::{Engine, Scope, AST};
+use crate::Issue;
+
+/// A Rhai boolean expression evaluated per issue after the JQL search, e.g.
+/// `labels.contains("infra") && priority != "Low"`.
+///
+/// Available variables: `key`, `summary`, `status`, `priority`, `labels` and
+/// `fields` (the extra fetched fields by id).
+pub struct IssueFilter {
+    engine: Engine,
+    ast: AST,
+}
+
+impl IssueFilter {
+    pub fn new(expression: &str) -> Result<IssueFilter, Box<dyn Error>> {
+        let engine = Engine::new();
+        let ast = engine
+            .compile_expression(expression)
+            .map_err(|e| format!("Filtre ifadesi geçersiz: {}", e))?;
+        Ok(IssueFilter { engine, ast })
+    }
+
+    pub fn matches(&self, issue: &Issue) -> Result<bool, Box<dyn Error>> {
+        let fields = &issue.fields;
+        let mut scope = Scope::new();
+        scope.push_constant("key", issue.key.clone());
+        scope.push_constant("summary", fields.summary.clone());
+        scope.push_constant("status", fields.status.name.clone());
+        scope.push_constant("priority", fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default());
+        scope.push_constant("labels", rhai::serde::to_dynamic(&fields.labels)?);
+        scope.push_constant("fields", rhai::serde::to_dynamic(&fields.extra)?);
+
+        self.engine
+            .eval_ast_with_scope::<bool>(&mut scope, &self.ast)
+            .map_err(|e| format!("{}: filtre hatası: {}", issue.key, e).into())
+    }
+}
+
+/// Field ids a Rhai expression or script reads through `fields`
+/// (`fields.customfield_10050`, `fields["customfield_10050"]`), so the search
+/// can fetch them without the user listing them again.
+pub fn referenced_fields(source: &str) -> Vec<String> {
+    let access = Regex::new(r#"\bfields\s*(?:\.\s*([A-Za-z_][A-Za-z0-9_]*)|\[\s*"([^"]+)"\s*\])"#).unwrap();
+    access
+        .captures_iter(source)
+        .filter_map(|c| c.get(1).or(c.get(2)).map(|m| m.as_str().to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_filter_expression() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "OPS-7",
+        "fields": {
+            "summary": "Rotate certs",
+            "status": { "name": "To Do" },
+            "priority": { "name": "High" },
+            "labels": ["infra", "security"]
+        }
+    }))
+    .unwrap();
+
+    assert!(IssueFilter::new(r#"labels.contains("infra") && priority != "Low""#).unwrap().matches(&issue).unwrap());
+    assert!(!IssueFilter::new(r#"status == "Done""#).unwrap().matches(&issue).unwrap());
+    assert!(IssueFilter::new("labels.contains(").is_err());
+    assert_eq!(
+        referenced_fields(r#"fields.customfield_10050 == "ACME" || fields["customfield_10016"] > 3 || "fields" in labels"#),
+        ["customfield_10050", "customfield_10016"]
+    );
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use crate::{Issue, NoteContext};
+use tracing::info;
+
+const HEADER: &str = "date,status,count";
+
+/// `[flow]` in the config file: daily per-status counts for a cumulative flow diagram.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct FlowConfig {
+    pub enabled: bool,
+    /// CSV file in the vault (default "JiraFlow.csv").
+    pub file: Option<String>,
+}
+
+/// Appends today's per-status counts to the flow CSV. A day has one set of
+/// rows: syncing again the same day replaces them.
+pub fn record(ctx: &NoteContext<'_>, issues: &[Issue], today: NaiveDate) -> Result<(), Box<dyn Error>> {
+    let file = ctx.config.flow.file.clone().unwrap_or_else(|| "JiraFlow.csv".to_string());
+    let path = Path::new(&ctx.config.vault_path).join(&file);
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    ctx.writer.write(&path, &update_csv(&existing, issues, today))?;
+    info!("📉 Akış verisi eklendi: {}", file);
+    Ok(())
+}
+
+fn update_csv(existing: &str, issues: &[Issue], today: NaiveDate) -> String {
+    let date = today.format("%Y-%m-%d").to_string();
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for issue in issues {
+        *counts.entry(issue.fields.status.name.as_str()).or_default() += 1;
+    }
+
+    let mut csv = format!("{}\n", HEADER);
+    for line in existing.lines().skip(1) {
+        if !line.is_empty() && !line.starts_with(&format!("{},", date)) {
+            csv.push_str(line);
+            csv.push('\n');
+        }
+    }
+    for (status, count) in counts {
+        csv.push_str(&format!("{},{},{}\n", date, quote(status), count));
+    }
+    csv
+}
+
+fn quote(value: &str) -> String {
+    if value.contains([',', '"']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_update_flow_csv() {
+    let issue = |status: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": "A-1",
+            "fields": { "summary": "x", "status": { "name": status } }
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("Done"), issue("To Do"), issue("Done")];
+    let existing = "date,status,count\n2025-05-31,To Do,3\n2025-06-01,To Do,9\n";
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+    assert_eq!(
+        update_csv(existing, &issues, today),
+        "date,status,count\n2025-05-31,To Do,3\n2025-06-01,Done,2\n2025-06-01,To Do,1\n"
+    );
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+/// Reads the `key: value` lines of a note's leading YAML block. Good enough for
+/// the flat frontmatter we write; nested YAML is ignored.
+pub fn parse(content: &str) -> BTreeMap<String, String> {
+    let mut map = BTreeMap::new();
+    let mut lines = content.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return map;
+    }
+    for line in lines {
+        if line.trim_end() == "---" {
+            break;
+        }
+        if line.starts_with([' ', '\t', '-']) {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"').and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            map.insert(key.trim().to_string(), value.to_string());
+        }
+    }
+    map
+}
+
+/// Sets `key: value` in the leading YAML block, replacing an existing line or
+/// adding one before the closing `---`. Content without frontmatter is returned as is.
+pub fn set(content: &str, key: &str, value: &str) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    if lines.first().map(|l| l.trim_end()) != Some("---") {
+        return content.to_string();
+    }
+    let Some(end) = lines.iter().skip(1).position(|l| l.trim_end() == "---").map(|i| i + 1) else {
+        return content.to_string();
+    };
+    let entry = format!("{}: {}", key, value);
+    match lines[1..end].iter().position(|l| l.split_once(':').is_some_and(|(k, _)| k.trim() == key)) {
+        Some(i) => lines[i + 1] = entry,
+        None => lines.insert(end, entry),
+    }
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// A Jira field value as a frontmatter value: scalars as they are, objects by
+/// their `name`/`value`/`displayName`, arrays as a YAML list. `None` for empty fields.
+pub fn value(field: &serde_json::Value) -> Option<String> {
+    use serde_json::Value;
+    match field {
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::String(s) => Some(format!("\"{}\"", s.replace('"', "'"))),
+        Value::Array(items) => Some(format!("[{}]", items.iter().filter_map(value).collect::<Vec<_>>().join(", "))),
+        Value::Object(map) => ["name", "value", "displayName"].iter().find_map(|k| map.get(*k)).and_then(value),
+        other => Some(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_frontmatter_value() {
+    use serde_json::json;
+    assert_eq!(value(&json!(5.0)).as_deref(), Some("5.0"));
+    assert_eq!(value(&json!("Team \"A\"")).as_deref(), Some("\"Team 'A'\""));
+    assert_eq!(value(&json!([{ "name": "1.9" }, { "name": "2.0" }])).as_deref(), Some("[\"1.9\", \"2.0\"]"));
+    assert_eq!(value(&json!({ "value": "Backend", "id": "10" })).as_deref(), Some("\"Backend\""));
+    assert_eq!(value(&json!(null)), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_set_frontmatter() {
+    let note = "---\njira_key: A-1\njira_watching: false\n---\n# A-1\n";
+    assert_eq!(set(note, "jira_watching", "true"), "---\njira_key: A-1\njira_watching: true\n---\n# A-1\n");
+    assert_eq!(set(note, "pinned", "true"), "---\njira_key: A-1\njira_watching: false\npinned: true\n---\n# A-1\n");
+    assert_eq!(set("# A-1\n", "pinned", "true"), "# A-1\n");
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_frontmatter() {
+    let note = "---\njira_key: A-1\njira_status: \"In Review\"\ntags: [jira, task]\n---\n# A-1\nnot: frontmatter\n";
+    let fm = parse(note);
+    assert_eq!(fm.get("jira_status").map(String::as_str), Some("In Review"));
+    assert_eq!(fm.get("tags").map(String::as_str), Some("[jira, task]"));
+    assert!(!fm.contains_key("not"));
+    assert!(parse("# no frontmatter").is_empty());
+}
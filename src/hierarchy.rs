@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use serde_json::Value;
+use crate::links::note_link;
+use crate::Issue;
+
+/// `parent:` frontmatter line for subtasks (and, on Cloud, for any issue with
+/// a parent): a wikilink when the parent has a note, its key otherwise.
+pub fn frontmatter(issue: &Issue, targets: &HashMap<String, String>) -> String {
+    let Some(key) = issue.fields.extra.get("parent").and_then(|p| p["key"].as_str()) else {
+        return String::new();
+    };
+    match targets.get(key) {
+        Some(target) => format!("parent: \"[[{}|{}]]\"\n", target, key),
+        None => format!("parent: {}\n", key),
+    }
+}
+
+/// The `## 🧩` section of a parent's note: each subtask with its status.
+pub fn section(issue: &Issue, title: &str, targets: &HashMap<String, String>, base_url: &str) -> String {
+    let Some(Value::Array(subtasks)) = issue.fields.extra.get("subtasks") else {
+        return String::new();
+    };
+    let lines: Vec<String> = subtasks
+        .iter()
+        .filter_map(|subtask| {
+            let key = subtask["key"].as_str()?;
+            Some(format!(
+                "- {} {} (`{}`)\n",
+                note_link(key, targets, base_url),
+                subtask["fields"]["summary"].as_str().unwrap_or_default(),
+                subtask["fields"]["status"]["name"].as_str().unwrap_or_default()
+            ))
+        })
+        .collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("\n## 🧩 {}\n{}", title, lines.concat())
+}
+
+#[cfg(test)]
+#[test]
+fn test_subtasks_and_parent() {
+    let parent: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "subtasks": [{ "key": "A-2", "fields": { "summary": "Test yaz", "status": { "name": "Done" } } }]
+        }
+    }))
+    .unwrap();
+    let child: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-2",
+        "fields": { "summary": "Test yaz", "status": { "name": "Done" }, "parent": { "key": "A-1" } }
+    }))
+    .unwrap();
+    let targets = HashMap::from([("A-1".to_string(), "Jira/A-1".to_string()), ("A-2".to_string(), "A-2".to_string())]);
+
+    assert_eq!(section(&parent, "Subtasks", &targets, "https://x"), "\n## 🧩 Subtasks\n- [[A-2]] Test yaz (`Done`)\n");
+    assert_eq!(frontmatter(&child, &targets), "parent: \"[[Jira/A-1|A-1]]\"\n");
+    assert_eq!(frontmatter(&child, &HashMap::new()), "parent: A-1\n");
+    assert_eq!(frontmatter(&parent, &targets), "");
+}
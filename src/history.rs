@@ -0,0 +1,91 @@
+use chrono::FixedOffset;
+use crate::i18n::Labels;
+use crate::metrics::parse_time;
+use crate::people::Directory;
+use crate::Issue;
+
+/// Rows kept in the table; older changes are left to Jira.
+const MAX_ROWS: usize = 30;
+/// Longer values (descriptions, long text fields) are cut to this many characters.
+const MAX_VALUE_CHARS: usize = 60;
+/// Fields that change on every drag in a board and say nothing.
+const IGNORED_FIELDS: [&str; 2] = ["Rank", "RemoteIssueLink"];
+
+/// The `## 🕓` section of a ticket note: the issue's changelog as a table,
+/// newest first, one row per changed field.
+pub fn section(issue: &Issue, labels: &Labels, people: &Directory, offset: FixedOffset) -> String {
+    let Some(histories) = issue.changelog.as_ref().and_then(|c| c["histories"].as_array()) else {
+        return String::new();
+    };
+    let mut rows: Vec<(i64, String)> = Vec::new();
+    for history in histories {
+        let Some(time) = history["created"].as_str().and_then(parse_time) else { continue };
+        let author = &history["author"];
+        let who = match author["accountId"].as_str().or(author["name"].as_str()) {
+            Some(id) => people.name(id),
+            None => author["displayName"].as_str().unwrap_or("?").to_string(),
+        };
+        for item in history["items"].as_array().into_iter().flatten() {
+            let field = item["field"].as_str().unwrap_or_default();
+            if IGNORED_FIELDS.contains(&field) {
+                continue;
+            }
+            let value = |id: &str, text: &str| match (field, item[id].as_str()) {
+                ("assignee" | "reporter", Some(id)) => people.name(id),
+                _ => shorten(item[text].as_str().unwrap_or_default()),
+            };
+            let change = format!("{} → {}", value("from", "fromString"), value("to", "toString"));
+            rows.push((
+                time.timestamp(),
+                format!("| {} | {} | {} | {} |\n", time.with_timezone(&offset).format("%Y-%m-%d %H:%M"), who, field, change.trim()),
+            ));
+        }
+    }
+    if rows.is_empty() {
+        return String::new();
+    }
+    rows.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+    let mut section = format!("\n## 🕓 {}\n\n| {} | {} | {} | {} |\n|---|---|---|---|\n", labels.history, labels.date, labels.by, labels.field, labels.change);
+    for (_, row) in rows.iter().take(MAX_ROWS) {
+        section.push_str(row);
+    }
+    section
+}
+
+/// One line, table-safe, at most [`MAX_VALUE_CHARS`] characters.
+fn shorten(value: &str) -> String {
+    let line = value.split_whitespace().collect::<Vec<_>>().join(" ").replace('|', "\\|");
+    match line.char_indices().nth(MAX_VALUE_CHARS) {
+        Some((end, _)) => format!("{}…", &line[..end]),
+        None => line,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_history_section() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": { "summary": "s", "status": { "name": "In Review" } },
+        "changelog": { "histories": [
+            { "author": { "displayName": "Ayşe" }, "created": "2026-10-12T09:00:00.000+0000", "items": [
+                { "field": "status", "fromString": "To Do", "toString": "In Progress" },
+                { "field": "Rank", "fromString": "", "toString": "Ranked higher" }
+            ] },
+            { "author": { "displayName": "Bora" }, "created": "2026-10-13T09:00:00.000+0000", "items": [
+                { "field": "status", "fromString": "In Progress", "toString": "In Review" },
+                { "field": "labels", "fromString": null, "toString": "infra | ops" }
+            ] }
+        ] }
+    }))
+    .unwrap();
+    let offset = FixedOffset::east_opt(0).unwrap();
+    assert_eq!(
+        section(&issue, &Labels::for_locale("en"), &Directory::default(), offset),
+        "\n## 🕓 History\n\n| Date | By | Field | Change |\n|---|---|---|---|\n\
+         | 2026-10-13 09:00 | Bora | status | In Progress → In Review |\n\
+         | 2026-10-13 09:00 | Bora | labels | → infra \\| ops |\n\
+         | 2026-10-12 09:00 | Ayşe | status | To Do → In Progress |\n"
+    );
+    assert_eq!(shorten(&"a".repeat(70)).chars().count(), MAX_VALUE_CHARS + 1);
+}
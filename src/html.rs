@@ -0,0 +1,146 @@
+use regex::Regex;
+
+/// Markdown for the HTML Jira renders fields as (`expand=renderedFields`):
+/// paragraphs, headings, emphasis, code, links, images, lists and simple
+/// tables. Other tags are dropped, keeping their text.
+pub fn to_markdown(html: &str) -> String {
+    let tag = Regex::new(r#"(?s)<(/?)([a-zA-Z][a-zA-Z0-9]*)([^>]*?)/?>"#).unwrap();
+    let spaces = Regex::new(r"\s+").unwrap();
+    let attr = |attrs: &str, name: &str| {
+        Regex::new(&format!(r#"{}\s*=\s*"([^"]*)""#, name)).unwrap().captures(attrs).map(|c| decode(&c[1]))
+    };
+    let mut out = String::new();
+    // Açık listeler: sıralıysa sıradaki numara.
+    let mut lists: Vec<Option<usize>> = Vec::new();
+    // Açık bağlantıların adresi; çapa (`<a name>`) ise yok.
+    let mut links: Vec<Option<String>> = Vec::new();
+    let mut pre = false;
+    let mut header_cells = 0;
+    let mut last = 0;
+    for c in tag.captures_iter(html) {
+        let whole = c.get(0).unwrap();
+        let text = decode(&html[last..whole.start()]);
+        last = whole.end();
+        if pre {
+            out.push_str(&text);
+        } else {
+            let text = spaces.replace_all(&text, " ");
+            out.push_str(if out.is_empty() || out.ends_with('\n') { text.trim_start() } else { &text });
+        }
+        let closing = &c[1] == "/";
+        let (name, attrs) = (c[2].to_ascii_lowercase(), &c[3]);
+        match (name.as_str(), closing) {
+            ("p" | "div", true) => out.push_str("\n\n"),
+            ("br", _) => out.push('\n'),
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", false) => {
+                let level = name[1..].parse().unwrap_or(1);
+                out.push_str(&format!("\n\n{} ", "#".repeat(level)));
+            }
+            ("h1" | "h2" | "h3" | "h4" | "h5" | "h6", true) => out.push_str("\n\n"),
+            ("b" | "strong", _) => out.push_str("**"),
+            ("i" | "em", _) => out.push('*'),
+            ("del" | "s", _) => out.push_str("~~"),
+            ("code" | "tt", _) if !pre => out.push('`'),
+            ("pre", false) => {
+                pre = true;
+                out.push_str("\n```\n");
+            }
+            ("pre", true) => {
+                pre = false;
+                out.push_str("\n```\n\n");
+            }
+            ("a", false) => {
+                let href = attr(attrs, "href");
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    out.push_str(&format!("]({})", href));
+                }
+            }
+            ("img", _) => {
+                let alt = attr(attrs, "alt").unwrap_or_default();
+                out.push_str(&format!("![{}]({})", alt, attr(attrs, "src").unwrap_or_default()));
+            }
+            ("ul", false) => lists.push(None),
+            ("ol", false) => lists.push(Some(1)),
+            ("ul" | "ol", true) => {
+                lists.pop();
+                if lists.is_empty() {
+                    out.push_str("\n\n");
+                }
+            }
+            ("li", false) => {
+                let indent = "  ".repeat(lists.len().saturating_sub(1));
+                let marker = match lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}.", *n - 1)
+                    }
+                    _ => "-".to_string(),
+                };
+                out.push_str(&format!("\n{}{} ", indent, marker));
+            }
+            ("blockquote", false) => out.push_str("\n\n> "),
+            ("blockquote", true) => out.push_str("\n\n"),
+            ("tr", false) => {
+                header_cells = 0;
+                out.push_str("\n|");
+            }
+            ("th", false) => {
+                header_cells += 1;
+                out.push(' ');
+            }
+            ("td", false) => out.push(' '),
+            ("th" | "td", true) => out.push_str(" |"),
+            ("tr", true) if header_cells > 0 => out.push_str(&format!("\n|{}", "---|".repeat(header_cells))),
+            ("table", true) => out.push_str("\n\n"),
+            _ => {}
+        }
+    }
+    out.push_str(&decode(&html[last..]));
+
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    Regex::new(r"\n{3,}").unwrap().replace_all(&lines.join("\n"), "\n\n").trim().to_string()
+}
+
+fn decode(text: &str) -> String {
+    let entity = Regex::new(r"&(#x?[0-9a-fA-F]+|[a-z]+);").unwrap();
+    entity
+        .replace_all(text, |c: &regex::Captures| {
+            let name = &c[1];
+            let decoded = match name {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => name.strip_prefix('#').and_then(|n| n.parse().ok()).and_then(char::from_u32),
+                },
+            };
+            decoded.map_or_else(|| c[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+#[test]
+fn test_html_to_markdown() {
+    let html = "<h2><a name=\"x\"></a>Steps</h2>\n<p>Open the <b>login</b> page &amp; press <tt>Enter</tt>.<br/>\nSee <a href=\"https://wiki/x\">the spec</a>.</p>\n\
+                <ol><li>one</li><li>two<ul><li>nested</li></ul></li></ol>\n\
+                <pre>let x = 1;\nlet y = 2;</pre>\n\
+                <table><tbody><tr><th>Env</th><th>Result</th></tr><tr><td>prod</td><td>fails</td></tr></tbody></table>";
+    assert_eq!(
+        to_markdown(html),
+        "## Steps\n\nOpen the **login** page & press `Enter`.\nSee [the spec](https://wiki/x).\n\n\
+         1. one\n2. two\n  - nested\n\n\
+         ```\nlet x = 1;\nlet y = 2;\n```\n\n\
+         | Env | Result |\n|---|---|\n| prod | fails |"
+    );
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use crate::config::Config;
+use tracing::warn;
+
+/// Fixed text written into generated notes. The safe-zone marker is deliberately
+/// not here: it is how we find the user's notes, so it must never change.
+#[derive(Debug, Clone)]
+pub struct Labels {
+    pub details: String,
+    pub status: String,
+    pub priority: String,
+    pub link: String,
+    pub open_in_jira: String,
+    pub last_sync: String,
+    pub description: String,
+    pub no_description: String,
+    pub personal_notes: String,
+    pub notes_placeholder: String,
+    pub unknown_priority: String,
+    pub issues: String,
+    pub overdue: String,
+    pub flagged: String,
+    pub assigned_issues: String,
+    pub velocity: String,
+    pub committed: String,
+    pub completed: String,
+    pub changes: String,
+    pub appeared: String,
+    pub disappeared: String,
+    pub moved: String,
+    pub open_issues: String,
+    pub overdue_issues: String,
+    pub recently_completed: String,
+    pub dependencies: String,
+    pub blocked: String,
+    pub days: String,
+    pub deadlines: String,
+    pub due_soon: String,
+    pub tag_index: String,
+    pub labels: String,
+    pub components: String,
+    pub index: String,
+    pub all_issues: String,
+    pub recently_updated: String,
+    pub unassigned: String,
+    pub due: String,
+    pub assignee: String,
+    pub comments: String,
+    pub attachments: String,
+    pub linked_issues: String,
+    pub subtasks: String,
+    pub time_tracking: String,
+    pub original_estimate: String,
+    pub time_spent: String,
+    pub remaining_estimate: String,
+    pub date: String,
+    pub people: String,
+    pub history: String,
+    pub by: String,
+    pub field: String,
+    pub change: String,
+    pub development: String,
+    pub pull_requests: String,
+    pub branches: String,
+    pub commits: String,
+    pub references: String,
+    pub request_type: String,
+    pub restricted: String,
+}
+
+impl Labels {
+    pub fn from_config(config: &Config) -> Labels {
+        let mut labels = Labels::for_locale(&config.locale);
+        labels.apply_overrides(&config.labels);
+        labels
+    }
+
+    pub fn for_locale(locale: &str) -> Labels {
+        match locale {
+            "en" => Labels {
+                details: "Jira Details".into(),
+                status: "Status".into(),
+                priority: "Priority".into(),
+                link: "Link".into(),
+                open_in_jira: "Open in Jira".into(),
+                last_sync: "Last Sync".into(),
+                description: "Description".into(),
+                no_description: "No description provided.".into(),
+                personal_notes: "Personal Notes".into(),
+                notes_placeholder: "Take your notes here.".into(),
+                unknown_priority: "Unknown".into(),
+                issues: "issues".into(),
+                overdue: "overdue".into(),
+                flagged: "flagged".into(),
+                assigned_issues: "Assigned Issues".into(),
+                velocity: "Sprint Velocity".into(),
+                committed: "Committed".into(),
+                completed: "Completed".into(),
+                changes: "Board Changes".into(),
+                appeared: "appeared in".into(),
+                disappeared: "left the board from".into(),
+                moved: "moved".into(),
+                open_issues: "Open Issues".into(),
+                overdue_issues: "Overdue Issues".into(),
+                recently_completed: "Recently Completed".into(),
+                dependencies: "Cross-Project Dependencies".into(),
+                blocked: "Blocked Issues".into(),
+                days: "days".into(),
+                deadlines: "Deadlines".into(),
+                due_soon: "Due Soon".into(),
+                tag_index: "Jira Tags".into(),
+                labels: "Labels".into(),
+                components: "Components".into(),
+                index: "Jira Index".into(),
+                all_issues: "All Issues".into(),
+                recently_updated: "Updated This Week".into(),
+                unassigned: "Unassigned".into(),
+                due: "Due".into(),
+                assignee: "Assignee".into(),
+                comments: "Comments".into(),
+                attachments: "Attachments".into(),
+                linked_issues: "Linked Issues".into(),
+                subtasks: "Subtasks".into(),
+                time_tracking: "Time Tracking".into(),
+                original_estimate: "Original Estimate".into(),
+                time_spent: "Time Spent".into(),
+                remaining_estimate: "Remaining".into(),
+                date: "Date".into(),
+                people: "People".into(),
+                history: "History".into(),
+                by: "By".into(),
+                field: "Field".into(),
+                change: "Change".into(),
+                development: "Development".into(),
+                pull_requests: "Pull Requests".into(),
+                branches: "Branches".into(),
+                commits: "Commits".into(),
+                references: "References".into(),
+                request_type: "Request Type".into(),
+                restricted: "Jira did not return all of this issue to this account (security level, hidden fields or restricted comments); this note may be incomplete.".into(),
+            },
+            "tr" => Labels {
+                details: "Jira Detayları".into(),
+                status: "Durum".into(),
+                priority: "Öncelik".into(),
+                link: "Link".into(),
+                open_in_jira: "Jira'da Aç".into(),
+                last_sync: "Son Sync".into(),
+                description: "Açıklama".into(),
+                no_description: "Açıklama girilmemiş.".into(),
+                personal_notes: "Kişisel Notlarım".into(),
+                notes_placeholder: "Buraya not alabilirsin.".into(),
+                unknown_priority: "Belirsiz".into(),
+                issues: "iş".into(),
+                overdue: "gecikmiş".into(),
+                flagged: "işaretli".into(),
+                assigned_issues: "Atanmış İşler".into(),
+                velocity: "Sprint Hızı".into(),
+                committed: "Taahhüt".into(),
+                completed: "Tamamlanan".into(),
+                changes: "Pano Değişiklikleri".into(),
+                appeared: "panoya eklendi:".into(),
+                disappeared: "panodan çıktı:".into(),
+                moved: "taşındı:".into(),
+                open_issues: "Açık İşler".into(),
+                overdue_issues: "Geciken İşler".into(),
+                recently_completed: "Son Tamamlananlar".into(),
+                dependencies: "Projeler Arası Bağımlılıklar".into(),
+                blocked: "Engellenen İşler".into(),
+                days: "gün".into(),
+                deadlines: "Teslim Tarihleri".into(),
+                due_soon: "Yaklaşanlar".into(),
+                tag_index: "Jira Etiketleri".into(),
+                labels: "Etiketler".into(),
+                components: "Bileşenler".into(),
+                index: "Jira Dizini".into(),
+                all_issues: "Tüm İşler".into(),
+                recently_updated: "Bu Hafta Güncellenenler".into(),
+                unassigned: "Atanmamış".into(),
+                due: "Teslim".into(),
+                assignee: "Atanan".into(),
+                comments: "Yorumlar".into(),
+                attachments: "Ekler".into(),
+                linked_issues: "Bağlantılı İşler".into(),
+                subtasks: "Alt Görevler".into(),
+                time_tracking: "Zaman Takibi".into(),
+                original_estimate: "İlk Tahmin".into(),
+                time_spent: "Harcanan".into(),
+                remaining_estimate: "Kalan".into(),
+                date: "Tarih".into(),
+                people: "Kişiler".into(),
+                history: "Geçmiş".into(),
+                by: "Kim".into(),
+                field: "Alan".into(),
+                change: "Değişiklik".into(),
+                development: "Geliştirme".into(),
+                pull_requests: "Pull Request'ler".into(),
+                branches: "Dallar".into(),
+                commits: "Commit'ler".into(),
+                references: "Referanslar".into(),
+                request_type: "Talep Türü".into(),
+                restricted: "Jira bu işin tamamını bu hesaba göstermedi (güvenlik seviyesi, gizli alanlar ya da kısıtlı yorumlar); not eksik olabilir.".into(),
+            },
+            other => {
+                warn!("⚠️  Bilinmeyen dil '{}', Türkçe kullanılıyor", other);
+                Labels::for_locale("tr")
+            }
+        }
+    }
+
+    fn apply_overrides(&mut self, overrides: &HashMap<String, String>) {
+        for (name, value) in overrides {
+            let field = match name.as_str() {
+                "details" => &mut self.details,
+                "status" => &mut self.status,
+                "priority" => &mut self.priority,
+                "link" => &mut self.link,
+                "open_in_jira" => &mut self.open_in_jira,
+                "last_sync" => &mut self.last_sync,
+                "description" => &mut self.description,
+                "no_description" => &mut self.no_description,
+                "personal_notes" => &mut self.personal_notes,
+                "notes_placeholder" => &mut self.notes_placeholder,
+                "unknown_priority" => &mut self.unknown_priority,
+                "issues" => &mut self.issues,
+                "overdue" => &mut self.overdue,
+                "flagged" => &mut self.flagged,
+                "assigned_issues" => &mut self.assigned_issues,
+                "velocity" => &mut self.velocity,
+                "committed" => &mut self.committed,
+                "completed" => &mut self.completed,
+                "changes" => &mut self.changes,
+                "appeared" => &mut self.appeared,
+                "disappeared" => &mut self.disappeared,
+                "moved" => &mut self.moved,
+                "open_issues" => &mut self.open_issues,
+                "overdue_issues" => &mut self.overdue_issues,
+                "recently_completed" => &mut self.recently_completed,
+                "dependencies" => &mut self.dependencies,
+                "blocked" => &mut self.blocked,
+                "days" => &mut self.days,
+                "deadlines" => &mut self.deadlines,
+                "due_soon" => &mut self.due_soon,
+                "tag_index" => &mut self.tag_index,
+                "labels" => &mut self.labels,
+                "components" => &mut self.components,
+                "index" => &mut self.index,
+                "all_issues" => &mut self.all_issues,
+                "recently_updated" => &mut self.recently_updated,
+                "unassigned" => &mut self.unassigned,
+                "due" => &mut self.due,
+                "assignee" => &mut self.assignee,
+                "comments" => &mut self.comments,
+                "attachments" => &mut self.attachments,
+                "linked_issues" => &mut self.linked_issues,
+                "subtasks" => &mut self.subtasks,
+                "time_tracking" => &mut self.time_tracking,
+                "original_estimate" => &mut self.original_estimate,
+                "time_spent" => &mut self.time_spent,
+                "remaining_estimate" => &mut self.remaining_estimate,
+                "date" => &mut self.date,
+                "people" => &mut self.people,
+                "history" => &mut self.history,
+                "by" => &mut self.by,
+                "field" => &mut self.field,
+                "change" => &mut self.change,
+                "development" => &mut self.development,
+                "pull_requests" => &mut self.pull_requests,
+                "branches" => &mut self.branches,
+                "commits" => &mut self.commits,
+                "references" => &mut self.references,
+                "request_type" => &mut self.request_type,
+                "restricted" => &mut self.restricted,
+                _ => {
+                    warn!("⚠️  Bilinmeyen etiket '{}' yok sayıldı", name);
+                    continue;
+                }
+            };
+            *field = value.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_label_overrides() {
+    let mut config = Config { locale: "en".to_string(), ..Default::default() };
+    config.labels.insert("status".to_string(), "Estado".to_string());
+
+    let labels = Labels::from_config(&config);
+    assert_eq!(labels.status, "Estado");
+    assert_eq!(labels.priority, "Priority");
+    assert_eq!(Labels::for_locale("tr").description, "Açıklama");
+}
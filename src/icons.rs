@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use serde::Deserialize;
+use crate::Issue;
+
+/// `[icons]` in the config file: what to put in front of note titles and
+/// board cards for an issue type and a priority, e.g. `Bug = "🐞"`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IconsConfig {
+    /// Issue type name -> emoji or tag.
+    pub issue_types: HashMap<String, String>,
+    /// Priority name -> emoji or tag.
+    pub priorities: HashMap<String, String>,
+}
+
+impl IconsConfig {
+    /// The issue type's icon, then the priority's, each followed by a space;
+    /// empty when neither is mapped. Names match regardless of case.
+    pub fn prefix(&self, issue: &Issue) -> String {
+        let issue_type = issue.fields.extra.get("issuetype").and_then(|t| t["name"].as_str());
+        let priority = issue.fields.priority.as_ref().map(|p| p.name.as_str());
+        [(&self.issue_types, issue_type), (&self.priorities, priority)]
+            .into_iter()
+            .filter_map(|(icons, name)| lookup(icons, name?))
+            .map(|icon| format!("{} ", icon))
+            .collect()
+    }
+}
+
+fn lookup<'a>(icons: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    icons.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, icon)| icon.as_str()).filter(|icon| !icon.is_empty())
+}
+
+#[cfg(test)]
+#[test]
+fn test_icon_prefix() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": { "summary": "s", "status": { "name": "To Do" }, "priority": { "name": "Highest" }, "issuetype": { "name": "Bug" } }
+    }))
+    .unwrap();
+    let icons = IconsConfig {
+        issue_types: HashMap::from([("bug".to_string(), "🐞".to_string())]),
+        priorities: HashMap::from([("Highest".to_string(), "🔺".to_string()), ("Low".to_string(), "🔽".to_string())]),
+    };
+    assert_eq!(icons.prefix(&issue), "🐞 🔺 ");
+    assert_eq!(IconsConfig::default().prefix(&issue), "");
+}
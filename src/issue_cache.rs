@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+use std::fs;
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::state::State;
+use tracing::debug;
+
+const CACHE_FILE: &str = "issue-details.json";
+
+/// Parts of a raw issue that per-issue requests fill in and that only change
+/// along with the issue's `updated` (or, for watchers, its watcher count):
+/// `(object, key)`, an empty object meaning the issue itself. `restricted`
+/// records that one of those requests was refused. SLA timers and dev-status
+/// data change without touching the issue, so they aren't cached.
+const CACHED: [(&str, &str); 5] =
+    [("fields", "comment"), ("fields", "worklog"), ("fields", "watches"), ("", "remoteLinks"), ("", "restricted")];
+
+/// The issues of the last sync as they were before notes were made from them,
+/// with the details per-issue requests added, so unchanged issues need
+/// neither those requests nor, in an incremental sync, a full fetch. Kept in
+/// `.jira-sync/issue-details.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct IssueCache {
+    /// Which optional details were fetched; when that changes, nothing
+    /// cached can be trusted to be complete.
+    #[serde(default)]
+    details: String,
+    /// When the last sync of all queries started fetching (RFC 3339): every
+    /// issue they matched that was updated before then is in here.
+    #[serde(default)]
+    fetched_at: Option<String>,
+    entries: BTreeMap<String, CachedIssue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedIssue {
+    version: String,
+    issue: Value,
+}
+
+/// Changes whenever anything cached for the issue could have.
+fn version(raw: &Value) -> String {
+    let fields = &raw["fields"];
+    format!("{}/{}", fields["updated"].as_str().unwrap_or_default(), fields["watches"]["watchCount"].as_u64().unwrap_or(0))
+}
+
+fn slot<'a>(raw: &'a mut Value, (object, key): (&str, &str)) -> &'a mut Value {
+    match object {
+        "" => &mut raw[key],
+        object => &mut raw[object][key],
+    }
+}
+
+impl IssueCache {
+    /// The cache for a sync fetching the optional `details` named. An
+    /// unreadable cache, or one made with other details, is only a missed
+    /// shortcut: start empty.
+    pub fn load(vault_path: &str, details: &str) -> IssueCache {
+        let path = State::dir(vault_path).join(CACHE_FILE);
+        let cache = fs::read_to_string(&path).ok().and_then(|content| serde_json::from_str::<IssueCache>(&content).ok());
+        match cache {
+            Some(cache) if cache.details == details => cache,
+            _ => IssueCache { details: details.to_string(), ..Default::default() },
+        }
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(CACHE_FILE), serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The watermark of an incremental sync: when the last full one started.
+    pub fn fetched_at(&self) -> Option<DateTime<FixedOffset>> {
+        DateTime::parse_from_rfc3339(self.fetched_at.as_deref()?).ok()
+    }
+
+    /// The cached raw issue for `key`.
+    pub fn issue(&self, key: &str) -> Option<&Value> {
+        self.entries.get(key).map(|cached| &cached.issue)
+    }
+
+    /// Fills in the cached details of every issue that hasn't changed since
+    /// they were stored, and returns those issues' keys: they need no
+    /// per-issue requests this time.
+    pub fn restore(&self, issues: &mut [Value]) -> HashSet<String> {
+        let mut unchanged = HashSet::new();
+        for raw in issues {
+            let key = raw["key"].as_str().unwrap_or_default().to_string();
+            let Some(cached) = self.entries.get(&key).filter(|cached| cached.version == version(raw)) else { continue };
+            let mut cached = cached.issue.clone();
+            for path in CACHED {
+                let value = slot(&mut cached, path).take();
+                if !value.is_null() {
+                    *slot(raw, path) = value;
+                }
+            }
+            unchanged.insert(key);
+        }
+        debug!("{} iş değişmemiş; ayrıntıları önbellekten", unchanged.len());
+        unchanged
+    }
+
+    /// Remembers `issues`. After a sync of all queries, `fetched_at` is when
+    /// it started fetching, and issues it didn't see are forgotten.
+    pub fn store(&mut self, issues: &[Value], fetched_at: Option<DateTime<Local>>) {
+        if let Some(fetched_at) = fetched_at {
+            let keys: HashSet<&str> = issues.iter().filter_map(|raw| raw["key"].as_str()).collect();
+            self.entries.retain(|key, _| keys.contains(key.as_str()));
+            self.fetched_at = Some(fetched_at.to_rfc3339());
+        }
+        for raw in issues {
+            let Some(key) = raw["key"].as_str() else { continue };
+            self.entries.insert(key.to_string(), CachedIssue { version: version(raw), issue: raw.clone() });
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_issue_cache() {
+    let issue = |updated: &str, comments: Value| {
+        serde_json::json!({ "key": "A-1", "fields": { "updated": updated, "comment": comments, "watches": { "watchCount": 1 } } })
+    };
+    let full = serde_json::json!({ "total": 2, "comments": [{ "body": "1" }, { "body": "2" }] });
+    let first_page = serde_json::json!({ "total": 2, "comments": [{ "body": "1" }] });
+    let mut links = issue("t1", full.clone());
+    links["remoteLinks"] = serde_json::json!([{ "object": { "url": "https://wiki" } }]);
+
+    let mut cache = IssueCache::default();
+    assert!(cache.fetched_at().is_none());
+    cache.store(&[links.clone()], Some(Local::now()));
+    assert!(cache.fetched_at().is_some());
+    assert_eq!(cache.issue("A-1"), Some(&links));
+
+    let mut same = [issue("t1", first_page.clone())];
+    assert_eq!(cache.restore(&mut same), HashSet::from(["A-1".to_string()]));
+    assert_eq!(same[0], links);
+
+    let mut changed = [issue("t2", first_page.clone())];
+    assert!(cache.restore(&mut changed).is_empty());
+    assert_eq!(changed[0]["fields"]["comment"], first_page);
+
+    cache.store(&[], Some(Local::now()));
+    assert!(cache.issue("A-1").is_none());
+}
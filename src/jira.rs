@@ -0,0 +1,616 @@
+use std::borrow::Cow;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::fmt;
+use std::time::{Duration, Instant};
+use chrono::{DateTime, Local};
+use reqwest::header::HeaderMap;
+use reqwest::{Certificate, Client, Method, Proxy, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+use crate::auth::{self, Auth, AuthMethod, Detected};
+use crate::capabilities::Capabilities;
+use crate::config::Config;
+use crate::oauth::OAuthClient;
+use crate::state::State;
+use tracing::{debug, warn};
+
+/// Append-only record of every write we make to Jira, one JSON object per line.
+const AUDIT_FILE: &str = "audit.log";
+/// How much of Jira's response body is kept in the audit log.
+const AUDIT_RESPONSE_LIMIT: usize = 500;
+/// Entries asked for per request when completing an issue's comments or worklogs.
+const FIELD_PAGE_SIZE: usize = 100;
+/// Most issues `issue/bulkfetch` returns per request.
+pub const BULK_FETCH_SIZE: usize = 100;
+/// Longest we wait on a rate-limited response, whatever Jira asks for.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// How to reach Jira from behind a corporate proxy. `HTTPS_PROXY`/`HTTP_PROXY`
+/// and `NO_PROXY` are honoured without any of this.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// Proxy URL for every request, overriding the environment.
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates, e.g. of a TLS-intercepting proxy.
+    pub ca_cert: Option<String>,
+    /// Skips certificate checks entirely. Only as a last resort.
+    pub danger_accept_invalid_certs: bool,
+    /// Seconds to wait for a connection (default 10).
+    pub connect_timeout_secs: Option<u64>,
+    /// Seconds to wait for each read from Jira (default 60).
+    pub read_timeout_secs: Option<u64>,
+    /// Extra attempts after a network failure, a 429 or a 502/503/504 (default 3).
+    pub retries: Option<u32>,
+    /// Wait before the first retry in milliseconds, doubled for each further one (default 500).
+    pub retry_delay_ms: Option<u64>,
+    /// Search pages (or batches of issues) fetched at the same time (default 4).
+    pub concurrency: Option<usize>,
+}
+
+impl HttpConfig {
+    pub fn concurrency(&self) -> usize {
+        self.concurrency.unwrap_or(4).max(1)
+    }
+
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        Duration::from_millis(self.retry_delay_ms.unwrap_or(500).saturating_mul(1 << attempt.min(10)))
+    }
+}
+
+/// How long a rate-limited (429) response asks us to wait: `Retry-After` in
+/// seconds, or the `X-RateLimit-Reset` time Jira Cloud sends, capped at
+/// [`MAX_RETRY_AFTER`]. `None` when neither is given.
+fn retry_after(headers: &HeaderMap, now: DateTime<chrono::Utc>) -> Option<Duration> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+    let delay = match header("retry-after").and_then(|v| v.parse::<u64>().ok()) {
+        Some(seconds) => Duration::from_secs(seconds),
+        None => {
+            let reset = DateTime::parse_from_rfc3339(header("x-ratelimit-reset")?).ok()?;
+            (reset.with_timezone(&chrono::Utc) - now).to_std().unwrap_or_default()
+        }
+    };
+    Some(delay.min(MAX_RETRY_AFTER))
+}
+
+/// `delay` plus up to a quarter more, so that parallel syncs hitting the same
+/// limit don't all come back at the same moment.
+fn with_jitter(delay: Duration) -> Duration {
+    let random = RandomState::new().hash_one(Instant::now());
+    delay + delay.mul_f64((random % 1000) as f64 / 4000.0)
+}
+
+/// A request that still failed after all retries; keeps the network error as
+/// its source so [`is_offline`] can see it.
+struct RequestFailed {
+    target: String,
+    attempts: u32,
+    source: reqwest::Error,
+}
+
+impl fmt::Display for RequestFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cause = if self.source.is_timeout() {
+            "zaman aşımı"
+        } else if self.source.is_connect() {
+            "bağlantı kurulamadı"
+        } else {
+            "ağ hatası"
+        };
+        write!(f, "{} {} denemede başarısız ({}): {}", self.target, self.attempts, cause, self.source)
+    }
+}
+
+impl fmt::Debug for RequestFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for RequestFailed {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// The HTTP client every Jira request goes through.
+pub fn http_client(config: &HttpConfig) -> Result<Client, Box<dyn Error>> {
+    let mut builder = Client::builder()
+        .connect_timeout(Duration::from_secs(config.connect_timeout_secs.unwrap_or(10)))
+        .read_timeout(Duration::from_secs(config.read_timeout_secs.unwrap_or(60)));
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(Proxy::all(proxy)?);
+    }
+    if let Some(path) = &config.ca_cert {
+        let pem = fs::read(path).map_err(|e| format!("{} okunamadı: {}", path, e))?;
+        for cert in Certificate::from_pem_bundle(&pem)? {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+    if config.danger_accept_invalid_certs {
+        warn!("⚠️  TLS sertifika doğrulaması kapalı (danger_accept_invalid_certs)");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    Ok(builder.build()?)
+}
+
+/// Thin wrapper around reqwest that knows the Jira host and how to authenticate.
+pub struct JiraClient {
+    http: Client,
+    base_url: String,
+    user: String,
+    token: String,
+    audit_path: PathBuf,
+    read_only: bool,
+    retry: HttpConfig,
+    /// REST API version paths are sent to; code always names `/rest/api/3`.
+    api_version: AtomicU8,
+    /// The version was configured (or detected before), so detection leaves it.
+    api_version_fixed: bool,
+    /// Configured auth method; `None` means detect it on the first request.
+    auth_method: Option<AuthMethod>,
+    auth: OnceCell<Auth>,
+    /// Vault whose capabilities file remembers what was detected; `None` in
+    /// dry-run mode.
+    remember_in: Option<String>,
+    /// Set when signed in with OAuth: requests then go through
+    /// api.atlassian.com with a bearer token instead of basic auth.
+    oauth: Option<OAuthClient>,
+    /// Set once `search/jql` turned out to be missing (older Server/Data
+    /// Center); searches then use the legacy `search` endpoint.
+    legacy_search: AtomicBool,
+}
+
+impl JiraClient {
+    /// Fails when the HTTP settings can't be used, e.g. an unreadable
+    /// `ca_cert` bundle or a malformed proxy URL.
+    pub fn new(config: &Config) -> Result<JiraClient, Box<dyn Error>> {
+        let oauth = OAuthClient::load(config);
+        if config.oauth.enabled() && oauth.is_none() {
+            warn!("⚠️  OAuth oturumu bulunamadı; `login --oauth` ile giriş yapın");
+        }
+        Ok(JiraClient {
+            http: http_client(&config.http).map_err(|e| format!("HTTP istemcisi kurulamadı: {}", e))?,
+            base_url: oauth.as_ref().map_or_else(|| config.base_url(), OAuthClient::base_url),
+            user: config.jira_user.clone(),
+            token: config.jira_token.clone(),
+            audit_path: State::dir(&config.vault_path).join(AUDIT_FILE),
+            read_only: !config.allows_any_write(),
+            retry: config.http.clone(),
+            api_version: AtomicU8::new(config.api_version()),
+            api_version_fixed: config.api_version.is_some(),
+            auth_method: config.auth,
+            auth: OnceCell::new(),
+            remember_in: Some(config.vault_path.clone()).filter(|v| !v.is_empty() && !config.dry_run),
+            oauth,
+            legacy_search: AtomicBool::new(false),
+        })
+    }
+
+    /// The credentials to send: the configured method, or on first use the
+    /// one [`auth::detect`] finds (which also settles the API version).
+    async fn auth(&self) -> Result<&Auth, Box<dyn Error>> {
+        self.auth
+            .get_or_try_init(|| async {
+                if let Some(method) = self.auth_method {
+                    return Auth::new(method, &self.http, &self.base_url, &self.user, &self.token).await;
+                }
+                let detected = auth::detect(&self.http, &self.base_url, &self.user, &self.token).await;
+                if !self.api_version_fixed {
+                    self.api_version.store(detected.api_version, Ordering::Relaxed);
+                }
+                if detected.confirmed {
+                    self.remember(&detected);
+                }
+                Ok(detected.auth)
+            })
+            .await
+    }
+
+    /// The auth method in use; `None` with OAuth.
+    pub async fn auth_method(&self) -> Option<AuthMethod> {
+        match self.oauth {
+            Some(_) => None,
+            None => self.auth().await.ok().map(Auth::method),
+        }
+    }
+
+    /// Keeps a detection result so later runs skip it.
+    fn remember(&self, detected: &Detected) {
+        let Some(vault) = &self.remember_in else { return };
+        let mut caps = Capabilities::load(vault).unwrap_or_default();
+        caps.deployment_type = detected.deployment_type.clone();
+        caps.api_version = detected.api_version;
+        caps.auth = Some(detected.auth.method());
+        if let Err(e) = caps.save(vault) {
+            warn!("⚠️  Algılanan Jira ayarları kaydedilemedi: {}", e);
+        }
+    }
+
+    /// Whether searches go to the legacy `/search` endpoint.
+    pub fn uses_legacy_search(&self) -> bool {
+        self.legacy_search.load(Ordering::Relaxed)
+    }
+
+    /// Path searches are POSTed to.
+    pub fn search_path(&self) -> &'static str {
+        if self.uses_legacy_search() {
+            "/rest/api/3/search"
+        } else {
+            "/rest/api/3/search/jql"
+        }
+    }
+
+    /// How many searches may be in flight at once.
+    pub fn concurrency(&self) -> usize {
+        self.retry.concurrency()
+    }
+
+    /// Switches searches to the legacy endpoint; `false` if they already use it.
+    pub fn fall_back_to_legacy_search(&self) -> bool {
+        !self.legacy_search.swap(true, Ordering::Relaxed)
+    }
+
+    /// A request to `path` (e.g. `/rest/api/3/myself`); [`send`](Self::send)
+    /// adds auth and adjusts the API version.
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, format!("{}{}", self.base_url, path))
+    }
+
+    /// Sends a request, logging its status and how long Jira took. Network
+    /// failures and 502/503/504 are retried with exponential backoff, except
+    /// that a write which may have reached Jira is never sent twice. A 429 is
+    /// always retried, after the wait Jira asks for when it names one.
+    pub async fn send(&self, builder: RequestBuilder) -> Result<Response, Box<dyn Error>> {
+        let builder = match &self.oauth {
+            Some(oauth) => builder.bearer_auth(oauth.access_token(&self.http).await?),
+            None => self.auth().await?.apply(builder),
+        };
+        let mut request = builder.build()?;
+        let version = self.api_version.load(Ordering::Relaxed);
+        if let Cow::Owned(path) = api_path(request.url().path(), version) {
+            request.url_mut().set_path(&path);
+        }
+        let (method, path) = (request.method().clone(), request.url().path().to_string());
+        let repeatable = is_repeatable(&method, &path);
+        let retries = self.retry.retries.unwrap_or(3);
+        let mut attempt = 0;
+        loop {
+            // Gövdesi kopyalanamayan istek tek denemede gönderilir.
+            let retry = request.try_clone().filter(|_| attempt < retries);
+            let started = Instant::now();
+            let result = self.http.execute(request).await;
+            let ms = started.elapsed().as_millis() as u64;
+            match &result {
+                Ok(resp) => debug!(%method, path, status = resp.status().as_u16(), ms, attempt, "jira"),
+                Err(e) => debug!(%method, path, error = %e, ms, attempt, "jira"),
+            }
+            let transient = match &result {
+                // 429: Jira isteği işlemeden geri çevirdi, yazmalar da tekrar gönderilebilir.
+                Ok(resp) => resp.status() == StatusCode::TOO_MANY_REQUESTS || (repeatable && matches!(resp.status().as_u16(), 502..=504)),
+                // Bağlantı hiç kurulamadıysa istek Jira'ya ulaşmamıştır.
+                Err(e) => e.is_connect() || (repeatable && e.is_timeout()),
+            };
+            match (result, retry) {
+                (result, Some(next)) if transient => {
+                    let delay = match &result {
+                        Ok(resp) if resp.status() == StatusCode::TOO_MANY_REQUESTS => retry_after(resp.headers(), chrono::Utc::now()),
+                        _ => None,
+                    };
+                    let delay = with_jitter(delay.unwrap_or_else(|| self.retry.retry_delay(attempt)));
+                    let reason = match &result {
+                        Ok(resp) => resp.status().to_string(),
+                        Err(e) => e.to_string(),
+                    };
+                    warn!("⏳ {} {}: {}; {} ms sonra tekrar denenecek ({}/{})", method, path, reason, delay.as_millis(), attempt + 1, retries);
+                    tokio::time::sleep(delay).await;
+                    request = next;
+                    attempt += 1;
+                }
+                (Ok(resp), _) => return Ok(resp),
+                (Err(source), _) => {
+                    return Err(Box::new(RequestFailed { target: format!("{} {}", method, path), attempts: attempt + 1, source }));
+                }
+            }
+        }
+    }
+
+    /// Fetches an absolute URL Jira handed out (avatars, attachments) and
+    /// returns the body with its content type. Credentials only go along when
+    /// the URL is on the Jira server itself; avatars on Gravatar or a CDN are
+    /// fetched without them.
+    pub async fn download(&self, url: &str) -> Result<(Vec<u8>, String), Box<dyn Error>> {
+        let resp = if same_origin(url, &self.base_url) {
+            self.send(self.http.get(url)).await?
+        } else {
+            self.http.get(url).send().await?
+        };
+        let resp = check(resp).await?;
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        Ok((resp.bytes().await?.to_vec(), content_type))
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, Box<dyn Error>> {
+        let resp = check(self.send(self.request(Method::GET, path)).await?).await?;
+        Ok(resp.json().await?)
+    }
+
+    /// Search results carry only the first entries of busy issues' paged
+    /// fields (`comment`, `worklog`); fetches the rest from
+    /// `/issue/{key}/{field}` so `issue["fields"][field][items]` is complete.
+    pub async fn complete_field(&self, issue: &mut serde_json::Value, field: &str, items: &str) -> Result<(), Box<dyn Error>> {
+        let value = &issue["fields"][field];
+        let (Some(found), Some(total)) = (value[items].as_array(), value["total"].as_u64()) else {
+            return Ok(());
+        };
+        if found.len() as u64 >= total {
+            return Ok(());
+        }
+        let key = issue["key"].as_str().unwrap_or_default().to_string();
+        let mut all = Vec::new();
+        loop {
+            let mut path = format!("/rest/api/3/issue/{}/{}?startAt={}&maxResults={}", key, field, all.len(), FIELD_PAGE_SIZE);
+            if field == "comment" {
+                // Sayfa sayfa alınan yorumlar renderedFields içinde yok.
+                path.push_str("&expand=renderedBody");
+            }
+            let page: serde_json::Value = self.get_json(&path).await?;
+            let found = page[items].as_array().cloned().unwrap_or_default();
+            let done = found.is_empty() || all.len() + found.len() >= page["total"].as_u64().unwrap_or(0) as usize;
+            all.extend(found);
+            if done {
+                break;
+            }
+        }
+        issue["fields"][field][items] = serde_json::Value::Array(all);
+        Ok(())
+    }
+
+    /// Up to [`BULK_FETCH_SIZE`] issues by id or key in one request, with the
+    /// same fields and expansions a search would ask for. The order of the
+    /// result is Jira's, not `ids`'.
+    pub async fn bulk_fetch(&self, ids: &[String], fields: &[&str], expand: &[&str]) -> Result<Vec<serde_json::Value>, Box<dyn Error>> {
+        let body = serde_json::json!({ "issueIdsOrKeys": ids, "fields": fields, "expand": expand });
+        let resp = check(self.send(self.request(Method::POST, "/rest/api/3/issue/bulkfetch").json(&body)).await?).await?;
+        let mut page: serde_json::Value = resp.json().await?;
+        if let Some(errors) = page["issueErrors"].as_array().filter(|e| !e.is_empty()) {
+            warn!("⚠️  {} iş toplu olarak alınamadı", errors.len());
+        }
+        Ok(serde_json::from_value(page["issues"].take()).unwrap_or_default())
+    }
+
+    /// Sends a write request and returns the response body. Every attempt,
+    /// successful or not, is appended to the audit log.
+    pub async fn post_json(&self, path: &str, body: &serde_json::Value) -> Result<String, Box<dyn Error>> {
+        self.write(Method::POST, path, body).await
+    }
+
+    pub async fn put_json(&self, path: &str, body: &serde_json::Value) -> Result<String, Box<dyn Error>> {
+        self.write(Method::PUT, path, body).await
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<String, Box<dyn Error>> {
+        self.write(Method::DELETE, path, &serde_json::Value::Null).await
+    }
+
+    async fn write(&self, method: Method, path: &str, body: &serde_json::Value) -> Result<String, Box<dyn Error>> {
+        // Son savunma hattı: yazma izni yoksa istek hiç gönderilmez.
+        if self.read_only {
+            return Err(format!("Salt okunur mod: {} {} engellendi (allow_writes = true gerekli)", method, path).into());
+        }
+        let mut request = self.request(method.clone(), path);
+        if !body.is_null() {
+            request = request.json(body);
+        }
+        let resp = match self.send(request).await {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.audit(&method, path, body, None, &e.to_string());
+                return Err(e);
+            }
+        };
+        let status = resp.status();
+        let text = resp.text().await.unwrap_or_default();
+        self.audit(&method, path, body, Some(status), &text);
+
+        if status.is_success() {
+            Ok(text)
+        } else {
+            Err(format!("Jira {} ({}): {}", status, path, text).into())
+        }
+    }
+
+    fn audit(&self, method: &Method, path: &str, body: &serde_json::Value, status: Option<reqwest::StatusCode>, response: &str) {
+        let entry = serde_json::json!({
+            "time": Local::now().to_rfc3339(),
+            "method": method.as_str(),
+            "path": path,
+            "payload": body,
+            "status": status.map(|s| s.as_u16()),
+            "response": response.chars().take(AUDIT_RESPONSE_LIMIT).collect::<String>(),
+        });
+        let written = self.audit_path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| {
+            let mut file = OpenOptions::new().create(true).append(true).open(&self.audit_path)?;
+            writeln!(file, "{}", entry)
+        });
+        if let Err(e) = written {
+            warn!("⚠️  Denetim kaydı yazılamadı ({}): {}", self.audit_path.display(), e);
+        }
+    }
+}
+
+/// Whether `error` means Jira couldn't be reached at all, as opposed to Jira
+/// rejecting the request.
+pub fn is_offline(error: &(dyn Error + 'static)) -> bool {
+    std::iter::successors(Some(error), |&e| e.source())
+        .filter_map(|e| e.downcast_ref::<reqwest::Error>())
+        .any(|e| e.is_connect() || e.is_timeout())
+}
+
+/// `path` for another REST API version: `/rest/api/3/...` becomes
+/// `/rest/api/2/...` on Server/Data Center, behind any context path.
+fn api_path(path: &str, version: u8) -> Cow<'_, str> {
+    const V3: &str = "/rest/api/3/";
+    match path.find(V3) {
+        Some(at) if version != 3 => Cow::Owned(format!("{}/rest/api/{}/{}", &path[..at], version, &path[at + V3.len()..])),
+        _ => Cow::Borrowed(path),
+    }
+}
+
+/// Where the next page of a search starts.
+#[derive(Debug, PartialEq)]
+pub enum PageCursor {
+    /// `search/jql` hands out a token for the next page.
+    Token(String),
+    /// The legacy `search` endpoint counts issues instead.
+    StartAt(usize),
+}
+
+/// One page of search results in either the `search/jql` or the legacy
+/// `search` shape. Issues stay raw JSON so plugins can rewrite them first.
+#[derive(Debug, Deserialize)]
+pub struct SearchPage {
+    pub issues: Vec<serde_json::Value>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+    #[serde(rename = "isLast")]
+    is_last: Option<bool>,
+    #[serde(rename = "startAt")]
+    start_at: Option<usize>,
+    total: Option<usize>,
+}
+
+impl SearchPage {
+    /// `None` once the result set is exhausted.
+    pub fn next(&self) -> Option<PageCursor> {
+        if self.issues.is_empty() || self.is_last == Some(true) {
+            return None;
+        }
+        if let Some(token) = &self.next_page_token {
+            return Some(PageCursor::Token(token.clone()));
+        }
+        let end = self.start_at? + self.issues.len();
+        (end < self.total?).then_some(PageCursor::StartAt(end))
+    }
+
+    /// Where every page after this one starts, when the result says how many
+    /// issues there are (the legacy `search`), so they can be fetched at once.
+    pub fn remaining_starts(&self) -> Vec<usize> {
+        let (Some(PageCursor::StartAt(next)), Some(total)) = (self.next(), self.total) else {
+            return Vec::new();
+        };
+        (next..total).step_by(self.issues.len()).collect()
+    }
+}
+
+/// Whether sending the request again can't change anything in Jira: reads,
+/// plus writes that set rather than add. Searches are POSTed but only read.
+fn is_repeatable(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE => true,
+        Method::POST => ["/search/jql", "/search", "/jql/parse", "/issue/bulkfetch"].iter().any(|end| path.ends_with(end)),
+        _ => false,
+    }
+}
+
+/// Whether `error` is Jira refusing access (401/403) as reported by [`check`],
+/// as opposed to a failure worth retrying or reporting.
+pub fn is_forbidden(error: &dyn Error) -> bool {
+    matches!(status_of(error), Some(401 | 403))
+}
+
+/// The HTTP status of a non-2xx response reported by [`check`].
+pub fn status_of(error: &dyn Error) -> Option<u16> {
+    error.to_string().strip_prefix("Jira ")?.get(..3)?.parse().ok()
+}
+
+/// Turns a non-2xx response into an error carrying Jira's message.
+async fn check(resp: Response) -> Result<Response, Box<dyn Error>> {
+    if resp.status().is_success() {
+        return Ok(resp);
+    }
+    let status = resp.status();
+    let url = resp.url().path().to_string();
+    let body = resp.text().await.unwrap_or_default();
+    Err(format!("Jira {} ({}): {}", status, url, body).into())
+}
+
+/// Whether `url` has the scheme, host and port of `base`.
+fn same_origin(url: &str, base: &str) -> bool {
+    match (reqwest::Url::parse(url), reqwest::Url::parse(base)) {
+        (Ok(url), Ok(base)) => url.origin() == base.origin(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_http_client() {
+    assert!(http_client(&HttpConfig::default()).is_ok());
+    let proxied = HttpConfig { proxy: Some("http://proxy.corp.lan:3128".into()), danger_accept_invalid_certs: true, ..Default::default() };
+    assert!(http_client(&proxied).is_ok());
+    let missing = HttpConfig { ca_cert: Some("/nonexistent/corp-ca.pem".into()), ..Default::default() };
+    assert!(http_client(&missing).unwrap_err().to_string().contains("/nonexistent/corp-ca.pem"));
+
+    let retry = HttpConfig { retry_delay_ms: Some(200), ..Default::default() };
+    assert_eq!(retry.retry_delay(0), Duration::from_millis(200));
+    assert_eq!(retry.retry_delay(2), Duration::from_millis(800));
+    let jittered = with_jitter(Duration::from_millis(800));
+    assert!(jittered >= Duration::from_millis(800) && jittered <= Duration::from_millis(1000));
+
+    let now = DateTime::parse_from_rfc3339("2026-10-16T12:00:00Z").unwrap().with_timezone(&chrono::Utc);
+    let mut headers = HeaderMap::new();
+    assert_eq!(retry_after(&headers, now), None);
+    headers.insert("x-ratelimit-reset", "2026-10-16T12:00:30Z".parse().unwrap());
+    assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(30)));
+    headers.insert("retry-after", "7".parse().unwrap());
+    assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(7)));
+    headers.insert("retry-after", "3600".parse().unwrap());
+    assert_eq!(retry_after(&headers, now), Some(MAX_RETRY_AFTER));
+    assert!(is_repeatable(&Method::POST, "/rest/api/3/search/jql"));
+    assert!(is_repeatable(&Method::PUT, "/rest/api/3/issue/A-1/assignee"));
+    assert!(!is_repeatable(&Method::POST, "/rest/api/3/issue/A-1/comment"));
+    assert!(same_origin("https://acme.atlassian.net/rest/api/3/attachment/content/1", "https://acme.atlassian.net"));
+    assert!(!same_origin("https://secure.gravatar.com/avatar/x?s=48", "https://acme.atlassian.net"));
+    assert!(!same_origin("http://acme.atlassian.net/x", "https://acme.atlassian.net"));
+    assert!(!same_origin("https://jira.corp:8443/x", "https://jira.corp"));
+    let denied: Box<dyn Error> = "Jira 401 Unauthorized (/rest/api/3/myself): ".into();
+    assert_eq!(status_of(denied.as_ref()), Some(401));
+    assert!(is_forbidden(denied.as_ref()));
+    let offline: Box<dyn Error> = "error sending request for url (https://jira.corp/)".into();
+    assert_eq!(status_of(offline.as_ref()), None);
+
+    assert_eq!(api_path("/rest/api/3/search/jql", 2), "/rest/api/2/search/jql");
+    assert_eq!(api_path("/rest/api/3/myself", 3), "/rest/api/3/myself");
+    assert_eq!(api_path("/rest/agile/1.0/board", 2), "/rest/agile/1.0/board");
+    assert_eq!(api_path("/jira/rest/api/3/issue/A-1", 2), "/jira/rest/api/2/issue/A-1");
+}
+
+#[cfg(test)]
+#[test]
+fn test_search_pages() {
+    let page = |json: serde_json::Value| serde_json::from_value::<SearchPage>(json).unwrap().next();
+    let issues = serde_json::json!([{ "key": "A-1" }, { "key": "A-2" }]);
+    assert_eq!(page(serde_json::json!({ "issues": issues, "nextPageToken": "t2" })), Some(PageCursor::Token("t2".into())));
+    assert_eq!(page(serde_json::json!({ "issues": issues, "isLast": true })), None);
+    assert_eq!(page(serde_json::json!({ "issues": issues })), None);
+    assert_eq!(page(serde_json::json!({ "issues": issues, "startAt": 0, "total": 5 })), Some(PageCursor::StartAt(2)));
+    assert_eq!(page(serde_json::json!({ "issues": issues, "startAt": 3, "total": 5 })), None);
+    assert_eq!(page(serde_json::json!({ "issues": [], "startAt": 0, "total": 5 })), None);
+
+    let starts = |json: serde_json::Value| serde_json::from_value::<SearchPage>(json).unwrap().remaining_starts();
+    assert_eq!(starts(serde_json::json!({ "issues": issues, "startAt": 0, "total": 7 })), [2, 4, 6]);
+    assert_eq!(starts(serde_json::json!({ "issues": issues, "nextPageToken": "t2" })), Vec::<usize>::new());
+}
@@ -0,0 +1,88 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use regex::{Captures, Regex};
+
+/// Placeholders JQL may contain, e.g. `updated >= {{start_of_week}}`.
+const PLACEHOLDERS: [&str; 5] = ["today", "yesterday", "start_of_week", "start_of_month", "me"];
+
+/// Whether `jql` mentions `{{me}}`, which costs a request to resolve.
+pub fn uses_me(jql: &str) -> bool {
+    placeholder().captures_iter(jql).any(|c| &c[1] == "me")
+}
+
+/// Fills in the placeholders of `jql` as quoted JQL values: dates as
+/// `"YYYY-MM-DD"` (weeks start on Monday), `me` as the signed-in user's
+/// accountId (or username on Jira Server).
+pub fn expand(jql: &str, today: NaiveDate, me: Option<&str>) -> Result<String, String> {
+    let mut unknown = None;
+    let expanded = placeholder().replace_all(jql, |c: &Captures| {
+        let date = match &c[1] {
+            "today" => today,
+            "yesterday" => today - Duration::days(1),
+            "start_of_week" => today - Duration::days(today.weekday().num_days_from_monday().into()),
+            "start_of_month" => today.with_day(1).unwrap_or(today),
+            "me" => return format!("\"{}\"", me.unwrap_or_default()),
+            other => {
+                unknown.get_or_insert_with(|| other.to_string());
+                return c[0].to_string();
+            }
+        };
+        format!("\"{}\"", date.format("%Y-%m-%d"))
+    });
+    match unknown {
+        Some(name) => Err(format!("JQL içinde bilinmeyen yer tutucu {{{{{}}}}} (kullanılabilenler: {})", name, PLACEHOLDERS.join(", "))),
+        None => Ok(expanded.into_owned()),
+    }
+}
+
+/// `jql` narrowed to issues updated in the last `minutes`, keeping its
+/// `ORDER BY`. Relative times are used because absolute ones would be read in
+/// the Jira user's time zone, which we don't know.
+pub fn updated_since(jql: &str, minutes: i64) -> String {
+    let order_by = Regex::new(r"(?i)\border\s+by\b").unwrap();
+    let (filter, order) = match order_by.find_iter(jql).last() {
+        Some(m) => (jql[..m.start()].trim(), &jql[m.start()..]),
+        None => (jql.trim(), ""),
+    };
+    let recent = format!("updated >= \"-{}m\"", minutes);
+    let narrowed = match filter {
+        "" => recent,
+        filter => format!("({}) AND {}", filter, recent),
+    };
+    match order {
+        "" => narrowed,
+        order => format!("{} {}", narrowed, order),
+    }
+}
+
+fn placeholder() -> Regex {
+    Regex::new(r"\{\{\s*(\w+)\s*\}\}").unwrap()
+}
+
+#[cfg(test)]
+#[test]
+fn test_expand_placeholders() {
+    let thursday = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+    assert_eq!(
+        expand("updated >= {{start_of_week}} AND due <= {{ today }}", thursday, None).unwrap(),
+        r#"updated >= "2026-10-12" AND due <= "2026-10-15""#
+    );
+    assert_eq!(
+        expand("created >= {{start_of_month}} OR created = {{yesterday}}", thursday, None).unwrap(),
+        r#"created >= "2026-10-01" OR created = "2026-10-14""#
+    );
+    assert!(uses_me("reporter = {{me}}"));
+    assert!(!uses_me("reporter = currentUser()"));
+    assert_eq!(expand("reporter = {{me}}", thursday, Some("5b10ac8d")).unwrap(), r#"reporter = "5b10ac8d""#);
+    assert!(expand("updated >= {{last_sprint}}", thursday, None).unwrap_err().contains("{{last_sprint}}"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_updated_since() {
+    assert_eq!(
+        updated_since("assignee = currentUser() OR reporter = currentUser() ORDER BY updated DESC", 65),
+        r#"(assignee = currentUser() OR reporter = currentUser()) AND updated >= "-65m" ORDER BY updated DESC"#
+    );
+    assert_eq!(updated_since("project = A", 5), r#"(project = A) AND updated >= "-5m""#);
+    assert_eq!(updated_since("order by rank", 5), r#"updated >= "-5m" order by rank"#);
+}
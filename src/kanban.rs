@@ -0,0 +1,713 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::i18n::Labels;
+use crate::icons::IconsConfig;
+use crate::jira::JiraClient;
+use crate::layout::sanitize;
+use crate::service_desk;
+use crate::sprints;
+use crate::Issue;
+use tracing::warn;
+
+const SETTINGS_MARKER: &str = "%% kanban:settings";
+/// Between a card's link and its story points, and a column's count and points.
+const POINTS_SEPARATOR: &str = " · ";
+
+/// Board layout from the config file:
+///
+/// ```toml
+/// [board]
+/// columns = [
+///     { name = "Backlog", statuses = ["To Do", "Open"] },
+///     { name = "Doing", statuses = ["In Progress", "In Review"] },
+/// ]
+/// unmapped = "column"      # or "skip"
+/// unmapped_column = "Other"
+/// changes_file = "JiraChanges.md"
+/// ```
+///
+/// Without `columns` the columns of the Agile board `board_id` are used, and
+/// without that every status gets its own column.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BoardConfig {
+    pub columns: Vec<ColumnConfig>,
+    /// Jira board whose column configuration is used when `columns` is empty.
+    pub board_id: Option<u64>,
+    pub unmapped: UnmappedPolicy,
+    /// Name of the catch-all column (default "Other").
+    pub unmapped_column: Option<String>,
+    /// Field holding Jira's "Flagged" marker (default `customfield_10021`).
+    pub flagged_field: Option<String>,
+    /// Story points fields, first with a value wins: classic projects use
+    /// "Story Points", team-managed ones "Story point estimate". Found by
+    /// `capabilities` when unset.
+    pub points_fields: Vec<String>,
+    /// Jira's "Rank" field (LexoRank strings); cards in a column follow it,
+    /// as on the board in Jira. Found by `capabilities` when unset.
+    pub rank_field: Option<String>,
+    /// File name of the main board (default "JiraKanban.md").
+    pub file: Option<String>,
+    /// Note listing cards that appeared, left or moved (default "JiraChanges.md").
+    pub changes_file: Option<String>,
+    /// Column in front of the others holding the backlog issues of `board_id`
+    /// while it has an active sprint (default "Backlog").
+    pub backlog_column: Option<String>,
+}
+
+impl BoardConfig {
+    pub fn file(&self) -> &str {
+        self.file.as_deref().unwrap_or(crate::KANBAN_FILE)
+    }
+
+    pub fn backlog_column(&self) -> &str {
+        self.backlog_column.as_deref().unwrap_or("Backlog")
+    }
+
+    pub fn flagged_field(&self) -> &str {
+        self.flagged_field.as_deref().unwrap_or("customfield_10021")
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ColumnConfig {
+    pub name: String,
+    pub statuses: Vec<String>,
+}
+
+/// What happens to issues whose status no configured column lists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnmappedPolicy {
+    /// Put them in the catch-all column, shown last.
+    #[default]
+    Column,
+    /// Leave them off the board with a warning.
+    Skip,
+}
+
+/// A second board over the synced issues, e.g. a bugs-only board:
+///
+/// ```toml
+/// [[views]]
+/// name = "Bugs"
+/// issue_types = ["Bug"]
+/// ```
+///
+/// Each non-empty list must match (any of its values, case-insensitive).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BoardView {
+    pub name: String,
+    /// File name in the vault; defaults to `JiraKanban - {name}.md`.
+    pub file: Option<String>,
+    pub labels: Vec<String>,
+    pub components: Vec<String>,
+    pub issue_types: Vec<String>,
+}
+
+impl BoardView {
+    pub fn file_name(&self) -> String {
+        self.file.clone().unwrap_or_else(|| format!("JiraKanban - {}.md", sanitize(&self.name)))
+    }
+
+    pub fn matches(&self, issue: &Issue) -> bool {
+        let components = issue.fields.names("components");
+        let issue_type = issue.fields.extra.get("issuetype").and_then(|t| t["name"].as_str());
+
+        let any_of = |wanted: &[String], values: &[&str]| {
+            wanted.is_empty() || wanted.iter().any(|w| values.iter().any(|v| w.eq_ignore_ascii_case(v)))
+        };
+        let labels: Vec<&str> = issue.fields.labels.iter().map(String::as_str).collect();
+        any_of(&self.labels, &labels)
+            && any_of(&self.components, &components)
+            && any_of(&self.issue_types, &issue_type.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// `pinned` cards go to the top of their column, keeping their relative order.
+pub fn create_kanban_markdown(
+    issues: &[Issue],
+    note_links: &HashMap<String, String>,
+    pinned: &HashSet<String>,
+    icons: &IconsConfig,
+    board_config: &BoardConfig,
+    header: &str,
+) -> String {
+    let columns = columns(issues, board_config);
+
+    let mut markdown = String::from("---\nkanban-plugin: board\n---\n");
+    markdown.push_str(header);
+
+    for (column, issues) in &columns {
+        let points = issues.iter().filter_map(|issue| story_points(issue, board_config)).sum();
+        markdown.push_str(&format!("\n## {} ({})\n\n", column, column_stats(issues.len(), points)));
+        let mut issues = issues.clone();
+        issues.sort_by_key(|issue| !pinned.contains(&issue.key));
+        for issue in issues {
+            markdown.push_str(&card(issue, note_links, pinned, icons, board_config));
+            markdown.push('\n');
+        }
+    }
+
+    let col_count = columns.len();
+    let collapse_list: Vec<bool> = vec![false; col_count];
+    let collapse_json = serde_json::to_string(&collapse_list).unwrap_or_else(|_| "[]".to_string());
+
+    markdown.push_str("\n\n%% kanban:settings\n```\n");
+    markdown.push_str(&format!(r#"{{"kanban-plugin":"board","list-collapse":{}}}"#, collapse_json));
+    markdown.push_str("\n```\n%%\n");
+
+    markdown
+}
+
+/// The board line for one issue, linking to its note, with its SLA timer,
+/// type and priority icons and story points.
+pub fn card(
+    issue: &Issue,
+    note_links: &HashMap<String, String>,
+    pinned: &HashSet<String>,
+    icons: &IconsConfig,
+    board_config: &BoardConfig,
+) -> String {
+    let key = issue.key.as_str();
+    let pin = if pinned.contains(key) { "📌 " } else { "" };
+    let sla = format!("{}{}", service_desk::card_marker(issue), icons.prefix(issue));
+    let points = story_points(issue, board_config).map(|p| format!("{}{} pt", POINTS_SEPARATOR, format_points(p))).unwrap_or_default();
+    match note_links.get(key) {
+        Some(target) if target != key => format!("- [ ] {}{}[[{}|{}]]{}", pin, sla, target, key, points),
+        _ => format!("- [ ] {}{}[[{}]]{}", pin, sla, key, points),
+    }
+}
+
+/// Column heading counts: `3`, or `3 · 8 pt` once issues are estimated.
+fn column_stats(count: usize, points: f64) -> String {
+    if points > 0.0 {
+        format!("{}{}{} pt", count, POINTS_SEPARATOR, format_points(points))
+    } else {
+        count.to_string()
+    }
+}
+
+/// The story points of a card line, as written by [`card`].
+fn card_points(line: &str) -> Option<f64> {
+    line.strip_suffix(" pt")?.rsplit_once(POINTS_SEPARATOR)?.1.parse().ok()
+}
+
+/// The issue's story points from the first `points_fields` entry with a value.
+pub fn story_points(issue: &Issue, board_config: &BoardConfig) -> Option<f64> {
+    board_config.points_fields.iter().find_map(|field| issue.fields.extra.get(field)?.as_f64())
+}
+
+/// `3`, `2.5`: whole numbers without a decimal point.
+pub fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{}", points as i64)
+    } else {
+        format!("{:.1}", points)
+    }
+}
+
+/// Moves (or adds) the card for `key` to `column` on an existing board, for
+/// syncing a single issue. Column counts are recomputed; a missing column is
+/// added at the end. The header stats are left until the next full sync.
+pub fn move_card(board: &str, key: &str, column: &str, card: &str) -> String {
+    let links_key = |line: &str| {
+        line.trim_start().starts_with("- [")
+            && (line.contains(&format!("[[{}]]", key)) || line.contains(&format!("|{}]]", key)))
+    };
+    fn heading_name(line: &str) -> Option<&str> {
+        let name = line.strip_prefix("## ")?;
+        Some(name.rsplit_once(" (").filter(|(_, n)| n.ends_with(')')).map_or(name, |(name, _)| name))
+    }
+    let mut lines: Vec<String> = board.lines().filter(|l| !links_key(l)).map(str::to_string).collect();
+
+    let settings = lines.iter().position(|l| l.starts_with("%% kanban:settings")).unwrap_or(lines.len());
+    match lines[..settings].iter().position(|l| heading_name(l) == Some(column)) {
+        Some(heading) => {
+            let at = if lines.get(heading + 1).is_some_and(|l| l.is_empty()) { heading + 2 } else { heading + 1 };
+            lines.insert(at, card.to_string());
+        }
+        None => {
+            let end = lines[..settings].iter().rposition(|l| !l.is_empty()).map_or(0, |i| i + 1);
+            lines.splice(end..end, ["".to_string(), format!("## {}", column), "".to_string(), card.to_string()]);
+        }
+    }
+
+    for i in 0..lines.len() {
+        let Some(name) = heading_name(&lines[i]).map(str::to_string) else { continue };
+        let cards: Vec<&String> = lines[i + 1..]
+            .iter()
+            .take_while(|l| !l.starts_with("## ") && !l.starts_with("%%"))
+            .filter(|l| l.trim_start().starts_with("- ["))
+            .collect();
+        let points = cards.iter().filter_map(|l| card_points(l)).sum();
+        lines[i] = format!("## {} ({})", name, column_stats(cards.len(), points));
+    }
+    let mut updated = lines.join("\n");
+    if board.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// One-line summary shown above the lanes: total (and story points), overdue,
+/// flagged and sync time.
+pub fn stats_header(issues: &[Issue], labels: &Labels, board_config: &BoardConfig, today: NaiveDate, now: &str) -> String {
+    let overdue = issues
+        .iter()
+        .filter(|i| i.fields.status.status_category.as_ref().is_none_or(|c| c.key != "done"))
+        .filter_map(|i| i.fields.duedate.as_deref())
+        .filter(|due| NaiveDate::parse_from_str(due, "%Y-%m-%d").is_ok_and(|due| due < today))
+        .count();
+    let flagged = issues.iter().filter(|i| is_flagged(i, board_config)).count();
+    let points: f64 = issues.iter().filter_map(|i| story_points(i, board_config)).sum();
+    let points = if points > 0.0 { format!(" · {} pt", format_points(points)) } else { String::new() };
+    format!(
+        "\n> 📊 {} {}{} · {} {} · {} {} · {}: {}\n",
+        issues.len(), labels.issues, points, overdue, labels.overdue, flagged, labels.flagged, labels.last_sync, now
+    )
+}
+
+/// Whether Jira's "Flagged" marker is set on the issue.
+pub fn is_flagged(issue: &Issue, board_config: &BoardConfig) -> bool {
+    match issue.fields.extra.get(board_config.flagged_field()) {
+        Some(serde_json::Value::Array(values)) => !values.is_empty(),
+        Some(value) => !value.is_null(),
+        None => false,
+    }
+}
+
+/// The board's columns in display order: the configured ones, or one per
+/// status ordered by status category.
+pub fn columns<'a>(issues: &'a [Issue], board_config: &BoardConfig) -> Vec<(String, Vec<&'a Issue>)> {
+    let mut board: HashMap<String, Vec<&Issue>> = HashMap::new();
+    let mut status_order: HashMap<String, i32> = HashMap::new();
+
+    for issue in issues.iter().filter(|issue| !sprints::in_backlog(issue)) {
+        let status_name = &issue.fields.status.name;
+        board.entry(status_name.clone()).or_default().push(issue);
+
+        if !status_order.contains_key(status_name) {
+            let order = if let Some(cat) = &issue.fields.status.status_category {
+                match cat.key.as_str() {
+                    "new" => 0,
+                    "indeterminate" => 1,
+                    "done" => 2,
+                    _ => 3,
+                }
+            } else {
+                3
+            };
+            status_order.insert(status_name.clone(), order);
+        }
+    }
+
+    let mut sorted_statuses: Vec<String> = board.keys().cloned().collect();
+    sorted_statuses.sort_by(|a, b| {
+        let order_a = status_order.get(a).unwrap_or(&3);
+        let order_b = status_order.get(b).unwrap_or(&3);
+        if order_a == order_b {
+            a.cmp(b)
+        } else {
+            order_a.cmp(order_b)
+        }
+    });
+
+    let mut columns = if board_config.columns.is_empty() {
+        sorted_statuses.iter().map(|s| (s.clone(), board.remove(s).unwrap_or_default())).collect()
+    } else {
+        map_columns(issues, board_config)
+    };
+    for (_, cards) in &mut columns {
+        sort_by_rank(cards, board_config);
+    }
+    let mut backlog: Vec<&Issue> = issues.iter().filter(|issue| sprints::in_backlog(issue)).collect();
+    sort_by_rank(&mut backlog, board_config);
+    if !backlog.is_empty() {
+        let name = board_config.backlog_column();
+        // Aynı adlı bir sütun zaten varsa backlog işleri onun başına girer.
+        match columns.iter_mut().find(|(column, _)| column.eq_ignore_ascii_case(name)) {
+            Some((_, cards)) => {
+                cards.splice(0..0, backlog);
+            }
+            None => columns.insert(0, (name.to_string(), backlog)),
+        }
+    }
+    columns
+}
+
+/// Orders cards by `rank_field` like the board in Jira; unranked cards go
+/// last in the order they came.
+fn sort_by_rank(cards: &mut [&Issue], board_config: &BoardConfig) {
+    let Some(field) = &board_config.rank_field else { return };
+    cards.sort_by_cached_key(|issue| {
+        let rank = issue.fields.extra.get(field).and_then(Value::as_str).map(str::to_string);
+        (rank.is_none(), rank)
+    });
+}
+
+/// The columns of a Jira board, in board order, with the names of the
+/// statuses mapped to each.
+pub async fn board_columns(client: &JiraClient, board_id: u64) -> Result<Vec<ColumnConfig>, Box<dyn Error>> {
+    let configuration: Value = client.get_json(&format!("/rest/agile/1.0/board/{}/configuration", board_id)).await?;
+    let statuses: Vec<Value> = client.get_json("/rest/api/3/status").await?;
+    let names: HashMap<String, String> = statuses
+        .iter()
+        .filter_map(|s| Some((s["id"].as_str()?.to_string(), s["name"].as_str()?.to_string())))
+        .collect();
+    Ok(columns_from_configuration(&configuration, &names))
+}
+
+fn columns_from_configuration(configuration: &Value, status_names: &HashMap<String, String>) -> Vec<ColumnConfig> {
+    let Some(columns) = configuration["columnConfig"]["columns"].as_array() else {
+        return Vec::new();
+    };
+    columns
+        .iter()
+        .filter_map(|column| {
+            let statuses = column["statuses"].as_array().map(Vec::as_slice).unwrap_or_default();
+            Some(ColumnConfig {
+                name: column["name"].as_str()?.to_string(),
+                statuses: statuses.iter().filter_map(|s| status_names.get(s["id"].as_str()?).cloned()).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Groups issues into the configured columns, in config order.
+fn map_columns<'a>(issues: &'a [Issue], board_config: &BoardConfig) -> Vec<(String, Vec<&'a Issue>)> {
+    let mut columns: Vec<(String, Vec<&Issue>)> =
+        board_config.columns.iter().map(|c| (c.name.clone(), Vec::new())).collect();
+    let mut unmapped: Vec<&Issue> = Vec::new();
+
+    for issue in issues.iter().filter(|issue| !sprints::in_backlog(issue)) {
+        let status = &issue.fields.status.name;
+        let column = board_config
+            .columns
+            .iter()
+            .position(|c| c.statuses.iter().any(|s| s.eq_ignore_ascii_case(status)));
+        match (column, board_config.unmapped) {
+            (Some(i), _) => columns[i].1.push(issue),
+            (None, UnmappedPolicy::Column) => unmapped.push(issue),
+            (None, UnmappedPolicy::Skip) => {
+                warn!("⚠️  {}: '{}' durumu hiçbir sütuna eşlenmemiş, panoya eklenmedi", issue.key, status);
+            }
+        }
+    }
+
+    if !unmapped.is_empty() {
+        let name = board_config.unmapped_column.clone().unwrap_or_else(|| "Other".to_string());
+        columns.push((name, unmapped));
+    }
+    columns
+}
+
+/// Regenerating the board must not reset what the Obsidian Kanban plugin stored
+/// in it: the frontmatter and the `%% kanban:settings %%` block of `existing`
+/// replace the freshly generated ones; only the lanes come from `generated`.
+pub fn preserve_plugin_blocks(generated: &str, existing: &str) -> String {
+    let (gen_frontmatter, body, gen_settings) = split_board(generated);
+    let (old_frontmatter, _, old_settings) = split_board(existing);
+    format!(
+        "{}{}{}",
+        old_frontmatter.or(gen_frontmatter).unwrap_or_default(),
+        body,
+        old_settings.or(gen_settings).unwrap_or_default()
+    )
+}
+
+/// Splits a board into (frontmatter incl. `---` lines, lanes, settings block to the end).
+fn split_board(content: &str) -> (Option<&str>, &str, Option<&str>) {
+    let (frontmatter, rest) = match content.strip_prefix("---\n").and_then(|r| r.find("\n---\n")) {
+        Some(end) => content.split_at(end + 4 + 5),
+        None => ("", content),
+    };
+    let (body, settings) = match rest.find(SETTINGS_MARKER) {
+        Some(start) => (&rest[..start], Some(&rest[start..])),
+        None => (rest, None),
+    };
+    let frontmatter = if frontmatter.is_empty() { None } else { Some(frontmatter) };
+    (frontmatter, body, settings)
+}
+
+#[cfg(test)]
+#[test]
+fn test_kanban_logic() {
+    use crate::{Fields, Status, StatusCategory};
+
+    let issue1 = Issue {
+        key: "TASK-1".to_string(),
+        fields: Fields {
+            summary: "Task 1".to_string(),
+            description: None,
+            status: Status {
+                name: "To Do".to_string(),
+                status_category: Some(StatusCategory {
+                    key: "new".to_string(),
+                    name: "To Do".to_string(),
+                }),
+            },
+            priority: None,
+            labels: Vec::new(),
+            duedate: None,
+            assignee: None,
+            reporter: None,
+            extra: HashMap::new(),
+        },
+        changelog: None,
+        rendered_fields: None,
+        development: None,
+        remote_links: None,
+        service_desk: None,
+        sprint_scope: None,
+        restricted: false,
+    };
+
+    let issue2 = Issue {
+        key: "TASK-2".to_string(),
+        fields: Fields {
+            summary: "Task 2".to_string(),
+            description: None,
+            status: Status {
+                name: "Done".to_string(),
+                status_category: Some(StatusCategory {
+                    key: "done".to_string(),
+                    name: "Done".to_string(),
+                }),
+            },
+            priority: None,
+            labels: Vec::new(),
+            duedate: None,
+            assignee: None,
+            reporter: None,
+            extra: HashMap::new(),
+        },
+        changelog: None,
+        rendered_fields: None,
+        development: None,
+        remote_links: None,
+        service_desk: None,
+        sprint_scope: None,
+        restricted: false,
+    };
+
+    let issues = vec![issue1, issue2];
+    let markdown = create_kanban_markdown(&issues, &HashMap::new(), &HashSet::new(), &IconsConfig::default(), &BoardConfig::default(), "");
+
+    assert!(markdown.contains("## To Do"));
+    assert!(markdown.contains("## Done"));
+    assert!(markdown.contains("## To Do (1)"));
+    assert!(markdown.contains("- [ ] [[TASK-1]]"));
+    assert!(markdown.contains("- [ ] [[TASK-2]]"));
+
+    // Check order: To Do (new) comes before Done (done)
+    let todo_pos = markdown.find("## To Do").unwrap();
+    let done_pos = markdown.find("## Done").unwrap();
+    assert!(todo_pos < done_pos);
+
+    // Check settings
+    assert!(markdown.contains(r#"{"kanban-plugin":"board","list-collapse":[false,false]}"#));
+}
+
+#[cfg(test)]
+#[test]
+fn test_move_card() {
+    let board = "---\nkanban-plugin: board\n---\n\n## To Do (2)\n\n- [ ] [[A/A-1|A-1]]\n- [ ] [[A-2]]\n\n## Doing (0)\n\n\n\n%% kanban:settings\n```\n{}\n```\n%%\n";
+    let moved = move_card(board, "A-1", "Doing", "- [ ] [[A/A-1|A-1]] · 3 pt");
+    assert!(moved.contains("## To Do (1)\n\n- [ ] [[A-2]]\n"));
+    assert!(moved.contains("## Doing (1 · 3 pt)\n\n- [ ] [[A/A-1|A-1]] · 3 pt\n"));
+
+    let added = move_card(board, "A-3", "Done", "- [ ] [[A-3]]");
+    assert!(added.find("## Done (1)\n\n- [ ] [[A-3]]\n").unwrap() < added.find("%% kanban:settings").unwrap());
+    assert!(added.contains("## To Do (2)"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_preserve_plugin_blocks() {
+    let existing = "---\nkanban-plugin: board\ncustom: yes\n---\n\n## Old\n\n- [ ] [[OLD-1]]\n\n\n%% kanban:settings\n```\n{\"kanban-plugin\":\"board\",\"lane-width\":400}\n```\n%%\n";
+    let generated = create_kanban_markdown(&Vec::new(), &HashMap::new(), &HashSet::new(), &IconsConfig::default(), &BoardConfig::default(), "");
+    let generated = generated.replace("\n\n%%", "\n## New\n\n- [ ] [[NEW-1]]\n\n\n%%");
+
+    let merged = preserve_plugin_blocks(&generated, existing);
+    assert!(merged.starts_with("---\nkanban-plugin: board\ncustom: yes\n---\n"));
+    assert!(merged.contains("## New"));
+    assert!(!merged.contains("## Old"));
+    assert!(merged.contains(r#""lane-width":400"#));
+    assert!(!merged.contains("list-collapse"));
+
+    // Without an existing board the generated blocks are kept.
+    assert_eq!(preserve_plugin_blocks(&generated, ""), generated);
+}
+
+#[cfg(test)]
+#[test]
+fn test_pinned_cards_first() {
+    let issue = |key: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "status": { "name": "To Do" } }
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("A-1"), issue("A-2"), issue("A-3")];
+    let pinned: HashSet<String> = ["A-3".to_string()].into();
+
+    let markdown = create_kanban_markdown(&issues, &HashMap::new(), &pinned, &IconsConfig::default(), &BoardConfig::default(), "");
+    let pin = markdown.find("- [ ] 📌 [[A-3]]").unwrap();
+    assert!(pin < markdown.find("[[A-1]]").unwrap());
+    assert!(markdown.find("[[A-1]]").unwrap() < markdown.find("[[A-2]]").unwrap());
+}
+
+#[cfg(test)]
+#[test]
+fn test_column_mapping_with_catch_all() {
+    let issue = |key: &str, status: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "status": { "name": status } }
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("A-1", "In Review"), issue("A-2", "Blocked"), issue("A-3", "to do")];
+    let mut board_config: BoardConfig = toml::from_str(r#"
+        columns = [
+            { name = "Backlog", statuses = ["To Do"] },
+            { name = "Doing", statuses = ["In Progress", "In Review"] },
+        ]
+    "#).unwrap();
+
+    let markdown = create_kanban_markdown(&issues, &HashMap::new(), &HashSet::new(), &IconsConfig::default(), &board_config, "");
+    let backlog = markdown.find("## Backlog").unwrap();
+    let doing = markdown.find("## Doing").unwrap();
+    let other = markdown.find("## Other").unwrap();
+    assert!(backlog < markdown.find("[[A-3]]").unwrap() && markdown.find("[[A-3]]").unwrap() < doing);
+    assert!(doing < markdown.find("[[A-1]]").unwrap() && markdown.find("[[A-1]]").unwrap() < other);
+    assert!(other < markdown.find("[[A-2]]").unwrap());
+
+    board_config.unmapped = UnmappedPolicy::Skip;
+    let markdown = create_kanban_markdown(&issues, &HashMap::new(), &HashSet::new(), &IconsConfig::default(), &board_config, "");
+    assert!(!markdown.contains("[[A-2]]"));
+    assert!(!markdown.contains("## Other"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_backlog_and_rank_order() {
+    let issue = |key: &str, status: &str, scope: &str| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": { "summary": key, "status": { "name": status } },
+            "sprintScope": scope
+        }))
+        .unwrap()
+    };
+    let issues = vec![issue("A-1", "In Progress", "active"), issue("A-2", "To Do", "backlog"), issue("A-3", "To Do", "active")];
+    let names = |board_config: &BoardConfig| -> Vec<(String, Vec<String>)> {
+        columns(&issues, board_config).into_iter().map(|(name, cards)| (name, cards.iter().map(|i| i.key.clone()).collect())).collect()
+    };
+    assert_eq!(
+        names(&BoardConfig::default()),
+        [("Backlog".to_string(), vec!["A-2".to_string()]), ("In Progress".into(), vec!["A-1".into()]), ("To Do".into(), vec!["A-3".into()])]
+    );
+
+    let mut board_config: BoardConfig = toml::from_str(r#"columns = [{ name = "backlog", statuses = ["To Do"] }, { name = "Doing", statuses = ["In Progress"] }]"#).unwrap();
+    assert_eq!(names(&board_config), [("backlog".to_string(), vec!["A-2".to_string(), "A-3".into()]), ("Doing".into(), vec!["A-1".into()])]);
+
+    let mut issues = vec![issue("A-1", "To Do", "active"), issue("A-2", "To Do", "active"), issue("A-3", "To Do", "active")];
+    issues[0].fields.extra.insert("customfield_10019".into(), serde_json::json!("0|i0000f:"));
+    issues[2].fields.extra.insert("customfield_10019".into(), serde_json::json!("0|i00007:"));
+    board_config.rank_field = Some("customfield_10019".into());
+    let ranked: Vec<&str> = columns(&issues, &board_config)[0].1.iter().map(|i| i.key.as_str()).collect();
+    assert_eq!(ranked, ["A-3", "A-1", "A-2"]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_board_columns_from_configuration() {
+    let configuration = serde_json::json!({
+        "columnConfig": { "columns": [
+            { "name": "Backlog", "statuses": [{ "id": "10000" }] },
+            { "name": "Selected", "statuses": [] },
+            { "name": "Doing", "statuses": [{ "id": "3" }, { "id": "10001" }] },
+            { "name": "Done", "statuses": [{ "id": "10002" }] }
+        ] }
+    });
+    let names: HashMap<String, String> =
+        [("10000", "To Do"), ("3", "In Progress"), ("10001", "In Review"), ("10002", "Done")].map(|(id, n)| (id.to_string(), n.to_string())).into();
+
+    let columns = columns_from_configuration(&configuration, &names);
+    let columns: Vec<(&str, Vec<&str>)> = columns.iter().map(|c| (c.name.as_str(), c.statuses.iter().map(String::as_str).collect())).collect();
+    assert_eq!(
+        columns,
+        [("Backlog", vec!["To Do"]), ("Selected", vec![]), ("Doing", vec!["In Progress", "In Review"]), ("Done", vec!["Done"])]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_board_view_matches() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "WEB-3",
+        "fields": {
+            "summary": "Broken button",
+            "status": { "name": "To Do" },
+            "labels": ["frontend"],
+            "issuetype": { "name": "Bug" },
+            "components": [{ "name": "Web" }, { "name": "Design" }]
+        }
+    }))
+    .unwrap();
+
+    let bugs: BoardView = toml::from_str(r#"name = "Bugs"
+issue_types = ["bug"]"#).unwrap();
+    assert!(bugs.matches(&issue));
+    assert_eq!(bugs.file_name(), "JiraKanban - Bugs.md");
+
+    let view = |components: &[&str], labels: &[&str]| BoardView {
+        components: components.iter().map(|s| s.to_string()).collect(),
+        labels: labels.iter().map(|s| s.to_string()).collect(),
+        ..BoardView::default()
+    };
+    assert!(view(&["Design"], &["frontend"]).matches(&issue));
+    assert!(!view(&["Design"], &["backend"]).matches(&issue));
+    assert!(!view(&["API"], &[]).matches(&issue));
+}
+
+#[cfg(test)]
+#[test]
+fn test_stats_header() {
+    let issue = |key: &str, category: &str, due: &str, flagged: serde_json::Value| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": {
+                "summary": key,
+                "status": { "name": "S", "statusCategory": { "key": category, "name": "S" } },
+                "duedate": due,
+                "customfield_10021": flagged
+            }
+        }))
+        .unwrap()
+    };
+    let issues = vec![
+        issue("A-1", "indeterminate", "2024-01-01", serde_json::json!([{ "value": "Impediment" }])),
+        issue("A-2", "done", "2024-01-01", serde_json::Value::Null),
+        issue("A-3", "new", "2030-01-01", serde_json::json!([])),
+    ];
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let header = stats_header(&issues, &Labels::for_locale("en"), &BoardConfig::default(), today, "2025-06-01 09:00");
+    assert_eq!(header, "\n> 📊 3 issues · 1 overdue · 1 flagged · Last Sync: 2025-06-01 09:00\n");
+
+    let mut estimated = issues.clone();
+    estimated[0].fields.extra.insert("customfield_10016".into(), serde_json::json!(5.0));
+    estimated[2].fields.extra.insert("customfield_10026".into(), serde_json::json!(2.5));
+    let board_config = BoardConfig { points_fields: vec!["customfield_10026".into(), "customfield_10016".into()], ..Default::default() };
+    let header = stats_header(&estimated, &Labels::for_locale("en"), &board_config, today, "2025-06-01 09:00");
+    assert!(header.starts_with("\n> 📊 3 issues · 7.5 pt · 1 overdue"));
+    let markdown = create_kanban_markdown(&estimated, &HashMap::new(), &HashSet::new(), &IconsConfig::default(), &board_config, "");
+    assert!(markdown.contains("## S (3 · 7.5 pt)\n\n- [ ] [[A-1]] · 5 pt\n- [ ] [[A-2]]\n- [ ] [[A-3]] · 2.5 pt\n"));
+}
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::Issue;
+
+/// Values a folder/filename template can refer to (`{key}`, `{project}`, ...).
+/// Stored in the state file too, so `migrate` can re-render paths without Jira.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NoteVars {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub priority: String,
+    /// Empty in states written before project names were kept.
+    #[serde(default)]
+    pub project_name: String,
+}
+
+impl NoteVars {
+    pub fn from_issue(issue: &Issue) -> NoteVars {
+        NoteVars {
+            key: issue.key.clone(),
+            summary: issue.fields.summary.clone(),
+            status: issue.fields.status.name.clone(),
+            priority: issue.fields.priority.as_ref().map(|p| p.name.clone()).unwrap_or_default(),
+            project_name: issue.fields.extra.get("project").and_then(|p| p["name"].as_str()).unwrap_or_default().to_string(),
+        }
+    }
+
+    /// The project key, from the issue key.
+    pub fn project(&self) -> &str {
+        self.key.split_once('-').map(|(p, _)| p).unwrap_or(&self.key)
+    }
+
+    /// The project's name, or its key when the name isn't known.
+    pub fn project_name(&self) -> &str {
+        if self.project_name.is_empty() { self.project() } else { &self.project_name }
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{key}", &sanitize(&self.key))
+            .replace("{project_name}", &sanitize(self.project_name()))
+            .replace("{project}", &sanitize(self.project()))
+            .replace("{summary}", &sanitize(&self.summary))
+            .replace("{status}", &sanitize(&self.status))
+            .replace("{priority}", &sanitize(&self.priority))
+    }
+}
+
+/// Vault-relative path of a ticket note, e.g. `PROJ/PROJ-1 Login bug.md`.
+pub fn note_path(config: &Config, vars: &NoteVars) -> PathBuf {
+    let mut file_name = vars.render(&config.filename_template).trim().to_string();
+    if file_name.is_empty() {
+        file_name = sanitize(&vars.key);
+    }
+
+    let mut path = folder_path(&config.folder_template, vars);
+    path.push(format!("{}.md", file_name));
+    path
+}
+
+/// Vault-relative folder a folder template names for `vars`; empty and
+/// `.`/`..` segments are dropped.
+pub fn folder_path(template: &str, vars: &NoteVars) -> PathBuf {
+    let mut path = PathBuf::new();
+    for part in vars.render(template).split('/') {
+        let part = part.trim();
+        if !part.is_empty() && part != "." && part != ".." {
+            path.push(part);
+        }
+    }
+    path
+}
+
+/// Wikilink target for a note: its vault-relative path without `.md`, using `/`.
+pub fn link_target(rel_path: &Path) -> String {
+    let s = rel_path.to_string_lossy().replace('\\', "/");
+    s.strip_suffix(".md").unwrap_or(&s).to_string()
+}
+
+/// Characters that break file names or Obsidian wikilinks.
+pub fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => '-',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+#[test]
+fn test_note_path_templates() {
+    let mut config = Config {
+        filename_template: "{key}".to_string(),
+        ..Default::default()
+    };
+    let vars = NoteVars {
+        key: "PROJ-12".to_string(),
+        summary: "Fix: login/logout [urgent]".to_string(),
+        status: "In Progress".to_string(),
+        priority: "High".to_string(),
+        project_name: String::new(),
+    };
+
+    assert_eq!(note_path(&config, &vars), PathBuf::from("PROJ-12.md"));
+
+    config.folder_template = "Jira/{project}/../{status}".to_string();
+    config.filename_template = "{key} {summary}".to_string();
+    let path = note_path(&config, &vars);
+    assert_eq!(path, PathBuf::from("Jira/PROJ/In Progress/PROJ-12 Fix- login-logout -urgent-.md"));
+    assert_eq!(link_target(&path), "Jira/PROJ/In Progress/PROJ-12 Fix- login-logout -urgent-");
+
+    config.folder_template = "Jira/{project_name}".to_string();
+    config.filename_template = "{key}".to_string();
+    assert_eq!(note_path(&config, &vars), PathBuf::from("Jira/PROJ/PROJ-12.md"));
+    let vars = NoteVars { project_name: "Mobile: App".to_string(), ..vars };
+    assert_eq!(note_path(&config, &vars), PathBuf::from("Jira/Mobile- App/PROJ-12.md"));
+}
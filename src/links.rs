@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use serde_json::Value;
+use crate::Issue;
+
+/// The `## 🔗` section of a ticket note: one line per issue link, phrased from
+/// this issue's side ("blocks", "is blocked by"). Issues with a note in the
+/// vault become wikilinks so the graph view shows the dependencies; the rest
+/// link to Jira.
+pub fn section(issue: &Issue, title: &str, targets: &HashMap<String, String>, base_url: &str) -> String {
+    let Some(Value::Array(links)) = issue.fields.extra.get("issuelinks") else {
+        return String::new();
+    };
+    let lines: Vec<String> = links.iter().filter_map(|link| line(link, targets, base_url)).collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("\n## 🔗 {}\n{}", title, lines.concat())
+}
+
+fn line(link: &Value, targets: &HashMap<String, String>, base_url: &str) -> Option<String> {
+    let (relation, other) = match (link.get("outwardIssue"), link.get("inwardIssue")) {
+        (Some(other), _) => (link["type"]["outward"].as_str()?, other),
+        (None, Some(other)) => (link["type"]["inward"].as_str()?, other),
+        (None, None) => return None,
+    };
+    let key = other["key"].as_str()?;
+    Some(format!(
+        "- {} {} {} (`{}`)\n",
+        relation,
+        note_link(key, targets, base_url),
+        other["fields"]["summary"].as_str().unwrap_or_default(),
+        other["fields"]["status"]["name"].as_str().unwrap_or_default()
+    ))
+}
+
+/// `[[target|KEY]]` for issues with a note, a Jira link otherwise.
+pub fn note_link(key: &str, targets: &HashMap<String, String>, base_url: &str) -> String {
+    match targets.get(key) {
+        Some(target) if target == key => format!("[[{}]]", key),
+        Some(target) => format!("[[{}|{}]]", target, key),
+        None => format!("[{}]({}/browse/{})", key, base_url, key),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_issue_link_section() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "issuelinks": [
+                { "type": { "inward": "is blocked by", "outward": "blocks" },
+                  "outwardIssue": { "key": "A-2", "fields": { "summary": "Login", "status": { "name": "Done" } } } },
+                { "type": { "inward": "is blocked by", "outward": "blocks" },
+                  "inwardIssue": { "key": "B-7", "fields": { "summary": "API", "status": { "name": "In Progress" } } } }
+            ]
+        }
+    }))
+    .unwrap();
+    let targets = HashMap::from([("A-2".to_string(), "Jira Tickets/A-2".to_string())]);
+    assert_eq!(
+        section(&issue, "Linked Issues", &targets, "https://x.atlassian.net"),
+        "\n## 🔗 Linked Issues\n\
+         - blocks [[Jira Tickets/A-2|A-2]] Login (`Done`)\n\
+         - is blocked by [B-7](https://x.atlassian.net/browse/B-7) API (`In Progress`)\n"
+    );
+}
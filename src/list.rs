@@ -0,0 +1,73 @@
+use std::error::Error;
+use crate::cli::SortKey;
+use crate::config::Config;
+use crate::state::{NoteState, State};
+
+/// `list`: prints the synced issues matching the filters, one per line as
+/// `KEY<TAB>status<TAB>priority<TAB>path<TAB>summary`, for scripting.
+pub fn run(
+    config: &Config,
+    status: Option<&str>,
+    label: Option<&str>,
+    priority: Option<&str>,
+    sort: SortKey,
+) -> Result<(), Box<dyn Error>> {
+    let state = State::load(&config.vault_path)?;
+    for (key, note) in select(&state, status, label, priority, sort) {
+        println!("{}\t{}\t{}\t{}\t{}", key, note.vars.status, note.vars.priority, note.path, note.vars.summary);
+    }
+    Ok(())
+}
+
+fn select<'a>(
+    state: &'a State,
+    status: Option<&str>,
+    label: Option<&str>,
+    priority: Option<&str>,
+    sort: SortKey,
+) -> Vec<(&'a String, &'a NoteState)> {
+    let same = |wanted: Option<&str>, value: &str| wanted.is_none_or(|w| w.eq_ignore_ascii_case(value));
+    let mut notes: Vec<(&String, &NoteState)> = state
+        .notes
+        .iter()
+        .filter(|(_, n)| same(status, &n.vars.status) && same(priority, &n.vars.priority))
+        .filter(|(_, n)| label.is_none_or(|l| n.labels.iter().any(|nl| nl.eq_ignore_ascii_case(l))))
+        .collect();
+
+    // PROJ-2 önce, PROJ-10 sonra gelsin
+    let key_order = |key: &str| {
+        let (project, number) = key.rsplit_once('-').unwrap_or((key, ""));
+        (project.to_string(), number.parse::<u64>().unwrap_or(0))
+    };
+    notes.sort_by_key(|(key, _)| key_order(key));
+    match sort {
+        SortKey::Key => {}
+        SortKey::Status => notes.sort_by(|a, b| a.1.vars.status.cmp(&b.1.vars.status)),
+        SortKey::Priority => notes.sort_by(|a, b| a.1.vars.priority.cmp(&b.1.vars.priority)),
+        SortKey::Updated => notes.sort_by(|a, b| b.1.updated.cmp(&a.1.updated)),
+    }
+    notes
+}
+
+#[cfg(test)]
+#[test]
+fn test_list_select() {
+    let note = |status: &str, labels: &[&str], updated: &str| -> NoteState {
+        serde_json::from_value(serde_json::json!({
+            "path": "x.md",
+            "vars": { "key": "", "summary": "", "status": status, "priority": "High" },
+            "labels": labels,
+            "updated": updated
+        }))
+        .unwrap()
+    };
+    let mut state = State::default();
+    state.notes.insert("A-10".into(), note("In Progress", &["infra"], "2025-05-01T00:00:00.000+0000"));
+    state.notes.insert("A-2".into(), note("In Progress", &["infra", "api"], "2025-05-03T00:00:00.000+0000"));
+    state.notes.insert("A-3".into(), note("To Do", &["infra"], "2025-05-02T00:00:00.000+0000"));
+
+    let keys = |notes: Vec<(&String, &NoteState)>| notes.into_iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+    assert_eq!(keys(select(&state, Some("in progress"), Some("infra"), None, SortKey::Key)), ["A-2", "A-10"]);
+    assert_eq!(keys(select(&state, None, Some("INFRA"), None, SortKey::Updated)), ["A-2", "A-3", "A-10"]);
+    assert_eq!(keys(select(&state, None, Some("api"), Some("Low"), SortKey::Key)), Vec::<String>::new());
+}
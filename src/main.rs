@@ -1,184 +1,1100 @@
-use std::env;
+mod adf;
+mod anonymize;
+mod attachments;
+mod auth;
+mod bulk;
+mod capabilities;
+mod changes;
+mod clean;
+mod cli;
+mod comments;
+mod config;
+mod credentials;
+mod custom_fields;
+mod dashboards;
+mod development;
+mod doctor;
+mod editmeta;
+mod epics;
+mod export;
+mod filter;
+mod history;
+mod html;
+mod flow;
+mod frontmatter;
+mod hierarchy;
+mod i18n;
+mod icons;
+mod issue_cache;
+mod jira;
+mod jql;
+mod kanban;
+mod layout;
+mod links;
+mod list;
+mod metrics;
+mod oauth;
+mod migrate;
+mod people;
+mod references;
+mod restricted;
+mod person_notes;
+mod project_notes;
+mod permissions;
+mod plugins;
+mod prune;
+mod push;
+mod queue;
+mod report;
+mod script;
+mod service_desk;
+mod sprints;
+mod state;
+mod status;
+mod summary;
+mod transitions;
+mod update;
+mod vault;
+mod velocity;
+mod wasm;
+mod watch;
+mod watchers;
+mod worklogs;
+
 use std::fs;
 use std::path::Path;
 use std::error::Error;
-use std::io::Write;
-use std::collections::HashMap;
-use reqwest::Client;
+use std::collections::{HashMap, HashSet};
+use reqwest::{Method, StatusCode};
 use serde::Deserialize;
 use regex::Regex;
-use chrono::Local;
+use chrono::{DateTime, FixedOffset, Local};
+use clap::{CommandFactory, Parser};
 use dotenv::dotenv;
+use futures::stream::{self, StreamExt};
+use anonymize::Anonymizer;
+use cli::{Cli, Command, QueueAction, ReportKind};
+use config::{Config, Query};
+use filter::IssueFilter;
+use i18n::Labels;
+use issue_cache::IssueCache;
+use jira::{JiraClient, PageCursor, SearchPage, BULK_FETCH_SIZE};
+use people::Directory;
+use layout::{link_target, NoteVars};
+use script::{Script, ScriptResult};
+use state::{NoteState, State};
+use summary::Summary;
+use vault::VaultWriter;
+use wasm::WasmPlugins;
+use tracing::{debug, error, info, warn};
 
-#[derive(Debug, Deserialize)]
-struct JiraSearchResponse {
-    issues: Vec<Issue>,
-}
-
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Issue {
     key: String,
     fields: Fields,
+    /// Change history, present when the search asked for `expand=changelog`.
+    #[serde(default)]
+    changelog: Option<serde_json::Value>,
+    /// Fields as HTML, present when the search asked for `expand=renderedFields`.
+    #[serde(default, rename = "renderedFields")]
+    rendered_fields: Option<serde_json::Value>,
+    /// Branches, commits and pull requests from the dev-status API.
+    #[serde(default)]
+    development: Option<serde_json::Value>,
+    /// Confluence pages and web links from `/issue/{key}/remotelink`.
+    #[serde(default, rename = "remoteLinks")]
+    remote_links: Option<serde_json::Value>,
+    /// Request type and SLAs from the Service Management API.
+    #[serde(default, rename = "serviceDesk")]
+    service_desk: Option<serde_json::Value>,
+    /// `active` or `backlog` on the configured Agile board.
+    #[serde(default, rename = "sprintScope")]
+    sprint_scope: Option<String>,
+    /// Set when the token could only partly read the issue.
+    #[serde(default)]
+    restricted: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Fields {
     summary: String,
     description: Option<serde_json::Value>,
     status: Status,
     priority: Option<Priority>,
+    #[serde(default)]
+    labels: Vec<String>,
+    /// `YYYY-MM-DD`
+    #[serde(default)]
+    duedate: Option<String>,
+    #[serde(default)]
+    assignee: Option<User>,
+    #[serde(default)]
+    reporter: Option<User>,
+    /// Everything else we asked for, e.g. custom fields for the external renderers.
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl Fields {
+    /// When the issue got its resolution; `None` while unresolved.
+    fn resolved(&self) -> Option<DateTime<FixedOffset>> {
+        self.extra.get("resolutiondate").and_then(|v| v.as_str()).and_then(metrics::parse_time)
+    }
+
+    /// Names in a list field like `components` or `fixVersions`.
+    fn names(&self, field: &str) -> Vec<&str> {
+        self.extra
+            .get(field)
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|item| item["name"].as_str()).collect())
+            .unwrap_or_default()
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Status {
     name: String,
     #[serde(rename = "statusCategory")]
     status_category: Option<StatusCategory>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct StatusCategory {
     key: String,
+    #[allow(dead_code)]
     name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct Priority {
     name: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct User {
+    /// Cloud; Server/DC only has `name`.
+    #[serde(rename = "accountId")]
+    account_id: Option<String>,
+    name: Option<String>,
+    #[serde(rename = "displayName", default)]
+    display_name: String,
+    #[serde(rename = "avatarUrls", default)]
+    avatar_urls: HashMap<String, String>,
+}
+
+impl User {
+    fn id(&self) -> &str {
+        self.account_id.as_deref().or(self.name.as_deref()).unwrap_or(&self.display_name)
+    }
+}
+
+/// Everything rendering a note needs besides the issue itself.
+struct NoteContext<'a> {
+    config: &'a Config,
+    people: Directory,
+    labels: Labels,
+    plugins: WasmPlugins,
+    script: Option<Script>,
+    writer: VaultWriter,
+}
+
+/// Frontmatter keys the user may set on a ticket note; sync carries them over.
+const USER_FRONTMATTER_KEYS: [&str; 1] = ["pinned"];
+
 const JQL_QUERY: &str = "assignee = currentUser() ORDER BY updated DESC";
+/// Issues asked for per search request; Jira caps it at 100 when fields are requested.
+const SEARCH_PAGE_SIZE: usize = 100;
+/// Asked for with every issue: the change history, and the fields as HTML
+/// for content the ADF conversion can't handle.
+const SEARCH_EXPAND: [&str; 2] = ["changelog", "renderedFields"];
+/// Ids per page when a search lists only ids; `search/jql` allows this many then.
+const ID_PAGE_SIZE: usize = 5000;
+/// Minutes an incremental sync reaches back before the last one, for clock skew.
+const WATERMARK_MARGIN_MINUTES: i64 = 5;
+const KANBAN_FILE: &str = "JiraKanban.md";
+/// Everything below this line in a generated note belongs to the user.
+const SAFE_ZONE: &str = "%% GÜVENLİ BÖLGE: Bu satırın altındakiler silinmez %%";
+
+/// A generated index note (epics, people, projects) ended by the safe-zone
+/// line, followed by what the user wrote below it in the `existing` note.
+fn with_safe_zone(generated: &str, existing: Option<&str>) -> String {
+    let personal = existing.and_then(|c| c.split_once(SAFE_ZONE)).map(|(_, rest)| rest.trim_start_matches('\n')).unwrap_or_default();
+    format!("{}\n---\n{}\n{}", generated, SAFE_ZONE, personal)
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     dotenv().ok(); //.env dosyasını yükle
+    let cli = Cli::parse();
+    init_logging(cli.verbose, cli.quiet || cli.cron);
 
-    // Ayarları oku
-    let jira_server = env::var("JIRA_SERVER").expect(".env dosyasında JIRA_SERVER eksik");
-    let jira_user = env::var("JIRA_USER").unwrap_or_default();
-    let jira_token = env::var("JIRA_API_TOKEN").expect(".env dosyasında JIRA_API_TOKEN eksik");
-    let vault_path = env::var("OBSIDIAN_PATH").expect(".env dosyasında OBSIDIAN_PATH eksik");
-
-    println!("🚀 Jira Özel Hattı Başlatılıyor...");
-
-    let client = Client::new();
-    let search_url = format!("https://{}/rest/api/3/search/jql", jira_server);
-
-    // Create a JSON body for the POST request
-    let request_body = serde_json::json!({
-        "jql": JQL_QUERY,
-        "fields": [
-            "key",
-            "summary",
-            "description",
-            "status",
-            "priority"
-        ]
-    });
-
-    // İstek ayarları (Cloud veya Server ayrımı)
-    let request_builder = client.post(&search_url)
-       .json(&request_body);
-
-    let request = if!jira_user.is_empty() {
-        request_builder.basic_auth(jira_user, Some(jira_token))
-    } else {
-        request_builder.bearer_auth(jira_token)
+    let overrides = cli.overrides;
+    let load = || Config::load(&overrides);
+    let (output, cron) = (cli.output, cli.cron);
+    let emit = |result| summary::emit(output, cron, result);
+    match cli.command {
+        None => emit(sync_all(&load()?, Steps::ALL).await),
+        Some(Command::Sync { issue: Some(key), board }) => {
+            emit(sync(&load()?, Steps { notes: true, boards: board }, Some(&key)).await)
+        }
+        Some(Command::Sync { issue: None, .. }) => emit(sync_all(&load()?, Steps { notes: true, boards: false }).await),
+        Some(Command::Board) => emit(sync_all(&load()?, Steps { notes: false, boards: true }).await),
+        Some(Command::Fetch) => fetch_only(&load()?).await,
+        Some(Command::Export { format, out }) => export::run(&load()?, format, out.as_deref()).await,
+        Some(Command::Clean { dry_run }) => clean::run(&load()?, dry_run),
+        Some(Command::Push { watch: true, .. }) => watch::run(&load()?).await,
+        Some(Command::Push { dry_run, confirm, .. }) => push::run(&load()?, dry_run, confirm).await,
+        Some(Command::Daemon) => daemon(&load()?).await,
+        Some(Command::Bulk { action }) => bulk::run(&load()?, &action).await,
+        Some(Command::Queue { action }) => match action {
+            QueueAction::List => queue::list(&load()?),
+            QueueAction::Flush => push::flush(&load()?).await,
+            QueueAction::Clear => queue::clear(&load()?),
+        },
+        Some(Command::Report { kind: ReportKind::Person { name } }) => report::person(&load()?, &name).await,
+        Some(Command::List { status, label, priority, sort }) => {
+            list::run(&load()?, status.as_deref(), label.as_deref(), priority.as_deref(), sort)
+        }
+        Some(Command::Watch { key }) => watchers::set(&load()?, &key, true).await,
+        Some(Command::Unwatch { key }) => watchers::set(&load()?, &key, false).await,
+        Some(Command::Doctor) => doctor::run(&overrides).await,
+        Some(Command::Login { oauth }) => credentials::login(&overrides, oauth).await,
+        Some(Command::Logout) => credentials::logout(&overrides),
+        Some(Command::Capabilities) => capabilities::run(&load()?).await,
+        Some(Command::Migrate { dry_run }) => migrate::run(&load()?, dry_run),
+        Some(Command::Status) => status::run(&load()?),
+        Some(Command::Prune { archive, delete, resolved_days, yes }) => prune::run(&load()?, archive.as_deref(), delete, resolved_days, yes).await,
+        Some(Command::SelfUpdate { check }) => update::run(check).await,
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Cli::command(), "jira_obsidian_sync", &mut std::io::stdout());
+            Ok(())
+        }
+    }
+}
+
+/// Syncs on a fixed interval until stopped. A failed run is reported and the
+/// next one is attempted as usual.
+async fn daemon(config: &Config) -> Result<(), Box<dyn Error>> {
+    let minutes = config.daemon_interval_minutes.unwrap_or(60).max(1);
+    info!("🔁 Arka plan modu: her {} dakikada bir senkronizasyon (Ctrl+C ile çıkın)", minutes);
+    loop {
+        if let Err(e) = sync_all(config, Steps::ALL).await {
+            error!("❌ Senkronizasyon başarısız: {}", e);
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(minutes * 60)).await;
+    }
+}
+
+/// Which parts of a sync run; `sync` and `board` run one of them.
+#[derive(Debug, Clone, Copy)]
+struct Steps {
+    /// Write the per-issue notes.
+    notes: bool,
+    /// Regenerate boards, dashboards and other overview notes.
+    boards: bool,
+}
+
+impl Steps {
+    const ALL: Steps = Steps { notes: true, boards: true };
+}
+
+/// Raw issues from all configured queries, each once, and which queries found it.
+struct Fetched {
+    issues: Vec<serde_json::Value>,
+    matched_queries: HashMap<String, Vec<String>>,
+}
+
+/// The configured queries, or the default one: the saved filter if set,
+/// else the built-in "assigned to me".
+fn queries(config: &Config) -> Vec<Query> {
+    if !config.queries.is_empty() {
+        return config.queries.clone();
+    }
+    let jql = if config.filter_id.is_some() { String::new() } else { JQL_QUERY.to_string() };
+    vec![Query { name: "default".to_string(), jql, filter_id: config.filter_id, board: None }]
+}
+
+/// The JQL a query runs: its own, or the current JQL of its saved filter, so
+/// edits the team makes to the filter in Jira apply on the next sync.
+async fn query_jql(client: &JiraClient, query: &Query) -> Result<String, Box<dyn Error>> {
+    let Some(id) = query.filter_id else {
+        return Ok(query.jql.clone());
     };
+    let filter: serde_json::Value = client
+        .get_json(&format!("/rest/api/3/filter/{}", id))
+        .await
+        .map_err(|e| format!("{} numaralı filtre alınamadı: {}", id, e))?;
+    match filter["jql"].as_str() {
+        Some(jql) => Ok(jql.to_string()),
+        None => Err(format!("{} numaralı filtrenin JQL'i yok", id).into()),
+    }
+}
 
-    let resp = request.send().await?;
+/// What every note shows whatever the config: its frontmatter, details box
+/// and standard sections.
+const NOTE_FIELDS: [&str; 26] = [
+    "key", "project", "summary", "description", "status", "priority", "labels", "issuetype", "components", "fixVersions", "duedate",
+    "assignee", "reporter", "created", "updated", "resolution", "resolutiondate", "comment", "attachment", "issuelinks",
+    "subtasks", "parent", "watches", "votes", "timetracking", "worklog",
+];
 
-    if!resp.status().is_success() {
-        println!("❌ Hata: Jira bağlantısı başarısız oldu. Kod: {}", resp.status());
-        let body = resp.text().await?;
-        println!("Detay: {}", body);
-        return Ok(());
+/// The `fields` of every search: what notes always show, plus what the config
+/// uses — board, epic and sprint fields, renderers, frontmatter fields and
+/// whatever the filter expression and script read from `fields` — each once.
+fn fields(config: &Config) -> Vec<String> {
+    let mut fields: Vec<String> = NOTE_FIELDS.iter().map(|f| f.to_string()).collect();
+    fields.push(config.board.flagged_field().to_string());
+    fields.extend(config.board.points_fields.iter().cloned());
+    fields.extend(config.board.rank_field.iter().cloned());
+    if let Some(field) = config.epics.link_field.as_ref().filter(|_| config.epics.enabled) {
+        fields.push(field.clone());
+    }
+    if let Some(field) = config.sprints.field.as_ref().filter(|_| config.sprints.enabled) {
+        fields.push(field.clone());
+    }
+    fields.extend(config.field_renderers.iter().map(|r| r.field.clone()));
+    fields.extend(config.fields.iter().cloned());
+    fields.extend(config.custom_fields.keys().cloned());
+    if let Some(expression) = &config.filter {
+        fields.extend(filter::referenced_fields(expression));
+    }
+    // Betik okunamazsa yüklenirken zaten hata verir; burada sessizce geç.
+    if let Some(source) = config.script.as_deref().and_then(|path| fs::read_to_string(path).ok()) {
+        fields.extend(filter::referenced_fields(&source));
     }
+    let mut seen = HashSet::new();
+    fields.retain(|field| seen.insert(field.clone()));
+    fields
+}
 
-    let search_results: JiraSearchResponse = resp.json().await?;
-    println!("🔍 {} adet aktif iş bulundu. İşleniyor...", search_results.issues.len());
+/// Error a sync or `bulk` ends with when Jira rejected a search; `search_page`
+/// has logged Jira's answer.
+const SEARCH_REJECTED: &str = "Jira aramayı reddetti; ayrıntılar yukarıda";
 
-    // Klasörü oluştur (yoksa)
-    fs::create_dir_all(&vault_path)?;
+/// Runs every query. `None` when Jira rejected one of them.
+async fn fetch(config: &Config, client: &JiraClient) -> Result<Option<Fetched>, Box<dyn Error>> {
+    fetch_queries(config, client, None).await
+}
+
+/// `fetch` for a routine sync: each query first lists its issues' ids, and
+/// only those updated since `since` are fetched in full; the rest come from
+/// `cache`. A query matching an issue the cache doesn't have is fetched in full.
+async fn fetch_changed(config: &Config, client: &JiraClient, cache: &IssueCache, since: DateTime<FixedOffset>) -> Result<Option<Fetched>, Box<dyn Error>> {
+    // Jira'nın saati bizimkinden biraz geride olabilir; birkaç dakika geriye taşı.
+    let minutes = (Local::now().fixed_offset() - since).num_minutes().max(0) + WATERMARK_MARGIN_MINUTES;
+    info!("⚡ Artımlı senkronizasyon: son {} dakikada değişen işler alınıyor (hepsi için --full)", minutes);
+    fetch_queries(config, client, Some((cache, minutes))).await
+}
+
+async fn fetch_queries(config: &Config, client: &JiraClient, incremental: Option<(&IssueCache, i64)>) -> Result<Option<Fetched>, Box<dyn Error>> {
+    let fields = fields(config);
+    let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+
+    let queries = queries(config);
+    // Aynı iş birden fazla sorguya düşerse tek not, eşleşen tüm sorgular kaydedilir.
+    let mut fetched = Fetched { issues: Vec::new(), matched_queries: HashMap::new() };
+    for query in &queries {
+        let jql = query_jql(client, query).await?;
+        let found = match incremental {
+            Some((cache, minutes)) => search_changed(client, &jql, &fields, cache, minutes).await?,
+            None => search_expanded(client, &jql, &fields, &SEARCH_EXPAND).await?,
+        };
+        let Some(found) = found else {
+            return Ok(None);
+        };
+        if queries.len() > 1 {
+            info!("🔎 {}: {} iş", query.name, found.len());
+        }
+        for raw in found {
+            let key = raw["key"].as_str().unwrap_or_default().to_string();
+            let names = fetched.matched_queries.entry(key).or_default();
+            if names.is_empty() {
+                fetched.issues.push(raw);
+            }
+            names.push(query.name.clone());
+        }
+    }
+    Ok(Some(fetched))
+}
 
-    for issue in &search_results.issues {
-        process_issue(issue, &jira_server, &vault_path)?;
+/// Everything `jql` matches, fetching only issues updated in the last
+/// `minutes` and taking the others from `cache`.
+async fn search_changed(
+    client: &JiraClient,
+    jql: &str,
+    fields: &[&str],
+    cache: &IssueCache,
+    minutes: i64,
+) -> Result<Option<Vec<serde_json::Value>>, Box<dyn Error>> {
+    let Some(listed) = list_issues(client, &expand_jql(client, jql).await?).await? else {
+        return Ok(None);
+    };
+    let keys: Vec<&str> = listed.iter().filter_map(|raw| raw["key"].as_str()).collect();
+    if let Some(missing) = keys.iter().find(|key| cache.issue(key).is_none()) {
+        debug!("{} önbellekte yok; sorgu tümüyle alınıyor", missing);
+        return search_expanded(client, jql, fields, &SEARCH_EXPAND).await;
     }
+    let Some(changed) = search_expanded(client, &jql::updated_since(jql, minutes), fields, &SEARCH_EXPAND).await? else {
+        return Ok(None);
+    };
+    debug!("🔄 {} / {} iş değişmiş", changed.len(), keys.len());
+    let mut changed: HashMap<String, serde_json::Value> =
+        changed.into_iter().map(|raw| (raw["key"].as_str().unwrap_or_default().to_string(), raw)).collect();
+    Ok(Some(keys.iter().filter_map(|key| changed.remove(*key).or_else(|| cache.issue(key).cloned())).collect()))
+}
 
-    let kanban_content = create_kanban_markdown(&search_results.issues);
-    let kanban_path = Path::new(&vault_path).join("JiraKanban.md");
-    let mut file = fs::File::create(&kanban_path)?;
-    write!(file, "{}", kanban_content)?;
-    println!("📋 Kanban panosu güncellendi: JiraKanban.md");
+/// Fetches one issue by key, for `sync --issue`.
+async fn fetch_issue(config: &Config, client: &JiraClient, key: &str) -> Result<Fetched, Box<dyn Error>> {
+    let path = format!("/rest/api/3/issue/{}?fields={}&expand={}", key, fields(config).join(","), SEARCH_EXPAND.join(","));
+    let raw: serde_json::Value = client.get_json(&path).await.map_err(|e| format!("{} alınamadı: {}", key, e))?;
+    Ok(Fetched { issues: vec![raw], matched_queries: HashMap::new() })
+}
 
-    println!("🏁 Senkronizasyon tamamlandı.");
+/// `fetch`: prints what a sync would work on, one tab-separated line per issue.
+async fn fetch_only(config: &Config) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let Some(fetched) = fetch(config, &client).await? else {
+        return Err(SEARCH_REJECTED.into());
+    };
+    for raw in &fetched.issues {
+        let fields = &raw["fields"];
+        println!(
+            "{}\t{}\t{}",
+            raw["key"].as_str().unwrap_or_default(),
+            fields["status"]["name"].as_str().unwrap_or_default(),
+            fields["summary"].as_str().unwrap_or_default()
+        );
+    }
+    info!("🔍 {} iş", fetched.issues.len());
     Ok(())
 }
 
-fn create_kanban_markdown(issues: &Vec<Issue>) -> String {
-    let mut board: HashMap<String, Vec<&Issue>> = HashMap::new();
-    let mut status_order: HashMap<String, i32> = HashMap::new();
+/// Progress goes to stderr so `fetch`/`list` output stays clean for scripts.
+/// Timestamps and levels only show once asked for more detail; libraries
+/// (reqwest, hyper) only speak up at `-vv`.
+fn init_logging(verbose: u8, quiet: bool) {
+    use tracing::Level;
+    use tracing_subscriber::{filter::Targets, fmt, prelude::*};
+    let level = match (quiet, verbose) {
+        (true, _) => Level::WARN,
+        (false, 0) => Level::INFO,
+        (false, 1) => Level::DEBUG,
+        (false, _) => Level::TRACE,
+    };
+    let filter = Targets::new()
+        .with_target(env!("CARGO_CRATE_NAME"), level)
+        .with_default(if verbose > 1 { level } else { Level::WARN });
+    let layer = fmt::layer().with_writer(std::io::stderr).with_target(verbose > 1);
+    if verbose > 0 {
+        tracing_subscriber::registry().with(layer).with(filter).init();
+    } else {
+        tracing_subscriber::registry().with(layer.without_time().with_level(false)).with(filter).init();
+    }
+}
+
+/// Syncs the main Jira host, then every configured instance into its subfolder.
+async fn sync_all(config: &Config, steps: Steps) -> Result<Summary, Box<dyn Error>> {
+    let mut summary = sync(config, steps, None).await?;
+    for instance in &config.instances {
+        info!("🌐 {} ({})", instance.name, instance.jira_server);
+        summary.merge(sync(&config.for_instance(instance)?, steps, None).await?);
+    }
+    Ok(summary)
+}
+
+/// Fetches and writes everything, or only `issue` when given; then its board
+/// card is moved in place instead of rebuilding the boards.
+async fn sync(config: &Config, steps: Steps, issue: Option<&str>) -> Result<Summary, Box<dyn Error>> {
+    let vault_path = &config.vault_path;
+
+    info!("🚀 Jira Özel Hattı Başlatılıyor...");
+    if !config.dry_run {
+        push::retry_queued(config).await?;
+    }
+
+    let client = JiraClient::new(config)?;
+    let resolved;
+    let config = if config.custom_fields.is_empty() {
+        config
+    } else {
+        resolved = custom_fields::resolve(config, &client).await;
+        &resolved
+    };
+    let board_config;
+    let config = match config.board.board_id {
+        Some(board) if steps.boards && config.board.columns.is_empty() => {
+            board_config = with_board_columns(config, &client, board).await;
+            &board_config
+        }
+        _ => config,
+    };
+    // Değişmemiş işler ve ayrıntıları önbellekten gelir, tekrar istenmez.
+    let details = format!(
+        "{};{};remote_links={};watcher_names={}",
+        fields(config).join(","), SEARCH_EXPAND.join(","), config.remote_links, config.watcher_names
+    );
+    let mut cache = IssueCache::load(vault_path, &details);
+    let fetched_at = Local::now();
+    let fetched = match (issue, cache.fetched_at().filter(|_| config.incremental())) {
+        (Some(key), _) => Some(fetch_issue(config, &client, key).await?),
+        (None, Some(since)) => fetch_changed(config, &client, &cache, since).await?,
+        (None, None) => fetch(config, &client).await?,
+    };
+    let Some(Fetched { issues: mut raw_issues, matched_queries }) = fetched else {
+        return Err(SEARCH_REJECTED.into());
+    };
+    let mut summary = Summary { fetched: raw_issues.len(), ..Default::default() };
+    info!("🔍 {} adet aktif iş bulundu. İşleniyor...", raw_issues.len());
+
+    let mut ctx = NoteContext {
+        config,
+        people: Directory::load(vault_path, &config.people)?,
+        labels: Labels::from_config(config),
+        plugins: WasmPlugins::load(&config.wasm_plugins)?,
+        script: config.script.as_deref().map(Script::load).transpose()?,
+        writer: VaultWriter::new(config),
+    };
+    let today = Local::now().date_naive();
+    let mut anonymizer = match config.anonymize.enabled {
+        true => Some(Anonymizer::load(&config.anonymize, vault_path)?),
+        false => None,
+    };
+    let unchanged = cache.restore(&mut raw_issues);
+    let changed = |raw: &&mut serde_json::Value| !unchanged.contains(raw["key"].as_str().unwrap_or_default());
+    for raw in raw_issues.iter_mut().filter(changed) {
+        for (field, items) in [("comment", "comments"), ("worklog", "worklogs")] {
+            match client.complete_field(raw, field, items).await {
+                Ok(()) => {}
+                Err(e) if jira::is_forbidden(e.as_ref()) => {
+                    debug!("{} için {} listesi kısıtlı: {}", raw["key"].as_str().unwrap_or_default(), field, e);
+                    restricted::mark(raw);
+                }
+                Err(e) => warn!("⚠️  {} için {} listesi tamamlanamadı: {}", raw["key"].as_str().unwrap_or_default(), field, e),
+            }
+        }
+    }
+    if config.watcher_names {
+        watchers::resolve(&client, raw_issues.iter_mut().filter(changed)).await;
+    }
+    if config.remote_links {
+        references::resolve(&client, raw_issues.iter_mut().filter(changed)).await;
+    }
+    if !config.dry_run {
+        cache.store(&raw_issues, issue.is_none().then_some(fetched_at));
+        cache.save(vault_path)?;
+    }
+    if config.service_desk.enabled {
+        service_desk::resolve(&client, &config.service_desk, &mut raw_issues).await;
+    }
+    if config.development.enabled {
+        development::resolve(&client, &mut raw_issues).await;
+    }
+    if let Some(board_id) = config.board.board_id {
+        sprints::resolve_scope(&client, board_id, &mut raw_issues).await;
+    }
+    if config.sprints.enabled {
+        match config.sprints.field.as_deref() {
+            Some(field) => sprints::resolve(&client, field, &mut raw_issues).await,
+            None => warn!("⚠️  Sprint alanı bilinmiyor; `capabilities` çalıştırın ya da [sprints] altında field ayarlayın"),
+        }
+    }
+    let mut issues = raw_issues
+        .into_iter()
+        .map(|mut raw| {
+            restricted::stub(&mut raw);
+            let mut issue = ctx.plugins.transform_issue(raw);
+            if let Some(anonymizer) = anonymizer.as_mut() {
+                anonymizer.apply(&mut issue);
+            }
+            ctx.people.collect(&issue, today);
+            serde_json::from_value(issue)
+        })
+        .collect::<Result<Vec<Issue>, _>>()?;
+    summary.restricted = issues.iter().filter(|issue| issue.restricted).map(|issue| issue.key.clone()).collect();
+    if !summary.restricted.is_empty() {
+        warn!("🔒 {} iş tam okunamadı, notları eksik olabilir: {}", summary.restricted.len(), summary.restricted.join(", "));
+    }
+    if !config.anonymize.enabled {
+        ctx.people.refresh(&client, today).await;
+    }
+    if !config.dry_run {
+        ctx.people.save(vault_path)?;
+        if let Some(anonymizer) = &anonymizer {
+            anonymizer.save(vault_path)?;
+        }
+    }
+
+    if let Some(expression) = &config.filter {
+        let filter = IssueFilter::new(expression)?;
+        let before = issues.len();
+        let mut kept = Vec::new();
+        for issue in issues {
+            match filter.matches(&issue) {
+                Ok(false) => {}
+                Ok(true) => kept.push(issue),
+                // Filtre bu işte hata verdiyse iş kalsın, senkronizasyon sürsün.
+                Err(e) => {
+                    warn!("⚠️  {}: filtre değerlendirilemedi, iş tutuluyor: {}", issue.key, e);
+                    kept.push(issue);
+                }
+            }
+        }
+        issues = kept;
+        info!("🧹 Yerel filtre: {} / {} iş kaldı", issues.len(), before);
+    }
+
+    let targets: Vec<Config> = config.vaults.iter().map(|target| config.for_vault(target)).collect();
+    summary.merge(write_vault(&ctx, &client, &issues, &matched_queries, steps, issue.is_some()).await?);
+    for target in &targets {
+        info!("📂 {}", target.vault_path);
+        ctx.config = target;
+        summary.merge(write_vault(&ctx, &client, &issues, &matched_queries, steps, issue.is_some()).await?);
+    }
+
+    queue::RetryQueue::load(vault_path)?.print_summary();
+    if config.dry_run {
+        info!("🔎 Deneme modu: hiçbir dosya yazılmadı.");
+        return Ok(summary);
+    }
+    info!("🏁 Senkronizasyon tamamlandı.");
+    Ok(summary)
+}
+
+/// `config` with the board's columns taken from the Jira board; unchanged
+/// (one column per status) when they can't be read.
+async fn with_board_columns(config: &Config, client: &JiraClient, board: u64) -> Config {
+    let mut config = config.clone();
+    match kanban::board_columns(client, board).await {
+        Ok(columns) => config.board.columns = columns,
+        Err(e) => warn!("⚠️  {} numaralı panonun sütunları alınamadı: {}", board, e),
+    }
+    config
+}
+
+/// Writes the notes and boards for `issues` into the vault of `ctx.config`.
+async fn write_vault(
+    ctx: &NoteContext<'_>,
+    client: &JiraClient,
+    issues: &[Issue],
+    matched_queries: &HashMap<String, Vec<String>>,
+    steps: Steps,
+    single_issue: bool,
+) -> Result<Summary, Box<dyn Error>> {
+    let config = ctx.config;
+    let vault_path = &config.vault_path;
+
+    // Klasörü oluştur (yoksa)
+    if !config.dry_run {
+        fs::create_dir_all(vault_path)?;
+    }
+
+    let mut summary = Summary::default();
+    let mut state = State::load(vault_path)?;
+    let mut note_links: HashMap<String, String> = HashMap::new();
+    let mut pinned: HashSet<String> = config.pinned.iter().cloned().collect();
+    if steps.notes && !single_issue {
+        state.failed.clear();
+    }
+
+    // Notlar birbirine bağlanabilsin diye hepsinin yeri baştan belirlenir.
+    let mut targets: HashMap<String, String> =
+        state.notes.iter().map(|(key, note)| (key.clone(), link_target(Path::new(&note.path)))).collect();
+    let mut paths: HashMap<String, String> = HashMap::new();
+    if steps.notes {
+        for issue in issues {
+            let rel_path = note_rel_path(config, &state, issue);
+            targets.insert(issue.key.clone(), link_target(Path::new(&rel_path)));
+            paths.insert(issue.key.clone(), rel_path);
+        }
+    }
 
     for issue in issues {
-        let status_name = &issue.fields.status.name;
-        board.entry(status_name.clone()).or_default().push(issue);
-
-        if !status_order.contains_key(status_name) {
-            let order = if let Some(cat) = &issue.fields.status.status_category {
-                match cat.key.as_str() {
-                    "new" => 0,
-                    "indeterminate" => 1,
-                    "done" => 2,
-                    _ => 3,
+        if !steps.notes {
+            // Sadece pano: mevcut notlara bağlan, notlara dokunma.
+            if let Some(note) = state.notes.get(&issue.key) {
+                let content = fs::read_to_string(Path::new(vault_path).join(&note.path)).unwrap_or_default();
+                if frontmatter::parse(&content).get("pinned").is_some_and(|v| v == "true") {
+                    pinned.insert(issue.key.clone());
                 }
-            } else {
-                3
-            };
-            status_order.insert(status_name.clone(), order);
+                note_links.insert(issue.key.clone(), link_target(Path::new(&note.path)));
+            }
+            continue;
         }
+        let vars = NoteVars::from_issue(issue);
+        let rel_path = paths.remove(&issue.key).unwrap_or_default();
+
+        let issue_queries = matched_queries.get(&issue.key).map(Vec::as_slice).unwrap_or_default();
+        let abs_path = Path::new(vault_path).join(&rel_path);
+        let before = fs::read_to_string(&abs_path).ok();
+        attachments::download(ctx, client, issue).await;
+        let note = match process_issue(issue, ctx, &abs_path, issue_queries, &targets) {
+            Ok(Some(note)) => note,
+            Ok(None) => {
+                summary.skipped.push(issue.key.clone());
+                continue;
+            }
+            Err(e) => {
+                // Tek bir bozuk iş tüm senkronizasyonu durdurmasın.
+                error!("❌ {} yazılamadı: {}", issue.key, e);
+                summary.errors.push(format!("{}: {}", issue.key, e));
+                state.failed.insert(issue.key.clone(), e.to_string());
+                continue;
+            }
+        };
+        state.failed.remove(&issue.key);
+        summary.record(&rel_path, before.as_deref(), &note);
+        if frontmatter::parse(&note).get("pinned").is_some_and(|v| v == "true") {
+            pinned.insert(issue.key.clone());
+        }
+        note_links.insert(issue.key.clone(), link_target(Path::new(&rel_path)));
+        let updated = issue.fields.extra.get("updated").and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        state.notes.insert(issue.key.clone(), NoteState { path: rel_path, vars, labels: issue.fields.labels.clone(), updated });
+    }
+    if steps.notes && !config.dry_run {
+        state.last_sync = Some(Local::now().to_rfc3339());
+        state.save(vault_path)?;
     }
+    let issues: Vec<Issue> = issues.iter().filter(|issue| !summary.skipped.contains(&issue.key)).cloned().collect();
 
-    let mut sorted_statuses: Vec<String> = board.keys().cloned().collect();
-    sorted_statuses.sort_by(|a, b| {
-        let order_a = status_order.get(a).unwrap_or(&3);
-        let order_b = status_order.get(b).unwrap_or(&3);
-        if order_a == order_b {
-            a.cmp(b)
-        } else {
-            order_a.cmp(order_b)
+    if steps.boards && single_issue {
+        for issue in &issues {
+            update_card(ctx, issue, &note_links, &pinned)?;
         }
-    });
+    } else if steps.boards {
+        write_boards(ctx, client, &issues, matched_queries, &note_links, &pinned).await?;
+    }
+    Ok(summary)
+}
 
-    let mut markdown = String::from("---\nkanban-plugin: board\n---\n");
+/// Vault-relative path of the note for `issue`: where the layout puts it, or
+/// where it already is when the layout has changed since.
+fn note_rel_path(config: &Config, state: &State, issue: &Issue) -> String {
+    let wanted = layout::note_path(config, &NoteVars::from_issue(issue)).to_string_lossy().replace('\\', "/");
+    // Şablon değiştiyse notu yerinde bırak; taşımak `migrate` komutunun işi.
+    match state.notes.get(&issue.key) {
+        Some(note) if note.path != wanted && Path::new(&config.vault_path).join(&note.path).exists() => {
+            warn!("⚠️  {} eski konumunda güncellendi, yeni düzen için `migrate` çalıştırın", issue.key);
+            note.path.clone()
+        }
+        _ => wanted,
+    }
+}
 
-    for status in &sorted_statuses {
-        markdown.push_str(&format!("\n## {}\n\n", status));
-        if let Some(issues) = board.get(status) {
-            for issue in issues {
-                markdown.push_str(&format!("- [ ] [[{}]]\n", issue.key));
+/// Boards, dashboards and the other overview notes built from all issues.
+async fn write_boards(
+    ctx: &NoteContext<'_>,
+    client: &JiraClient,
+    issues: &[Issue],
+    matched_queries: &HashMap<String, Vec<String>>,
+    note_links: &HashMap<String, String>,
+    pinned: &HashSet<String>,
+) -> Result<(), Box<dyn Error>> {
+    let config = ctx.config;
+    let today = Local::now().date_naive();
+    write_board(ctx, ctx.config.board.file(), issues, note_links, pinned)?;
+    changes::update(ctx, issues, note_links)?;
+    for view in &config.views {
+        let view_issues: Vec<Issue> = issues.iter().filter(|i| view.matches(i)).cloned().collect();
+        write_board(ctx, &view.file_name(), &view_issues, note_links, pinned)?;
+    }
+    for query in &queries(config) {
+        let Some(board) = &query.board else { continue };
+        let query_issues: Vec<Issue> = issues
+            .iter()
+            .filter(|i| matched_queries.get(&i.key).is_some_and(|names| names.contains(&query.name)))
+            .cloned()
+            .collect();
+        write_board(ctx, board, &query_issues, note_links, pinned)?;
+    }
+    if config.person_notes.enabled {
+        person_notes::write(ctx, client, issues, note_links).await?;
+    }
+    if config.project_notes.enabled {
+        project_notes::write(ctx, issues, note_links)?;
+    }
+    if config.epics.enabled {
+        epics::write(ctx, client, issues, note_links).await?;
+    }
+    dashboards::write(ctx, client, issues, note_links).await?;
+    if let Err(e) = velocity::write(ctx, client).await {
+        warn!("⚠️  Sprint hızı notu oluşturulamadı: {}", e);
+    }
+    if config.flow.enabled {
+        flow::record(ctx, issues, today)?;
+    }
+    Ok(())
+}
+
+/// Runs one JQL search. `None` when Jira answered with an error, which is
+/// reported here.
+async fn search(
+    client: &JiraClient,
+    jql: &str,
+    fields: &[&str],
+) -> Result<Option<Vec<serde_json::Value>>, Box<dyn Error>> {
+    search_expanded(client, jql, fields, &[]).await
+}
+
+/// [`search`] that also asks for `expand`, e.g. `changelog`.
+async fn search_expanded(
+    client: &JiraClient,
+    jql: &str,
+    fields: &[&str],
+    expand: &[&str],
+) -> Result<Option<Vec<serde_json::Value>>, Box<dyn Error>> {
+    let jql = expand_jql(client, jql).await?;
+    let body = |cursor: Option<&PageCursor>| {
+        let mut request_body = serde_json::json!({
+            "jql": jql,
+            "fields": fields,
+            "maxResults": SEARCH_PAGE_SIZE
+        });
+        if !expand.is_empty() {
+            // search/jql virgüllü bir metin, eski /search bir liste bekler.
+            request_body["expand"] = if client.uses_legacy_search() { expand.into() } else { expand.join(",").into() };
+        }
+        match cursor {
+            Some(PageCursor::Token(token)) => request_body["nextPageToken"] = token.as_str().into(),
+            Some(PageCursor::StartAt(start)) => request_body["startAt"] = (*start).into(),
+            None => {}
+        }
+        request_body
+    };
+
+    let first = loop {
+        let resp = client.send(client.request(Method::POST, client.search_path()).json(&body(None))).await?;
+        // search/jql olmayan eski sürümlerde klasik uç noktaya geç.
+        if resp.status() == StatusCode::NOT_FOUND && client.fall_back_to_legacy_search() {
+            warn!("⚠️  /search/jql bulunamadı; eski /search uç noktası kullanılıyor");
+            continue;
+        }
+        match search_page(resp).await? {
+            Some(page) => break page,
+            None => return Ok(None),
+        }
+    };
+    let (starts, cursor) = (first.remaining_starts(), first.next());
+    let mut issues = first.issues;
+    match cursor {
+        None => Ok(Some(issues)),
+        // Toplamı bilinen eski /search: kalan sayfalar aynı anda istenir.
+        Some(PageCursor::StartAt(_)) => {
+            debug!("📄 {} iş alındı, kalan {} sayfa isteniyor", issues.len(), starts.len());
+            let pages: Vec<_> = stream::iter(starts)
+                .map(|start| {
+                    let request_body = body(Some(&PageCursor::StartAt(start)));
+                    async move { search_page(client.send(client.request(Method::POST, client.search_path()).json(&request_body)).await?).await }
+                })
+                .buffered(client.concurrency())
+                .collect()
+                .await;
+            for page in pages {
+                match page? {
+                    Some(page) => issues.extend(page.issues),
+                    None => return Ok(None),
+                }
             }
+            Ok(Some(issues))
+        }
+        // search/jql sayfaları sırayla gelen jetonlarla ilerler: önce yalnızca
+        // kimlikler listelenir, kalan işler sonra toplu ve paralel alınır.
+        Some(PageCursor::Token(_)) => {
+            let Some(listed) = list_issues(client, &jql).await? else { return Ok(None) };
+            let ids: Vec<String> = listed.iter().filter_map(|issue| issue["id"].as_str().map(str::to_string)).collect();
+            let fetched: HashSet<&str> = issues.iter().filter_map(|issue| issue["id"].as_str()).collect();
+            let rest: Vec<String> = ids.iter().filter(|id| !fetched.contains(id.as_str())).cloned().collect();
+            debug!("📄 {} iş alındı, kalan {} iş toplu isteniyor", issues.len(), rest.len());
+            let batches: Vec<_> = stream::iter(rest.chunks(BULK_FETCH_SIZE))
+                .map(|batch| client.bulk_fetch(batch, fields, expand))
+                .buffered(client.concurrency())
+                .collect()
+                .await;
+            for batch in batches {
+                issues.extend(batch?);
+            }
+            // Arama sırasını koru.
+            let order: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+            issues.sort_by_key(|issue| issue["id"].as_str().and_then(|id| order.get(id).copied()).unwrap_or(usize::MAX));
+            Ok(Some(issues))
         }
     }
+}
 
-    let col_count = sorted_statuses.len();
-    let collapse_list: Vec<bool> = vec![false; col_count];
-    let collapse_json = serde_json::to_string(&collapse_list).unwrap_or_else(|_| "[]".to_string());
+/// Just the ids and keys of everything `jql` (already expanded) matches, in
+/// search order: far cheaper than a search with fields, as Jira returns
+/// thousands of them per page.
+async fn list_issues(client: &JiraClient, jql: &str) -> Result<Option<Vec<serde_json::Value>>, Box<dyn Error>> {
+    let mut issues = Vec::new();
+    let mut cursor = None;
+    loop {
+        let mut request_body = serde_json::json!({ "jql": jql, "fields": ["id"], "maxResults": ID_PAGE_SIZE });
+        match &cursor {
+            Some(PageCursor::Token(token)) => request_body["nextPageToken"] = token.as_str().into(),
+            Some(PageCursor::StartAt(start)) => request_body["startAt"] = (*start).into(),
+            None => {}
+        }
+        let resp = client.send(client.request(Method::POST, client.search_path()).json(&request_body)).await?;
+        if resp.status() == StatusCode::NOT_FOUND && cursor.is_none() && client.fall_back_to_legacy_search() {
+            warn!("⚠️  /search/jql bulunamadı; eski /search uç noktası kullanılıyor");
+            continue;
+        }
+        let Some(page) = search_page(resp).await? else { return Ok(None) };
+        cursor = page.next();
+        issues.extend(page.issues);
+        if cursor.is_none() {
+            return Ok(Some(issues));
+        }
+    }
+}
 
-    markdown.push_str("\n\n%% kanban:settings\n```\n");
-    markdown.push_str(&format!(r#"{{"kanban-plugin":"board","list-collapse":{}}}"#, collapse_json));
-    markdown.push_str("\n```\n%%\n");
+/// One page of search results, or `None` (after logging Jira's answer) when
+/// the search failed.
+async fn search_page(resp: reqwest::Response) -> Result<Option<SearchPage>, Box<dyn Error>> {
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await?;
+        error!("❌ Hata: Jira bağlantısı başarısız oldu. Kod: {} — {}", status, body);
+        return Ok(None);
+    }
+    Ok(Some(resp.json().await?))
+}
 
-    markdown
+/// `jql` with its `{{placeholders}}` filled in for today and the signed-in user.
+async fn expand_jql(client: &JiraClient, jql: &str) -> Result<String, Box<dyn Error>> {
+    let me = if jql::uses_me(jql) {
+        let me: User = client.get_json("/rest/api/3/myself").await?;
+        Some(me.id().to_string())
+    } else {
+        None
+    };
+    let expanded = jql::expand(jql, Local::now().date_naive(), me.as_deref())?;
+    if expanded != jql {
+        debug!("🔎 JQL: {}", expanded);
+    }
+    Ok(expanded)
+}
+
+/// Moves one issue's card on the main board without rebuilding the board.
+fn update_card(
+    ctx: &NoteContext,
+    issue: &Issue,
+    note_links: &HashMap<String, String>,
+    pinned: &HashSet<String>,
+) -> Result<(), Box<dyn Error>> {
+    let file_name = ctx.config.board.file();
+    let path = Path::new(&ctx.config.vault_path).join(file_name);
+    let Ok(board) = fs::read_to_string(&path) else {
+        warn!("⚠️  {} bulunamadı; panoyu oluşturmak için tam bir sync çalıştırın", file_name);
+        return Ok(());
+    };
+    let columns = kanban::columns(std::slice::from_ref(issue), &ctx.config.board);
+    let Some((column, _)) = columns.into_iter().find(|(_, cards)| !cards.is_empty()) else {
+        return Ok(());
+    };
+    let card = kanban::card(issue, note_links, pinned, &ctx.config.icons, &ctx.config.board);
+    ctx.writer.write(&path, &kanban::move_card(&board, &issue.key, &column, &card))?;
+    info!("📋 {} kartı '{}' sütununa taşındı: {}", issue.key, column, file_name);
+    Ok(())
 }
 
-fn process_issue(issue: &Issue, server: &str, path: &str) -> Result<(), Box<dyn Error>> {
-    let file_name = format!("{}.md", issue.key);
-    let file_path = Path::new(path).join(file_name);
+/// Generates a board from `issues` and writes it to `file_name` in the vault,
+/// keeping the plugin settings of the board already there.
+fn write_board(
+    ctx: &NoteContext,
+    file_name: &str,
+    issues: &[Issue],
+    note_links: &HashMap<String, String>,
+    pinned: &HashSet<String>,
+) -> Result<(), Box<dyn Error>> {
+    let kanban_path = Path::new(&ctx.config.vault_path).join(file_name);
+    let now = Local::now();
+    let sync_time = now.format("%Y-%m-%d %H:%M").to_string();
+    let header = kanban::stats_header(issues, &ctx.labels, &ctx.config.board, now.date_naive(), &sync_time);
+    let mut kanban_content = kanban::create_kanban_markdown(issues, note_links, pinned, &ctx.config.icons, &ctx.config.board, &header);
+    if let Ok(existing) = fs::read_to_string(&kanban_path) {
+        kanban_content = kanban::preserve_plugin_blocks(&kanban_content, &existing);
+    }
+    ctx.writer.write(&kanban_path, &kanban_content)?;
+    info!("📋 Kanban panosu güncellendi: {}", file_name);
+    Ok(())
+}
 
-    let priority_name = issue.fields.priority.as_ref().map(|p| p.name.as_str()).unwrap_or("Belirsiz");
-    let description = issue.fields.description.as_ref().map_or(String::new(), |d| extract_text_from_doc(d));
-    let markdown_desc = jira_to_markdown(&description);
-    let link = format!("https://{}/browse/{}", server, issue.key);
+/// Writes the note for one issue and returns its content, or `None` when the
+/// user script skipped it. `queries` are the names of the configured queries
+/// that returned the issue; `targets` the wikilink target of every note.
+fn process_issue(
+    issue: &Issue,
+    ctx: &NoteContext,
+    file_path: &Path,
+    queries: &[String],
+    targets: &HashMap<String, String>,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let (config, labels, plugins) = (ctx.config, &ctx.labels, &ctx.plugins);
+    let existing = fs::read_to_string(file_path).ok();
+    let user_frontmatter: String = existing.as_deref().map(frontmatter::parse).unwrap_or_default()
+        .into_iter()
+        .filter(|(k, _)| USER_FRONTMATTER_KEYS.contains(&k.as_str()))
+        .map(|(k, v)| format!("{}: {}\n", k, v))
+        .collect();
+    let priority_name = issue.fields.priority.as_ref().map(|p| p.name.as_str()).unwrap_or(&labels.unknown_priority);
+    let people_frontmatter: String = [("jira_assignee", &issue.fields.assignee), ("jira_reporter", &issue.fields.reporter)]
+        .iter()
+        .filter_map(|(name, user)| user.as_ref().map(|u| format!("{}: {}\n", name, ctx.people.name(u.id()))))
+        .collect();
+    let mut status_fields = String::new();
+    if let Some(category) = &issue.fields.status.status_category {
+        status_fields.push_str(&format!("jira_status_category: {}\n", category.key));
+    }
+    if let Some(due) = &issue.fields.duedate {
+        status_fields.push_str(&format!("jira_due: {}\n", due));
+    }
+    if let Some(resolution) = issue.fields.extra.get("resolution").and_then(|r| r["name"].as_str()) {
+        status_fields.push_str(&format!("jira_resolution: \"{}\"\n", resolution.replace('"', "'")));
+    }
+    if let Some(resolved) = issue.fields.resolved() {
+        status_fields.push_str(&format!("jira_resolved: {}\n", resolved.with_timezone(&Local).format("%Y-%m-%d")));
+    }
+    let lists = [
+        ("jira_labels", issue.fields.labels.iter().map(String::as_str).collect()),
+        ("jira_components", issue.fields.names("components")),
+        ("jira_fix_versions", issue.fields.names("fixVersions")),
+    ];
+    for (key, items) in lists.iter().filter(|(_, items)| !items.is_empty()) {
+        let items: Vec<String> = items.iter().map(|item| format!("\"{}\"", item.replace('"', "'"))).collect();
+        status_fields.push_str(&format!("{}: [{}]\n", key, items.join(", ")));
+    }
+    status_fields.push_str(&watchers::frontmatter(issue, &ctx.people));
+    status_fields.push_str(&service_desk::frontmatter(issue));
+    for field in &config.fields {
+        if let Some(value) = issue.fields.extra.get(field).and_then(frontmatter::value) {
+            status_fields.push_str(&format!("jira_{}: {}\n", field, value));
+        }
+    }
+    if let Some(field) = config.sprints.field.as_deref().filter(|_| config.sprints.enabled) {
+        status_fields.push_str(&sprints::frontmatter(issue, field));
+    }
+    status_fields.push_str(&sprints::scope_frontmatter(issue));
+    if issue.restricted {
+        status_fields.push_str("restricted: true\n");
+    }
+    if let Some(points) = kanban::story_points(issue, &config.board) {
+        status_fields.push_str(&format!("jira_story_points: {}\n", kanban::format_points(points)));
+    }
+    status_fields.push_str(&custom_fields::frontmatter(issue, config));
+    let rendered_description = issue.rendered_fields.as_ref().and_then(|r| r["description"].as_str());
+    let mut markdown_desc = issue.fields.description.as_ref().map(|d| rich_text(ctx, d, rendered_description)).unwrap_or_default();
+    if markdown_desc.trim().is_empty() {
+        markdown_desc = labels.no_description.clone();
+    }
+    let link = format!("{}/browse/{}", config.base_url(), issue.key);
+    let queries_line = if config.queries.is_empty() {
+        String::new()
+    } else if queries.is_empty() {
+        // Tek iş sync'inde hangi sorguların eşleştiği bilinmez; eskisini koru.
+        existing.as_deref().map(frontmatter::parse).and_then(|fm| fm.get("queries").map(|q| format!("queries: {}\n", q))).unwrap_or_default()
+    } else {
+        format!("queries: [{}]\n", queries.join(", "))
+    };
     let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
     let frontmatter = format!(r#"---
@@ -187,20 +1103,20 @@ jira_status: {status}
 jira_priority: {priority}
 jira_link: {link}
 updated: {date}
-tags: [jira, task]
----
-# {key}: {summary}
-
-> [!INFO] Jira Detayları
-> **Durum:** `{status}` | **Öncelik:** `{priority}`
-> **Link:** [Jira'da Aç]({link})
-> **Son Sync:** {date}
+{status_fields}{parent}{epic}{people_frontmatter}{metrics}tags: [jira, task]
+{queries_line}{user_frontmatter}---
+# {icons}{key}: {summary}
 
-## 📄 Açıklama
+> [!INFO] {l_details}
+> **{l_status}:** `{status}` | **{l_priority}:** `{priority}`
+> **{l_link}:** [{l_open}]({link})
+> **{l_sync}:** {date}
+{restricted}{sla}
+## 📄 {l_desc}
 {desc}
-
+{extra}{subtasks}{links}{references}{development}{attachments}{time}{comments}{history}
 ---
-%% GÜVENLİ BÖLGE: Bu satırın altındakiler silinmez %%
+{safe_zone}
 "#,
         key=issue.key,
         status=issue.fields.status.name,
@@ -208,42 +1124,118 @@ tags: [jira, task]
         link=link,
         date=now,
         summary=issue.fields.summary,
-        desc=markdown_desc
+        icons=config.icons.prefix(issue),
+        restricted=restricted::callout(issue, labels),
+        desc=markdown_desc,
+        status_fields=status_fields,
+        people_frontmatter=people_frontmatter,
+        metrics=metrics::frontmatter(issue, Local::now().fixed_offset()),
+        queries_line=queries_line,
+        user_frontmatter=user_frontmatter,
+        extra=render_extra_fields(issue, config),
+        parent=hierarchy::frontmatter(issue, targets),
+        epic=epics::frontmatter(issue, config),
+        subtasks=hierarchy::section(issue, &labels.subtasks, targets, &config.base_url()),
+        links=links::section(issue, &labels.linked_issues, targets, &config.base_url()),
+        sla=service_desk::callout(issue, labels),
+        references=references::section(issue, &labels.references),
+        development=development::section(issue, labels),
+        attachments=attachments::section(ctx, issue),
+        time=worklogs::section(issue, labels, &ctx.people, *Local::now().offset()),
+        history=history::section(issue, labels, &ctx.people, *Local::now().offset()),
+        comments=comments::section(issue, &labels.comments, &ctx.people, *Local::now().offset(), &|comment| rich_text(ctx, &comment["body"], rendered_comment(issue, comment))),
+        l_details=labels.details,
+        l_status=labels.status,
+        l_priority=labels.priority,
+        l_link=labels.link,
+        l_open=labels.open_in_jira,
+        l_sync=labels.last_sync,
+        l_desc=labels.description,
+        safe_zone=SAFE_ZONE
     );
 
-    let mut personal_notes = String::from("\n## 🧠 Kişisel Notlarım\n- [ ] Buraya not alabilirsin.\n");
+    let mut personal_notes = format!("\n## 🧠 {}\n- [ ] {}\n", labels.personal_notes, labels.notes_placeholder);
 
-    if file_path.exists() {
-        let content = fs::read_to_string(&file_path)?;
-        let splitter = "%% GÜVENLİ BÖLGE: Bu satırın altındakiler silinmez %%";
-        if let Some(parts) = content.split_once(splitter) {
+    if let Some(content) = &existing {
+        if let Some(parts) = content.split_once(SAFE_ZONE) {
             personal_notes = parts.1.to_string();
         }
     }
 
-    let mut file = fs::File::create(&file_path)?;
-    write!(file, "{}{}", frontmatter, personal_notes)?;
+    let mut note = format!("{}{}", frontmatter, personal_notes);
+    if let Some(script) = &ctx.script {
+        let script_issue = serde_json::json!({
+            "key": issue.key,
+            "summary": issue.fields.summary,
+            "status": issue.fields.status.name,
+            "priority": priority_name,
+            "description": markdown_desc,
+            "fields": issue.fields.extra,
+        });
+        match script.run(&script_issue, note)? {
+            ScriptResult::Write(rewritten) => note = rewritten,
+            ScriptResult::Skip => {
+                info!("⏭️  Betik atladı: {}", issue.key);
+                return Ok(None);
+            }
+        }
+    }
+    let note = plugins.post_process_note(note);
 
-    println!("✅ Yazıldı: {}", issue.key);
-    Ok(())
+    ctx.writer.write(file_path, &note)?;
+    info!("✅ Yazıldı: {}", issue.key);
+    debug!(key = %issue.key, path = %file_path.display(), "not güncellendi");
+    for conflict in vault::conflict_siblings(file_path) {
+        warn!("⚠️  Senkron çakışma kopyası bulundu: {}", conflict.display());
+    }
+    Ok(Some(note))
 }
 
-fn extract_text_from_doc(doc: &serde_json::Value) -> String {
-    let mut text = String::new();
-    if let Some(content) = doc.get("content").and_then(|c| c.as_array()) {
-        for item in content {
-            if let Some(content) = item.get("content").and_then(|c| c.as_array()) {
-                for sub_item in content {
-                    if let Some(text_content) = sub_item.get("text").and_then(|t| t.as_str()) {
-                        text.push_str(text_content);
-                    }
-                }
+/// Sections produced by the configured external field renderers.
+fn render_extra_fields(issue: &Issue, config: &Config) -> String {
+    let mut sections = String::new();
+    for renderer in &config.field_renderers {
+        let value = match issue.fields.extra.get(&renderer.field) {
+            Some(value) if !value.is_null() => value,
+            _ => continue,
+        };
+        match renderer.render(value) {
+            Ok(markdown) if !markdown.trim().is_empty() => {
+                sections.push_str(&format!("\n## {}\n{}\n", renderer.title(), markdown.trim_end()));
             }
-            // Add a newline after each top-level content item to preserve some structure
-            text.push('\n');
+            Ok(_) => {}
+            Err(e) => warn!("⚠️  {} / {}: {}", issue.key, renderer.field, e),
         }
     }
-    text
+    sections
+}
+
+/// A description or comment body as Markdown: ADF on API v3 (mentions named
+/// from the people directory), wiki markup on v2. ADF with node types
+/// [`adf::to_markdown`] doesn't know (macros, extensions) is taken from
+/// Jira's `rendered` HTML instead, when there is some.
+fn rich_text(ctx: &NoteContext, value: &serde_json::Value, rendered: Option<&str>) -> String {
+    if let Some(wiki) = value.as_str() {
+        return jira_to_markdown(wiki);
+    }
+    let render_node = |node: &serde_json::Value| ctx.plugins.render_adf_node(node);
+    if let Some(html) = rendered.filter(|_| !adf::is_supported(value, &render_node)) {
+        debug!("ADF desteklenmeyen içerik barındırıyor; Jira'nın HTML çıktısı kullanılıyor");
+        return html::to_markdown(html);
+    }
+    let mut doc = value.clone();
+    ctx.people.rewrite_mentions(&mut doc);
+    adf::to_markdown(&doc, &render_node)
+}
+
+/// The HTML Jira rendered for `comment`: its own `renderedBody` when it was
+/// fetched page by page, else the matching comment in `renderedFields`.
+fn rendered_comment<'a>(issue: &'a Issue, comment: &'a serde_json::Value) -> Option<&'a str> {
+    if let Some(body) = comment["renderedBody"].as_str() {
+        return Some(body);
+    }
+    let rendered = issue.rendered_fields.as_ref()?["comment"]["comments"].as_array()?;
+    rendered.iter().find(|r| r["id"] == comment["id"])?["body"].as_str()
 }
 
 fn jira_to_markdown(text: &str) -> String {
@@ -261,55 +1253,3 @@ fn jira_to_markdown(text: &str) -> String {
 
     t
 }
-
-#[cfg(test)]
-#[test]
-fn test_kanban_logic() {
-    let issue1 = Issue {
-        key: "TASK-1".to_string(),
-        fields: Fields {
-            summary: "Task 1".to_string(),
-            description: None,
-            status: Status {
-                name: "To Do".to_string(),
-                status_category: Some(StatusCategory {
-                    key: "new".to_string(),
-                    name: "To Do".to_string(),
-                }),
-            },
-            priority: None,
-        },
-    };
-
-    let issue2 = Issue {
-        key: "TASK-2".to_string(),
-        fields: Fields {
-            summary: "Task 2".to_string(),
-            description: None,
-            status: Status {
-                name: "Done".to_string(),
-                status_category: Some(StatusCategory {
-                    key: "done".to_string(),
-                    name: "Done".to_string(),
-                }),
-            },
-            priority: None,
-        },
-    };
-
-    let issues = vec![issue1, issue2];
-    let markdown = create_kanban_markdown(&issues);
-
-    assert!(markdown.contains("## To Do"));
-    assert!(markdown.contains("## Done"));
-    assert!(markdown.contains("- [ ] [[TASK-1]]"));
-    assert!(markdown.contains("- [ ] [[TASK-2]]"));
-
-    // Check order: To Do (new) comes before Done (done)
-    let todo_pos = markdown.find("## To Do").unwrap();
-    let done_pos = markdown.find("## Done").unwrap();
-    assert!(todo_pos < done_pos);
-
-    // Check settings
-    assert!(markdown.contains(r#"{"kanban-plugin":"board","list-collapse":[false,false]}"#));
-}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, FixedOffset};
+use crate::Issue;
+
+/// Jira timestamps look like `2024-01-15T10:30:00.000+0000`.
+const JIRA_TIME: &str = "%Y-%m-%dT%H:%M:%S%.f%z";
+
+/// Frontmatter lines with derived per-issue metrics, for Dataview dashboards:
+/// days since creation and since the last update, and hours until the first
+/// comment (left out while nobody has commented).
+pub fn frontmatter(issue: &Issue, now: DateTime<FixedOffset>) -> String {
+    let time = |field: &str| issue.fields.extra.get(field).and_then(|v| v.as_str()).and_then(parse_time);
+    let created = time("created");
+    let mut lines = String::new();
+
+    if let Some(created) = created {
+        lines.push_str(&format!("jira_age_days: {}\n", (now - created).num_days()));
+    }
+    if let Some(updated) = time("updated") {
+        lines.push_str(&format!("jira_days_since_update: {}\n", (now - updated).num_days()));
+    }
+    let first_comment = issue.fields.extra.get("comment")
+        .and_then(|c| c["comments"].as_array())
+        .and_then(|comments| comments.iter().filter_map(|c| c["created"].as_str().and_then(parse_time)).min());
+    if let (Some(created), Some(first)) = (created, first_comment) {
+        let hours = (first - created).num_minutes() as f64 / 60.0;
+        lines.push_str(&format!("jira_first_comment_hours: {:.1}\n", hours));
+    }
+    lines
+}
+
+pub fn parse_time(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, JIRA_TIME).ok()
+}
+
+#[cfg(test)]
+#[test]
+fn test_metrics_frontmatter() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "x",
+            "status": { "name": "To Do" },
+            "created": "2025-05-01T09:00:00.000+0000",
+            "updated": "2025-05-20T12:00:00.000+0000",
+            "comment": { "comments": [
+                { "created": "2025-05-02T10:30:00.000+0000" },
+                { "created": "2025-05-01T15:00:00.000+0000" }
+            ]}
+        }
+    }))
+    .unwrap();
+    let now = parse_time("2025-06-01T09:00:00.000+0000").unwrap();
+    assert_eq!(
+        frontmatter(&issue, now),
+        "jira_age_days: 31\njira_days_since_update: 11\njira_first_comment_hours: 6.0\n"
+    );
+}
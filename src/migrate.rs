@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use regex::{Captures, Regex};
+use crate::config::Config;
+use crate::layout::{self, link_target};
+use crate::state::State;
+use crate::vault::VaultWriter;
+use tracing::{info, warn};
+
+/// Moves every managed note to the path the current folder/filename templates
+/// produce, then rewrites wikilinks in the managed notes and the board.
+pub fn run(config: &Config, dry_run: bool) -> Result<(), Box<dyn Error>> {
+    let vault = Path::new(&config.vault_path);
+    let mut state = State::load(&config.vault_path)?;
+    let mut renames: HashMap<String, String> = HashMap::new();
+
+    for (key, note) in state.notes.iter_mut() {
+        let new_rel = layout::note_path(config, &note.vars);
+        let new_path = new_rel.to_string_lossy().replace('\\', "/");
+        if new_path == note.path {
+            continue;
+        }
+
+        let old_abs = vault.join(&note.path);
+        let new_abs = vault.join(&new_rel);
+        if new_abs.exists() {
+            warn!("⚠️  {}: hedef zaten var, atlandı ({})", key, new_path);
+            continue;
+        }
+
+        info!("🚚 {}: {} -> {}", key, note.path, new_path);
+        if !dry_run {
+            if old_abs.exists() {
+                if let Some(parent) = new_abs.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::rename(&old_abs, &new_abs)?;
+                remove_empty_parents(&old_abs, vault);
+            } else {
+                warn!("⚠️  {}: eski dosya bulunamadı, sadece kayıt güncellendi", key);
+            }
+        }
+
+        renames.insert(link_target(Path::new(&note.path)), link_target(&new_rel));
+        if !dry_run {
+            note.path = new_path;
+        }
+    }
+
+    if renames.is_empty() {
+        info!("✨ Taşınacak not yok, düzen güncel.");
+        return Ok(());
+    }
+    if dry_run {
+        info!("🔎 Deneme modu: {} not taşınacaktı, hiçbir şey yazılmadı.", renames.len());
+        return Ok(());
+    }
+
+    let writer = VaultWriter::new(config);
+    let mut files: Vec<PathBuf> = state.notes.values().map(|n| vault.join(&n.path)).collect();
+    files.push(vault.join(config.board.file()));
+    for file in files {
+        if !file.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&file)?;
+        let updated = rewrite_links(&content, &renames);
+        if updated != content {
+            writer.write(&file, &updated)?;
+        }
+    }
+
+    state.save(&config.vault_path)?;
+    info!("🏁 {} not yeni düzene taşındı.", renames.len());
+    Ok(())
+}
+
+/// Points `[[old]]`, `[[old|alias]]` and `[[old#heading]]` links at their new targets.
+/// Links without an alias get the old file name as alias so they read the same.
+fn rewrite_links(content: &str, renames: &HashMap<String, String>) -> String {
+    let re = Regex::new(r"\[\[([^\]|#]+)((?:#[^\]|]*)?)((?:\|[^\]]*)?)\]\]").unwrap();
+    re.replace_all(content, |caps: &Captures| {
+        let target = &caps[1];
+        match renames.get(target) {
+            Some(new_target) => {
+                let alias = if caps[3].is_empty() {
+                    format!("|{}", target.rsplit('/').next().unwrap_or(target))
+                } else {
+                    caps[3].to_string()
+                };
+                format!("[[{}{}{}]]", new_target, &caps[2], alias)
+            }
+            None => caps[0].to_string(),
+        }
+    })
+    .to_string()
+}
+
+/// Removes directories left empty by a move, stopping at the vault root.
+fn remove_empty_parents(file: &Path, vault: &Path) {
+    let mut dir = file.parent();
+    while let Some(d) = dir {
+        if d == vault || fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rewrite_links() {
+    let mut renames = HashMap::new();
+    renames.insert("PROJ-1".to_string(), "PROJ/PROJ-1 Login".to_string());
+
+    let content = "- [ ] [[PROJ-1]]\nSee [[PROJ-1#Açıklama|the bug]] and [[PROJ-10]].";
+    let updated = rewrite_links(content, &renames);
+
+    assert_eq!(
+        updated,
+        "- [ ] [[PROJ/PROJ-1 Login|PROJ-1]]\nSee [[PROJ/PROJ-1 Login#Açıklama|the bug]] and [[PROJ-10]]."
+    );
+}
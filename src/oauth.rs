@@ -0,0 +1,257 @@
+use std::error::Error;
+use std::process;
+use chrono::Utc;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+use crate::config::Config;
+use crate::credentials;
+
+const AUTHORIZE_URL: &str = "https://auth.atlassian.com/authorize";
+const TOKEN_URL: &str = "https://auth.atlassian.com/oauth/token";
+const RESOURCES_URL: &str = "https://api.atlassian.com/oauth/token/accessible-resources";
+const SCOPES: &str = "read:jira-work write:jira-work read:jira-user offline_access";
+/// Access tokens are renewed this many seconds before they expire.
+const REFRESH_MARGIN: i64 = 60;
+
+/// An Atlassian OAuth 2.0 (3LO) app to sign in with instead of an API token.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    /// Port of the local callback (default 8765); the app's callback URL must be
+    /// `http://localhost:<port>/callback`.
+    pub callback_port: Option<u16>,
+}
+
+impl OAuthConfig {
+    pub fn enabled(&self) -> bool {
+        !self.client_id.is_empty()
+    }
+
+    fn redirect_uri(&self) -> String {
+        format!("http://localhost:{}/callback", self.callback_port.unwrap_or(8765))
+    }
+}
+
+/// Tokens of a signed-in user, kept in the OS keyring as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// Unix time the access token stops working.
+    pub expires_at: i64,
+    /// Jira site the tokens are for; API calls go through
+    /// `api.atlassian.com/ex/jira/<cloud_id>`.
+    pub cloud_id: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: i64,
+}
+
+/// Keyring entry of the OAuth session, next to the API token's.
+pub fn account(config: &Config) -> String {
+    format!("oauth:{}", credentials::account(config))
+}
+
+/// Hands out a fresh access token, renewing (and re-saving) the session when
+/// it is about to expire.
+pub struct OAuthClient {
+    config: OAuthConfig,
+    account: String,
+    cloud_id: String,
+    session: Mutex<Session>,
+}
+
+impl OAuthClient {
+    /// `None` when OAuth isn't configured or nobody has signed in yet.
+    pub fn load(config: &Config) -> Option<OAuthClient> {
+        if !config.oauth.enabled() {
+            return None;
+        }
+        let account = account(config);
+        let session: Session = serde_json::from_str(&credentials::get(&account)?).ok()?;
+        let cloud_id = session.cloud_id.clone();
+        Some(OAuthClient { config: config.oauth.clone(), account, cloud_id, session: Mutex::new(session) })
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("https://api.atlassian.com/ex/jira/{}", self.cloud_id)
+    }
+
+    pub async fn access_token(&self, http: &Client) -> Result<String, Box<dyn Error>> {
+        let mut session = self.session.lock().await;
+        if Utc::now().timestamp() >= session.expires_at - REFRESH_MARGIN {
+            let body = json!({
+                "grant_type": "refresh_token",
+                "client_id": self.config.client_id,
+                "client_secret": self.config.client_secret,
+                "refresh_token": session.refresh_token,
+            });
+            let tokens = request_tokens(http, &body)
+                .await
+                .map_err(|e| format!("OAuth oturumu yenilenemedi ({}); `login --oauth` ile tekrar giriş yapın", e))?;
+            session.access_token = tokens.access_token;
+            // Atlassian yenileme token'larını döndürür; eskisi bir daha çalışmaz.
+            if let Some(refresh_token) = tokens.refresh_token {
+                session.refresh_token = refresh_token;
+            }
+            session.expires_at = Utc::now().timestamp() + tokens.expires_in;
+            credentials::set(&self.account, &serde_json::to_string(&*session)?)?;
+        }
+        Ok(session.access_token.clone())
+    }
+}
+
+/// `login --oauth`: opens Atlassian's consent page, waits for the redirect
+/// on localhost, and keeps the resulting tokens in the OS keyring.
+pub async fn login(config: &Config) -> Result<(), Box<dyn Error>> {
+    let oauth = &config.oauth;
+    if !oauth.enabled() {
+        return Err("[oauth] altında client_id ve client_secret tanımlayın (ya da JIRA_OAUTH_CLIENT_ID/SECRET)".into());
+    }
+    // state tahmin edilemesin diye işletim sisteminin rastgele üretecinden gelir.
+    let mut seed = [0u8; 32];
+    getrandom::fill(&mut seed).map_err(|e| format!("Rastgele değer üretilemedi: {}", e))?;
+    let state: String = seed.iter().map(|b| format!("{:02x}", b)).collect();
+    let mut url = Url::parse(AUTHORIZE_URL)?;
+    url.query_pairs_mut()
+        .append_pair("audience", "api.atlassian.com")
+        .append_pair("client_id", &oauth.client_id)
+        .append_pair("scope", SCOPES)
+        .append_pair("redirect_uri", &oauth.redirect_uri())
+        .append_pair("state", &state)
+        .append_pair("response_type", "code")
+        .append_pair("prompt", "consent");
+
+    let listener = TcpListener::bind(("127.0.0.1", oauth.callback_port.unwrap_or(8765))).await?;
+    println!("🌐 Tarayıcıda Atlassian girişi açılıyor; açılmazsa bu adrese gidin:\n   {}", url);
+    open_browser(url.as_str());
+    let code = wait_for_code(&listener, &state).await?;
+
+    let http = crate::jira::http_client(&config.http)?;
+    let tokens = request_tokens(
+        &http,
+        &json!({
+            "grant_type": "authorization_code",
+            "client_id": oauth.client_id,
+            "client_secret": oauth.client_secret,
+            "code": code,
+            "redirect_uri": oauth.redirect_uri(),
+        }),
+    )
+    .await?;
+    let resources: Vec<Value> = http.get(RESOURCES_URL).bearer_auth(&tokens.access_token).send().await?.error_for_status()?.json().await?;
+    let (cloud_id, site) = pick_site(&resources, &config.jira_server)
+        .ok_or_else(|| format!("Bu hesabın erişebildiği siteler arasında {} yok", config.jira_server))?;
+
+    let session = Session {
+        access_token: tokens.access_token,
+        refresh_token: tokens.refresh_token.ok_or("Atlassian yenileme token'ı vermedi (offline_access kapsamı gerekli)")?,
+        expires_at: Utc::now().timestamp() + tokens.expires_in,
+        cloud_id,
+    };
+    credentials::set(&account(config), &serde_json::to_string(&session)?)?;
+    println!("✅ {} için OAuth oturumu açıldı ve anahtar zincirine kaydedildi.", site);
+    Ok(())
+}
+
+async fn request_tokens(http: &Client, body: &Value) -> Result<TokenResponse, Box<dyn Error>> {
+    let resp = http.post(TOKEN_URL).json(body).send().await?;
+    let status = resp.status();
+    if !status.is_success() {
+        return Err(format!("{}: {}", status, resp.text().await.unwrap_or_default()).into());
+    }
+    Ok(resp.json().await?)
+}
+
+fn open_browser(url: &str) {
+    let opened = if cfg!(target_os = "macos") {
+        process::Command::new("open").arg(url).status()
+    } else if cfg!(windows) {
+        process::Command::new("cmd").args(["/C", "start", "", url]).status()
+    } else {
+        process::Command::new("xdg-open").arg(url).status()
+    };
+    if opened.is_err() {
+        println!("⚠️  Tarayıcı açılamadı; adresi elle açın.");
+    }
+}
+
+/// Answers requests on the callback port until Atlassian redirects back with
+/// the code (browsers also ask for things like `/favicon.ico`).
+async fn wait_for_code(listener: &TcpListener, state: &str) -> Result<String, Box<dyn Error>> {
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let mut buf = vec![0; 8192];
+        let n = stream.read(&mut buf).await?;
+        let request = String::from_utf8_lossy(&buf[..n]);
+        let result = parse_callback(request.lines().next().unwrap_or_default(), state);
+        let page = match &result {
+            Some(Ok(_)) => "Giriş tamamlandı, bu sekmeyi kapatabilirsiniz.",
+            Some(Err(_)) => "Giriş başarısız oldu, terminale bakın.",
+            None => "",
+        };
+        let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nConnection: close\r\n\r\n<p>{}</p>", page);
+        stream.write_all(response.as_bytes()).await?;
+        if let Some(result) = result {
+            return result.map_err(Into::into);
+        }
+    }
+}
+
+/// `None` for requests other than the callback.
+fn parse_callback(request_line: &str, state: &str) -> Option<Result<String, String>> {
+    let target = request_line.split_whitespace().nth(1)?;
+    let url = Url::parse(&format!("http://localhost{}", target)).ok()?;
+    if url.path() != "/callback" {
+        return None;
+    }
+    let param = |name: &str| url.query_pairs().find(|(k, _)| k == name).map(|(_, v)| v.into_owned());
+    if let Some(error) = param("error") {
+        return Some(Err(format!("Atlassian girişi reddetti: {}", param("error_description").unwrap_or(error))));
+    }
+    if param("state").as_deref() != Some(state) {
+        return Some(Err("Geri dönüşteki state değeri eşleşmiyor".to_string()));
+    }
+    Some(param("code").ok_or_else(|| "Geri dönüşte kod yok".to_string()))
+}
+
+/// The cloud id and URL of the accessible site matching `server`; with no
+/// server configured, the only site the account can reach.
+fn pick_site(resources: &[Value], server: &str) -> Option<(String, String)> {
+    let sites: Vec<(String, String)> = resources
+        .iter()
+        .filter_map(|r| Some((r["id"].as_str()?.to_string(), r["url"].as_str()?.to_string())))
+        .collect();
+    if server.is_empty() && sites.len() == 1 {
+        return sites.into_iter().next();
+    }
+    let host = |url: &str| url.split("://").last().unwrap_or(url).trim_end_matches('/').to_lowercase();
+    sites.into_iter().find(|(_, url)| host(url) == host(server))
+}
+
+#[cfg(test)]
+#[test]
+fn test_oauth_callback_and_site() {
+    assert_eq!(parse_callback("GET /callback?code=abc&state=s1 HTTP/1.1", "s1"), Some(Ok("abc".to_string())));
+    assert!(matches!(parse_callback("GET /callback?code=abc&state=evil HTTP/1.1", "s1"), Some(Err(_))));
+    assert!(matches!(parse_callback("GET /callback?error=access_denied&state=s1 HTTP/1.1", "s1"), Some(Err(_))));
+    assert_eq!(parse_callback("GET /favicon.ico HTTP/1.1", "s1"), None);
+
+    let resources = vec![
+        json!({ "id": "c-1", "url": "https://company.atlassian.net", "name": "company" }),
+        json!({ "id": "c-2", "url": "https://other.atlassian.net", "name": "other" }),
+    ];
+    assert_eq!(pick_site(&resources, "Other.atlassian.net").map(|(id, _)| id).as_deref(), Some("c-2"));
+    assert_eq!(pick_site(&resources, ""), None);
+    assert_eq!(pick_site(&resources[..1], "").map(|(id, _)| id).as_deref(), Some("c-1"));
+}
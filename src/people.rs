@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::jira::JiraClient;
+use crate::state::State;
+use tracing::{debug, warn};
+
+const PEOPLE_FILE: &str = "people.json";
+/// Entries older than this are looked up again.
+const REFRESH_AFTER_DAYS: i64 = 30;
+/// Upper bound on people looked up per sync, to stay well inside rate limits.
+const MAX_LOOKUPS: usize = 200;
+/// accountIds asked for in one `/user/bulk` request.
+const BULK_LOOKUP_SIZE: usize = 50;
+/// Changelog fields whose `from`/`to` are accountIds.
+pub const USER_FIELDS: [&str; 2] = ["assignee", "reporter"];
+
+/// Cached accountId -> display name, built from everyone who shows up in the
+/// synced issues (assignees, reporters, mentions, comment authors, people in
+/// the changelog); ids Jira gives without a name are looked up. Names from
+/// the config's `[people]` table win, so a person reads the same everywhere.
+#[derive(Debug, Default)]
+pub struct Directory {
+    entries: BTreeMap<String, Person>,
+    /// accountId or Jira display name -> name to use instead.
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Person {
+    #[serde(default)]
+    pub display_name: String,
+    /// Day the name was last confirmed by Jira (`YYYY-MM-DD`).
+    #[serde(default)]
+    pub seen: String,
+}
+
+impl Directory {
+    pub fn load(vault_path: &str, overrides: &HashMap<String, String>) -> Result<Directory, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(PEOPLE_FILE);
+        let entries = if path.exists() { serde_json::from_str(&fs::read_to_string(path)?)? } else { BTreeMap::new() };
+        Ok(Directory { entries, overrides: overrides.clone() })
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(PEOPLE_FILE), serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+
+    /// The name to show for `account_id`: override, cached name, or the id itself.
+    pub fn name(&self, account_id: &str) -> String {
+        if let Some(name) = self.overrides.get(account_id) {
+            return name.clone();
+        }
+        match self.entries.get(account_id).filter(|p| !p.display_name.is_empty()) {
+            Some(person) => self.overrides.get(&person.display_name).unwrap_or(&person.display_name).clone(),
+            None => account_id.to_string(),
+        }
+    }
+
+    /// The id of the person called `name` (case-insensitive): their id, Jira
+    /// display name or overridden name.
+    pub fn find(&self, name: &str) -> Option<String> {
+        let overridden = self.overrides.iter().filter(|(_, n)| n.eq_ignore_ascii_case(name)).map(|(from, _)| from.as_str());
+        let names: Vec<&str> = overridden.chain([name]).collect();
+        self.entries
+            .iter()
+            .find(|(id, p)| names.iter().any(|n| id.eq_ignore_ascii_case(n) || p.display_name.eq_ignore_ascii_case(n)))
+            .map(|(id, _)| id.clone())
+    }
+
+    /// Records every user object and mention found anywhere in a raw issue.
+    pub fn collect(&mut self, value: &Value, today: NaiveDate) {
+        match value {
+            Value::Object(map) => {
+                let id = map.get("accountId").or_else(|| map.get("name")).and_then(Value::as_str);
+                if let (Some(id), Some(name)) = (id, map.get("displayName").and_then(Value::as_str)) {
+                    self.record(id, name, today);
+                }
+                if map.get("type").and_then(Value::as_str) == Some("mention") {
+                    let attrs = &map["attrs"];
+                    if let Some(id) = attrs["id"].as_str() {
+                        // Bahsetme metni eski olabilir; sadece bilinmeyen kişiler için kullan.
+                        self.note(id, attrs["text"].as_str().unwrap_or_default().trim_start_matches('@'));
+                    }
+                }
+                // Değişiklik geçmişinde atanan/raporlayan yalnızca kimlik ve o günkü adıyla gelir.
+                if map.get("field").and_then(Value::as_str).is_some_and(|field| USER_FIELDS.contains(&field)) {
+                    for (id, text) in [("from", "fromString"), ("to", "toString")] {
+                        if let Some(id) = map.get(id).and_then(Value::as_str) {
+                            self.note(id, map.get(text).and_then(Value::as_str).unwrap_or_default());
+                        }
+                    }
+                }
+                map.values().for_each(|v| self.collect(v, today));
+            }
+            Value::Array(items) => items.iter().for_each(|v| self.collect(v, today)),
+            _ => {}
+        }
+    }
+
+    /// Remembers a person seen only by id, with whatever name came along, until
+    /// Jira confirms it.
+    fn note(&mut self, id: &str, name: &str) {
+        self.entries.entry(id.to_string()).or_insert(Person { display_name: name.to_string(), seen: String::new() });
+    }
+
+    fn record(&mut self, id: &str, display_name: &str, today: NaiveDate) {
+        self.entries.insert(
+            id.to_string(),
+            Person { display_name: display_name.to_string(), seen: today.format("%Y-%m-%d").to_string() },
+        );
+    }
+
+    /// Looks up people whose name is unknown or stale, in batches of
+    /// `/user/bulk`, falling back to one `/user` request each where that fails.
+    pub async fn refresh(&mut self, client: &JiraClient, today: NaiveDate) {
+        let stale = self.stale(today);
+        for batch in stale.chunks(BULK_LOOKUP_SIZE) {
+            let ids: Vec<String> = batch.iter().map(|id| format!("accountId={}", id)).collect();
+            let path = format!("/rest/api/3/user/bulk?maxResults={}&{}", batch.len(), ids.join("&"));
+            match client.get_json::<Value>(&path).await {
+                Ok(page) => {
+                    for user in page["values"].as_array().into_iter().flatten() {
+                        if let (Some(id), Some(name)) = (user["accountId"].as_str(), user["displayName"].as_str()) {
+                            self.record(id, name, today);
+                        }
+                    }
+                }
+                Err(e) => {
+                    debug!("Toplu kişi sorgusu başarısız, tek tek soruluyor: {}", e);
+                    for id in batch {
+                        self.lookup(client, id, today).await;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn lookup(&mut self, client: &JiraClient, id: &str, today: NaiveDate) {
+        match client.get_json::<Value>(&format!("/rest/api/3/user?accountId={}", id)).await {
+            Ok(user) => {
+                if let Some(name) = user["displayName"].as_str() {
+                    self.record(id, name, today);
+                }
+            }
+            Err(e) => warn!("⚠️  Kişi {} okunamadı: {}", id, e),
+        }
+    }
+
+    /// Ids to look up this sync: never confirmed ones first (they may show as
+    /// a bare id), then those not confirmed for `REFRESH_AFTER_DAYS`.
+    fn stale(&self, today: NaiveDate) -> Vec<String> {
+        let mut stale: Vec<(&String, &Person)> = self
+            .entries
+            .iter()
+            .filter(|(_, p)| {
+                NaiveDate::parse_from_str(&p.seen, "%Y-%m-%d")
+                    .map_or(true, |seen| today - seen > Duration::days(REFRESH_AFTER_DAYS))
+            })
+            .collect();
+        stale.sort_by_key(|(_, p)| !p.seen.is_empty());
+        stale.into_iter().map(|(id, _)| id.clone()).take(MAX_LOOKUPS).collect()
+    }
+
+    /// Points the text of every mention node at the directory's name.
+    pub fn rewrite_mentions(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                if map.get("type").and_then(Value::as_str) == Some("mention") {
+                    if let Some(id) = map["attrs"]["id"].as_str().map(str::to_string) {
+                        map["attrs"]["text"] = Value::String(format!("@{}", self.name(&id)));
+                    }
+                }
+                map.values_mut().for_each(|v| self.rewrite_mentions(v));
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| self.rewrite_mentions(v)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_directory_names() {
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let overrides: HashMap<String, String> = [("Ayşe Yılmaz".to_string(), "Ayşe".to_string())].into();
+    let mut directory = Directory { overrides, ..Default::default() };
+    let mut issue = serde_json::json!({
+        "fields": {
+            "assignee": { "accountId": "a1", "displayName": "Ayşe Yılmaz" },
+            "reporter": { "accountId": "b2", "displayName": "Bora Kaya" },
+            "description": { "type": "doc", "content": [{ "type": "paragraph", "content": [
+                { "type": "mention", "attrs": { "id": "b2", "text": "@Bora K." } },
+                { "type": "mention", "attrs": { "id": "c3", "text": "@Cem" } }
+            ]}]}
+        },
+        "changelog": { "histories": [{ "items": [
+            { "field": "assignee", "from": "a1", "fromString": "Ayşe Y.", "to": "d4", "toString": "Deniz Ak" },
+            { "field": "status", "from": "1", "fromString": "To Do", "to": "3", "toString": "Done" }
+        ]}]}
+    });
+    directory.collect(&issue, today);
+
+    assert_eq!(directory.name("a1"), "Ayşe");
+    assert_eq!(directory.name("b2"), "Bora Kaya");
+    assert_eq!(directory.name("c3"), "Cem");
+    assert_eq!(directory.name("d4"), "Deniz Ak");
+    assert!(directory.find("To Do").is_none());
+    assert_eq!(directory.name("zz"), "zz");
+    directory.entries.insert("a0".into(), Person { display_name: "Eski".into(), seen: "2025-01-01".into() });
+    assert_eq!(directory.stale(today), ["c3", "d4", "a0"]);
+    assert_eq!(directory.find("ayşe").as_deref(), Some("a1"));
+    assert_eq!(directory.find("bora kaya").as_deref(), Some("b2"));
+
+    directory.rewrite_mentions(&mut issue);
+    assert_eq!(issue["fields"]["description"]["content"][0]["content"][0]["attrs"]["text"], "@Bora Kaya");
+}
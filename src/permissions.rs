@@ -0,0 +1,73 @@
+use std::collections::{BTreeSet, HashMap};
+use serde::Deserialize;
+use crate::config::{Capability, Config};
+use crate::jira::JiraClient;
+use tracing::{info, warn};
+
+/// Jira permission keys behind what write-back can do, with the name we print
+/// and the setting that enables it here (`bulk assign` counts as an edit).
+const CHECKED: [(&str, &str, Capability); 4] = [
+    ("TRANSITION_ISSUES", "geçiş", Capability::Transitions),
+    ("ADD_COMMENTS", "yorum", Capability::Comments),
+    ("EDIT_ISSUES", "düzenleme", Capability::Edits),
+    ("ASSIGN_ISSUES", "atama", Capability::Edits),
+];
+
+#[derive(Deserialize)]
+struct MyPermissions {
+    permissions: HashMap<String, Permission>,
+}
+
+#[derive(Deserialize)]
+struct Permission {
+    #[serde(rename = "havePermission")]
+    have_permission: bool,
+}
+
+/// Asks Jira which of the write permissions the token has in each project and
+/// prints them, warning where a capability is enabled in the config but the
+/// token lacks the permission. Never fails the run: a failed check is reported.
+pub async fn preflight(client: &JiraClient, config: &Config, projects: &BTreeSet<String>) {
+    let keys: Vec<&str> = CHECKED.iter().map(|(key, _, _)| *key).collect();
+    for project in projects {
+        let path = format!("/rest/api/3/mypermissions?projectKey={}&permissions={}", project, keys.join(","));
+        match client.get_json::<MyPermissions>(&path).await {
+            Ok(response) => {
+                let granted = granted(&response);
+                let line: Vec<String> = CHECKED
+                    .iter()
+                    .map(|(key, name, _)| format!("{} {}", name, if granted.contains(key) { "✅" } else { "❌" }))
+                    .collect();
+                info!("🔐 {}: {}", project, line.join("  "));
+                for (key, name, capability) in CHECKED {
+                    if config.allows(capability) && !granted.contains(&key) {
+                        warn!("⚠️  {} izni açık ama token'ın {} projesinde {} yetkisi yok; bu değişiklikler başarısız olacak", name, project, key);
+                    }
+                }
+            }
+            Err(e) => warn!("⚠️  {}: izinler okunamadı: {}", project, e),
+        }
+    }
+}
+
+fn granted(response: &MyPermissions) -> Vec<&'static str> {
+    CHECKED
+        .iter()
+        .map(|(key, _, _)| *key)
+        .filter(|key| response.permissions.get(*key).is_some_and(|p| p.have_permission))
+        .collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_granted_permissions() {
+    let response: MyPermissions = serde_json::from_value(serde_json::json!({
+        "permissions": {
+            "TRANSITION_ISSUES": { "key": "TRANSITION_ISSUES", "havePermission": true },
+            "ADD_COMMENTS": { "key": "ADD_COMMENTS", "havePermission": false },
+            "EDIT_ISSUES": { "key": "EDIT_ISSUES", "havePermission": true }
+        }
+    }))
+    .unwrap();
+    assert_eq!(granted(&response), ["TRANSITION_ISSUES", "EDIT_ISSUES"]);
+}
@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::jira::JiraClient;
+use crate::layout::sanitize;
+use crate::vault::markdown_files;
+use crate::{with_safe_zone, Issue, NoteContext, User};
+use tracing::{info, warn};
+
+/// `[person_notes]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PersonNotesConfig {
+    pub enabled: bool,
+    /// Vault folder for the notes (default "People").
+    pub folder: Option<String>,
+    /// Download each assignee's avatar into `<folder>/avatars` and embed it.
+    pub avatars: bool,
+}
+
+impl PersonNotesConfig {
+    pub fn folder(&self) -> &str {
+        self.folder.as_deref().unwrap_or("People")
+    }
+}
+
+/// One note per assignee listing their synced issues. The part below the
+/// safe-zone line is the user's and survives regeneration.
+pub async fn write(
+    ctx: &NoteContext<'_>,
+    client: &JiraClient,
+    issues: &[Issue],
+    note_links: &HashMap<String, String>,
+) -> Result<(), Box<dyn Error>> {
+    let settings = &ctx.config.person_notes;
+    let folder = Path::new(&ctx.config.vault_path).join(settings.folder());
+
+    let mut by_person: BTreeMap<String, (&User, Vec<&Issue>)> = BTreeMap::new();
+    for issue in issues {
+        if let Some(user) = &issue.fields.assignee {
+            by_person.entry(user.id().to_string()).or_insert((user, Vec::new())).1.push(issue);
+        }
+    }
+
+    // Var olan notlar hesap kimliğiyle bulunur: ad değişse de kişinin kendi notları taşınır.
+    // Hepsi baştan okunur ki yer değiştiren iki kişi birbirinin notunu ezmesin.
+    let existing: HashMap<String, (PathBuf, String)> = markdown_files(&folder)
+        .into_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let id = content.lines().find_map(|l| l.strip_prefix("jira_account: "))?.trim().to_string();
+            Some((id, (path, content)))
+        })
+        .collect();
+    let people: Vec<(&str, String)> = by_person.keys().map(|id| (id.as_str(), ctx.people.name(id))).collect();
+    let owners = existing
+        .iter()
+        .filter_map(|(id, (path, _))| Some((path.file_stem()?.to_string_lossy().into_owned(), id.clone())))
+        .collect();
+    let file_names = file_names(&people, &owners);
+    let paths: HashMap<&str, PathBuf> = file_names.iter().map(|(id, name)| (*id, folder.join(format!("{}.md", name)))).collect();
+
+    for (id, name) in &people {
+        let (user, assigned) = &by_person[*id];
+        let avatar = match (settings.avatars, user.avatar_urls.get("48x48")) {
+            (true, Some(url)) if !ctx.config.dry_run => download_avatar(client, url, &folder.join("avatars"), &sanitize(id)).await,
+            _ => None,
+        };
+
+        let mut body = format!("---\njira_account: {}\ntags: [jira, person]\n---\n# {}\n\n", id, name);
+        if let Some(avatar) = avatar {
+            body.push_str(&format!("![[{}/avatars/{}|64]]\n\n", settings.folder(), avatar));
+        }
+        body.push_str(&format!("## {}\n", ctx.labels.assigned_issues));
+        for issue in assigned {
+            let target = note_links.get(&issue.key).unwrap_or(&issue.key);
+            let link = if *target == issue.key { format!("[[{}]]", target) } else { format!("[[{}|{}]]", target, issue.key) };
+            body.push_str(&format!("- {} {} (`{}`)\n", link, issue.fields.summary, issue.fields.status.name));
+        }
+
+        let path = &paths[id];
+        let previous = existing.get(*id);
+        ctx.writer.write(path, &with_safe_zone(&body, previous.map(|(_, content)| content.as_str())))?;
+        if let Some((old, _)) = previous.filter(|(old, _)| old != path && !paths.values().any(|p| p == old)) {
+            info!("👤 {} → {}", old.display(), path.display());
+            if !ctx.config.dry_run {
+                fs::remove_file(old)?;
+            }
+        }
+    }
+    info!("👥 {} kişi notu güncellendi.", by_person.len());
+    Ok(())
+}
+
+/// Note file names: the display name, with the end of the account id added
+/// when someone else has the same name, in this sync or in an existing note
+/// of a person who isn't.
+fn file_names<'a>(people: &[(&'a str, String)], owners: &HashMap<String, String>) -> HashMap<&'a str, String> {
+    people
+        .iter()
+        .map(|(id, name)| {
+            let base = sanitize(name);
+            let shared = people.iter().any(|(other, name)| other != id && sanitize(name) == base)
+                || owners.get(&base).is_some_and(|owner| owner != id && !people.iter().any(|(p, _)| p == owner));
+            if !shared {
+                return (*id, base);
+            }
+            let suffix: Vec<char> = id.chars().filter(char::is_ascii_alphanumeric).collect();
+            let suffix: String = suffix[suffix.len().saturating_sub(6)..].iter().collect();
+            (*id, format!("{} ({})", base, suffix))
+        })
+        .collect()
+}
+
+/// File extensions avatars are saved with.
+const AVATAR_EXTENSIONS: [&str; 5] = ["png", "jpg", "gif", "webp", "svg"];
+
+/// Saves the avatar once and returns its file name; a failure only costs the picture.
+async fn download_avatar(client: &JiraClient, url: &str, dir: &Path, name: &str) -> Option<String> {
+    for ext in AVATAR_EXTENSIONS {
+        let file = format!("{}.{}", name, ext);
+        if dir.join(&file).exists() {
+            return Some(file);
+        }
+    }
+    match client.download(url).await {
+        Ok((bytes, content_type)) => {
+            let ext = match content_type.split(';').next().unwrap_or_default().trim() {
+                "image/svg+xml" => "svg",
+                "image/jpeg" | "image/jpg" => "jpg",
+                "image/gif" => "gif",
+                "image/webp" => "webp",
+                _ => "png",
+            };
+            let file = format!("{}.{}", name, ext);
+            let written = fs::create_dir_all(dir).and_then(|_| fs::write(dir.join(&file), bytes));
+            match written {
+                Ok(()) => Some(file),
+                Err(e) => {
+                    warn!("⚠️  {} avatarı yazılamadı: {}", name, e);
+                    None
+                }
+            }
+        }
+        Err(e) => {
+            warn!("⚠️  {} avatarı indirilemedi: {}", name, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_person_file_names() {
+    let people = vec![("5b10ac8d82e05b22cc7d4ef5", "Ali Veli".to_string()), ("557058:f58131cb", "Ali Veli".to_string()), ("u3", "Bora".to_string()), ("u4", "Can".to_string())];
+    // "Bora.md" eski bir kişiye, "Can.md" adı değişmiş ve bu senkronda yeniden adlandırılacak birine ait.
+    let owners = HashMap::from([("Bora".to_string(), "old".to_string()), ("Can".to_string(), "u3".to_string())]);
+    let names = file_names(&people, &owners);
+    assert_eq!(names["5b10ac8d82e05b22cc7d4ef5"], "Ali Veli (7d4ef5)");
+    assert_eq!(names["557058:f58131cb"], "Ali Veli (8131cb)");
+    assert_eq!(names["u3"], "Bora (u3)");
+    assert_eq!(names["u4"], "Can");
+}
@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::io::{self, ErrorKind, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use serde::Deserialize;
+
+/// Seconds a field renderer may run before it is killed, unless configured.
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// An external program that turns one field's JSON into Markdown.
+///
+/// ```toml
+/// [[field_renderers]]
+/// field = "customfield_10050"
+/// title = "Region"
+/// command = "python3 ~/bin/render_cascade.py"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct FieldRenderer {
+    pub field: String,
+    /// Section heading in the note; the field id when omitted.
+    #[serde(default)]
+    pub title: Option<String>,
+    pub command: String,
+    /// Seconds before the command is killed (default 30).
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+}
+
+impl FieldRenderer {
+    pub fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.field)
+    }
+
+    /// Runs the command through the shell with the field JSON on stdin and
+    /// returns its stdout. Input is written and output read at the same time,
+    /// so a streaming filter can't fill a pipe and hang; a command that stops
+    /// reading early is fine. One that runs past its timeout is killed.
+    pub fn render(&self, value: &serde_json::Value) -> Result<String, Box<dyn Error>> {
+        let mut child = shell(&self.command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let input = serde_json::to_vec(value)?;
+        let stdin = child.stdin.take().map(|mut stdin| {
+            thread::spawn(move || match stdin.write_all(&input) {
+                // Girdinin hepsini okumayan komut da geçerli.
+                Err(e) if e.kind() != ErrorKind::BrokenPipe => Err(e),
+                _ => Ok(()),
+            })
+        });
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+
+        let deadline = Instant::now() + Duration::from_secs(self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                // Öldürülen komutun boruları kapanır, okuyan iş parçacıkları da biter.
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("`{}` {} saniyede bitmedi", self.command, self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS)).into());
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        if let Some(stdin) = stdin {
+            stdin.join().map_err(|_| "stdin yazılamadı")??;
+        }
+        let stdout = stdout.join().map_err(|_| "stdout okunamadı")??;
+        let stderr = stderr.join().map_err(|_| "stderr okunamadı")??;
+        if !status.success() {
+            let stderr = String::from_utf8_lossy(&stderr);
+            return Err(format!("`{}` {} ile bitti: {}", self.command, status, stderr.trim()).into());
+        }
+        Ok(String::from_utf8(stdout)?)
+    }
+}
+
+/// Reads a child's output pipe to the end on its own thread.
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut out = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut out)?;
+        }
+        Ok(out)
+    })
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+#[cfg(all(test, unix))]
+#[test]
+fn test_render_with_external_command() {
+    let renderer = FieldRenderer {
+        field: "customfield_1".to_string(),
+        title: None,
+        command: "tr -d '\"'".to_string(),
+        timeout_secs: None,
+    };
+    let output = renderer.render(&serde_json::json!("EMEA")).unwrap();
+    assert_eq!(output, "EMEA");
+    assert_eq!(renderer.title(), "customfield_1");
+
+    // Boru tamponundan büyük bir alan akış halinde süzülürken takılmamalı.
+    let large = "x".repeat(256 * 1024);
+    assert_eq!(renderer.render(&serde_json::json!(large)).unwrap(), large);
+    let ignores_input = FieldRenderer { command: "echo done".to_string(), ..renderer.clone() };
+    assert_eq!(ignores_input.render(&serde_json::json!(large)).unwrap(), "done\n");
+    let slow = FieldRenderer { command: "sleep 5".to_string(), timeout_secs: Some(1), ..renderer };
+    assert!(slow.render(&serde_json::json!("EMEA")).unwrap_err().to_string().contains("1 saniyede bitmedi"));
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use crate::layout::{folder_path, sanitize, NoteVars};
+use crate::{with_safe_zone, Issue, NoteContext};
+use tracing::info;
+
+/// `[project_notes]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProjectNotesConfig {
+    pub enabled: bool,
+    /// Vault folder for the notes, a template like `folder_template`, e.g.
+    /// `Jira/{project_name}` to keep each next to its tickets (default "Projects").
+    pub folder: Option<String>,
+}
+
+impl ProjectNotesConfig {
+    pub fn folder(&self) -> &str {
+        self.folder.as_deref().unwrap_or("Projects")
+    }
+}
+
+/// One index note per project of the synced issues, named after the project
+/// and listing its issues grouped by status. The part below the safe-zone
+/// line is the user's and survives regeneration.
+pub fn write(ctx: &NoteContext, issues: &[Issue], note_links: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+    let mut projects: BTreeMap<String, (NoteVars, Vec<&Issue>)> = BTreeMap::new();
+    for issue in issues {
+        let vars = NoteVars::from_issue(issue);
+        projects.entry(vars.project().to_string()).or_insert((vars, Vec::new())).1.push(issue);
+    }
+
+    let vault = Path::new(&ctx.config.vault_path);
+    for (vars, issues) in projects.values() {
+        let folder = vault.join(folder_path(ctx.config.project_notes.folder(), vars));
+        let path = folder.join(format!("{}.md", sanitize(vars.project_name())));
+        let note = render(ctx, vars, issues, note_links);
+        ctx.writer.write(&path, &with_safe_zone(&note, fs::read_to_string(&path).ok().as_deref()))?;
+    }
+    info!("🗂️  {} proje notu güncellendi.", projects.len());
+    Ok(())
+}
+
+fn render(ctx: &NoteContext, vars: &NoteVars, issues: &[&Issue], note_links: &HashMap<String, String>) -> String {
+    let mut note = format!(
+        "---\njira_project: {}\njira_project_name: \"{}\"\njira_link: {}/browse/{}\ntags: [jira, project]\n---\n# {}\n\n",
+        vars.project(),
+        vars.project_name().replace('"', "'"),
+        ctx.config.base_url(),
+        vars.project(),
+        vars.project_name()
+    );
+    let done = issues.iter().filter(|i| i.fields.status.status_category.as_ref().is_some_and(|c| c.key == "done")).count();
+    note.push_str(&format!("> **{}:** {}/{}\n", ctx.labels.completed, done, issues.len()));
+
+    // Epik notlarındaki gibi: önce yapılacaklar, sonra devam edenler, en son bitenler.
+    let mut by_status: BTreeMap<(u8, &str), Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        let rank = match issue.fields.status.status_category.as_ref().map(|c| c.key.as_str()) {
+            Some("new") => 0,
+            Some("done") => 2,
+            _ => 1,
+        };
+        by_status.entry((rank, issue.fields.status.name.as_str())).or_default().push(issue);
+    }
+    for ((_, status), issues) in by_status {
+        note.push_str(&format!("\n## {} ({})\n", status, issues.len()));
+        for issue in issues {
+            let link = match note_links.get(&issue.key) {
+                Some(target) if *target != issue.key => format!("[[{}|{}]]", target, issue.key),
+                _ => format!("[[{}]]", issue.key),
+            };
+            note.push_str(&format!("- {} {}\n", link, issue.fields.summary));
+        }
+    }
+    note
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use chrono::{DateTime, Duration, FixedOffset, Local};
+use serde_json::Value;
+use tracing::info;
+use crate::bulk::confirm;
+use crate::config::Config;
+use crate::jira::JiraClient;
+use crate::metrics::parse_time;
+use crate::state::{NoteState, State};
+
+/// `prune`: lists managed notes whose issue none of the queries return any
+/// more (closed, reassigned, moved) or, with `resolved_days`, was resolved
+/// longer ago than that, and, when asked, moves them under `archive` or
+/// deletes them. Either way they are dropped from the state.
+pub async fn run(config: &Config, archive: Option<&str>, delete: bool, resolved_days: Option<i64>, yes: bool) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let Some(fetched) = crate::fetch(config, &client).await? else {
+        return Err("Sorgular çalıştırılamadı; hiçbir not budanmadı".into());
+    };
+    let resolved_before = resolved_days.map(|days| Local::now().fixed_offset() - Duration::days(days));
+    let current = current_keys(&fetched.issues, resolved_before);
+    let targets = std::iter::once(config.clone()).chain(config.vaults.iter().map(|v| config.for_vault(v)));
+    for config in targets {
+        prune_vault(&config, &current, archive, delete, yes)?;
+    }
+    Ok(())
+}
+
+fn prune_vault(config: &Config, current: &HashSet<String>, archive: Option<&str>, delete: bool, yes: bool) -> Result<(), Box<dyn Error>> {
+    let vault = Path::new(&config.vault_path);
+    let mut state = State::load(&config.vault_path)?;
+    let stale = stale_notes(&state, current);
+    if stale.is_empty() {
+        info!("✨ {}: budanacak not yok", vault.display());
+        return Ok(());
+    }
+    println!("🍂 {} içinde budanacak {} not:", vault.display(), stale.len());
+    for (key, note) in &stale {
+        println!("   {}\t{}", key, note.path);
+    }
+    if config.dry_run || (archive.is_none() && !delete) {
+        return Ok(());
+    }
+    let action = if delete { "silinsin" } else { "arşive taşınsın" };
+    if !yes && !confirm(&format!("{} not {} mi?", stale.len(), action))? {
+        return Ok(());
+    }
+
+    for (key, note) in &stale {
+        let path = vault.join(&note.path);
+        if path.exists() {
+            match archive {
+                Some(folder) => {
+                    let destination = vault.join(folder).join(&note.path);
+                    if let Some(parent) = destination.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::rename(&path, &destination)?;
+                }
+                None => fs::remove_file(&path)?,
+            }
+        }
+        state.notes.remove(key);
+    }
+    state.save(&config.vault_path)?;
+    match archive {
+        Some(folder) => info!("🗄️  {} not {} klasörüne taşındı", stale.len(), folder),
+        None => info!("🗑️  {} not silindi", stale.len()),
+    }
+    Ok(())
+}
+
+/// Keys of the fetched issues whose notes stay: all of them, except those
+/// resolved before `resolved_before`.
+fn current_keys(issues: &[Value], resolved_before: Option<DateTime<FixedOffset>>) -> HashSet<String> {
+    issues
+        .iter()
+        .filter(|raw| {
+            let resolved = raw["fields"]["resolutiondate"].as_str().and_then(parse_time);
+            !matches!((resolved, resolved_before), (Some(resolved), Some(before)) if resolved < before)
+        })
+        .filter_map(|raw| raw["key"].as_str())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Managed notes whose key isn't among `current`.
+fn stale_notes(state: &State, current: &HashSet<String>) -> Vec<(String, NoteState)> {
+    state.notes.iter().filter(|(key, _)| !current.contains(*key)).map(|(key, note)| (key.clone(), note.clone())).collect()
+}
+
+#[cfg(test)]
+#[test]
+fn test_stale_notes() {
+    let note = |path: &str| NoteState { path: path.into(), vars: Default::default(), labels: Vec::new(), updated: String::new() };
+    let mut state = State::default();
+    state.notes.insert("A-1".into(), note("A/A-1.md"));
+    state.notes.insert("A-2".into(), note("A/A-2.md"));
+    state.notes.insert("B-7".into(), note("B/B-7.md"));
+    let current: HashSet<String> = ["A-2".to_string()].into();
+
+    let stale: Vec<(String, String)> = stale_notes(&state, &current).into_iter().map(|(k, n)| (k, n.path)).collect();
+    assert_eq!(stale, [("A-1".to_string(), "A/A-1.md".to_string()), ("B-7".to_string(), "B/B-7.md".to_string())]);
+
+    let issues = [
+        serde_json::json!({ "key": "A-1", "fields": { "resolutiondate": null } }),
+        serde_json::json!({ "key": "A-2", "fields": { "resolutiondate": "2025-05-01T10:00:00.000+0000" } }),
+        serde_json::json!({ "key": "A-3", "fields": { "resolutiondate": "2025-05-28T10:00:00.000+0000" } }),
+    ];
+    let before = DateTime::parse_from_rfc3339("2025-05-15T00:00:00+00:00").ok();
+    assert_eq!(current_keys(&issues, before), HashSet::from(["A-1".to_string(), "A-3".to_string()]));
+    assert_eq!(current_keys(&issues, None).len(), 3);
+}
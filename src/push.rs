@@ -0,0 +1,514 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use crate::config::{Capability, Config};
+use crate::editmeta;
+use crate::frontmatter;
+use crate::i18n::Labels;
+use crate::jira::{self, JiraClient};
+use crate::permissions;
+use crate::queue::RetryQueue;
+use crate::state::State;
+use crate::transitions::{self, TransitionCache};
+use crate::vault::VaultWriter;
+use tracing::{error, info, warn};
+
+/// Fence of an outbox comment in a note; once posted it is renamed to `SENT_FENCE`.
+const OUTBOX_FENCE: &str = "```jira-comment\n";
+const SENT_FENCE: &str = "```jira-comment-sent\n";
+
+/// One change we want to make in Jira, derived from a local note edit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Mutation {
+    /// `jira_status` was edited: move the issue through its workflow.
+    Transition { key: String, from: String, to: String },
+    /// A ```` ```jira-comment ```` block was written in the note: post it.
+    Comment { key: String, body: String },
+    /// A field shown in the frontmatter (e.g. `jira_priority`) was edited.
+    Edit { key: String, field: String, from: String, to: String },
+    /// Assign the issue (`bulk assign`); no `account_id` unassigns it.
+    Assign { key: String, from: String, to: String, account_id: Option<String> },
+    /// Add and remove labels (`bulk label`).
+    Labels { key: String, add: Vec<String>, remove: Vec<String> },
+}
+
+impl Mutation {
+    pub fn key(&self) -> &str {
+        match self {
+            Mutation::Transition { key, .. }
+            | Mutation::Comment { key, .. }
+            | Mutation::Edit { key, .. }
+            | Mutation::Assign { key, .. }
+            | Mutation::Labels { key, .. } => key,
+        }
+    }
+
+    pub fn project(&self) -> &str {
+        let key = self.key();
+        key.split_once('-').map(|(p, _)| p).unwrap_or(key)
+    }
+
+    /// Whether this mutation makes a queued `other` obsolete: a newer status
+    /// edit replaces an older one, comments are all sent.
+    pub fn supersedes(&self, other: &Mutation) -> bool {
+        match (self, other) {
+            (Mutation::Transition { key, .. }, Mutation::Transition { key: other_key, .. }) => {
+                key == other_key && self != other
+            }
+            (Mutation::Edit { key, field, .. }, Mutation::Edit { key: other_key, field: other_field, .. }) => {
+                key == other_key && field == other_field && self != other
+            }
+            (Mutation::Assign { key, .. }, Mutation::Assign { key: other_key, .. }) => key == other_key && self != other,
+            _ => false,
+        }
+    }
+
+    pub fn capability(&self) -> Capability {
+        match self {
+            Mutation::Transition { .. } => Capability::Transitions,
+            Mutation::Comment { .. } => Capability::Comments,
+            Mutation::Edit { .. } | Mutation::Assign { .. } | Mutation::Labels { .. } => Capability::Edits,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Mutation::Transition { key, from, to } => format!("{}: durum '{}' -> '{}'", key, from, to),
+            Mutation::Comment { key, body } => {
+                let preview: String = body.lines().next().unwrap_or_default().chars().take(40).collect();
+                format!("{}: yorum \"{}\"", key, preview)
+            }
+            Mutation::Edit { key, field, from, to } => format!("{}: {} '{}' -> '{}'", key, field, from, to),
+            Mutation::Assign { key, from, to, .. } => format!("{}: atanan '{}' -> '{}'", key, from, to),
+            Mutation::Labels { key, add, remove } => {
+                let changes: Vec<String> = add.iter().map(|l| format!("+{}", l)).chain(remove.iter().map(|l| format!("-{}", l))).collect();
+                format!("{}: etiketler {}", key, changes.join(" "))
+            }
+        }
+    }
+}
+
+/// Answer to the per-mutation prompt of `push --confirm`.
+#[derive(Debug, PartialEq)]
+enum Answer {
+    Yes,
+    No,
+    All,
+    SkipRest,
+}
+
+fn parse_answer(input: &str) -> Option<Answer> {
+    match input.trim().to_lowercase().as_str() {
+        "y" | "yes" | "e" | "evet" => Some(Answer::Yes),
+        "n" | "no" | "h" | "hayır" => Some(Answer::No),
+        "a" | "all" | "t" | "tümü" => Some(Answer::All),
+        "s" | "skip" | "q" | "quit" => Some(Answer::SkipRest),
+        _ => None,
+    }
+}
+
+fn ask(mutation: &Mutation) -> Result<Answer, Box<dyn Error>> {
+    let stdin = io::stdin();
+    loop {
+        print!("❓ {} gönderilsin mi? [y]es/[n]o/[a]ll/[s]kip rest: ", mutation.describe());
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            // stdin kapandı: geri kalanını göndermeyelim
+            return Ok(Answer::SkipRest);
+        }
+        if let Some(answer) = parse_answer(&line) {
+            return Ok(answer);
+        }
+    }
+}
+
+/// Sends local note edits back to Jira, together with earlier failures from the
+/// retry queue that are due again. With `dry_run` only the planned mutations
+/// are listed; nothing is changed in Jira or in the state file.
+/// With `confirm` every mutation is approved interactively first.
+pub async fn run(config: &Config, dry_run: bool, confirm: bool) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let mut state = State::load(&config.vault_path)?;
+    let mut cache = TransitionCache::load(&config.vault_path)?;
+    let mut queue = RetryQueue::load(&config.vault_path)?;
+    let now = Utc::now().timestamp();
+
+    let collected = collect(config, &state)?;
+    for mutation in &collected {
+        // Notta daha yeni bir düzenleme varsa kuyruktaki eskisinin yerini alır.
+        queue.items.retain(|item| !mutation.supersedes(&item.mutation));
+    }
+    // Kuyruktakiler önce ve sırayla gider.
+    let mut mutations = queue.due(now);
+    mutations.extend(collected.into_iter().filter(|m| !queue.contains(m)));
+    for item in queue.items.iter().filter(|item| !item.is_due(now)) {
+        info!("⏳ {} ({} deneme, sonra tekrar denenecek)", item.mutation.describe(), item.attempts);
+    }
+
+    if mutations.is_empty() {
+        if queue.is_empty() {
+            info!("✨ Gönderilecek değişiklik yok.");
+        }
+        queue.save(&config.vault_path)?;
+        return Ok(());
+    }
+
+    if config.allows_any_write() {
+        let projects: BTreeSet<String> = mutations.iter().map(|m| m.project().to_string()).collect();
+        permissions::preflight(&client, config, &projects).await;
+    }
+
+    if dry_run {
+        println!("🔎 Deneme modu: {} değişiklik gönderilecekti:", mutations.len());
+        for mutation in &mutations {
+            preview(&client, &mut cache, config, mutation).await;
+        }
+        cache.save(&config.vault_path)?;
+        return Ok(());
+    }
+
+    let outcome = send(&client, &mut cache, &mut state, &mut queue, config, &mutations, confirm).await?;
+    info!(
+        "🏁 Gönderim tamamlandı: {} başarılı, {} hatalı, {} atlandı.",
+        outcome.pushed, outcome.failed, outcome.declined
+    );
+    queue.print_summary();
+    Ok(())
+}
+
+/// Retries the queued mutations that are due; sync calls this before fetching
+/// so the notes it writes already reflect them.
+pub async fn retry_queued(config: &Config) -> Result<(), Box<dyn Error>> {
+    let queue = RetryQueue::load(&config.vault_path)?;
+    let mutations = queue.due(Utc::now().timestamp());
+    if mutations.is_empty() || !config.allows_any_write() {
+        return Ok(());
+    }
+    info!("🔁 Kuyruktaki {} değişiklik yeniden deneniyor...", mutations.len());
+    replay(config, queue, &mutations).await
+}
+
+/// `queue flush`: sends everything queued right away, ignoring the backoff.
+pub async fn flush(config: &Config) -> Result<(), Box<dyn Error>> {
+    let queue = RetryQueue::load(&config.vault_path)?;
+    if queue.is_empty() {
+        info!("✨ Kuyruk boş.");
+        return Ok(());
+    }
+    let mutations: Vec<Mutation> = queue.items.iter().map(|item| item.mutation.clone()).collect();
+    info!("🔁 Kuyruktaki {} değişiklik gönderiliyor...", mutations.len());
+    replay(config, queue, &mutations).await
+}
+
+/// Sends mutations planned elsewhere (e.g. `bulk`) after the permission
+/// check; failures are queued like any other push.
+pub async fn apply(config: &Config, mutations: &[Mutation]) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let projects: BTreeSet<String> = mutations.iter().map(|m| m.project().to_string()).collect();
+    permissions::preflight(&client, config, &projects).await;
+    let mut state = State::load(&config.vault_path)?;
+    let mut cache = TransitionCache::load(&config.vault_path)?;
+    let mut queue = RetryQueue::load(&config.vault_path)?;
+    let outcome = send(&client, &mut cache, &mut state, &mut queue, config, mutations, false).await?;
+    info!(
+        "🏁 Gönderim tamamlandı: {} başarılı, {} hatalı, {} atlandı.",
+        outcome.pushed, outcome.failed, outcome.declined
+    );
+    queue.print_summary();
+    Ok(())
+}
+
+async fn replay(config: &Config, mut queue: RetryQueue, mutations: &[Mutation]) -> Result<(), Box<dyn Error>> {
+    let client = JiraClient::new(config)?;
+    let mut state = State::load(&config.vault_path)?;
+    let mut cache = TransitionCache::load(&config.vault_path)?;
+    let outcome = send(&client, &mut cache, &mut state, &mut queue, config, mutations, false).await?;
+    info!("{} başarılı, {} hatalı.", outcome.pushed, outcome.failed);
+    queue.print_summary();
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct Outcome {
+    pushed: usize,
+    failed: usize,
+    declined: usize,
+}
+
+/// Executes `mutations` in order. Failures go to the retry queue and successes
+/// leave it. Once Jira is unreachable the rest is queued unsent, keeping the order.
+async fn send(
+    client: &JiraClient,
+    cache: &mut TransitionCache,
+    state: &mut State,
+    queue: &mut RetryQueue,
+    config: &Config,
+    mutations: &[Mutation],
+    confirm: bool,
+) -> Result<Outcome, Box<dyn Error>> {
+    let mut outcome = Outcome::default();
+    let writer = VaultWriter::new(config);
+    let mut ask_each = confirm;
+    for (i, mutation) in mutations.iter().enumerate() {
+        if !config.allows(mutation.capability()) {
+            warn!("🔒 {} (izin yok: allow_writes veya ilgili allow_* ayarını açın)", mutation.describe());
+            outcome.declined += 1;
+            continue;
+        }
+        if ask_each {
+            match ask(mutation)? {
+                Answer::Yes => {}
+                Answer::No => {
+                    outcome.declined += 1;
+                    continue;
+                }
+                Answer::All => ask_each = false,
+                Answer::SkipRest => {
+                    outcome.declined += mutations.len() - i;
+                    break;
+                }
+            }
+        }
+        info!("🔁 {}", mutation.describe());
+        match execute(client, cache, config, mutation).await {
+            Ok(detail) => {
+                if !detail.is_empty() {
+                    info!("✅ {}", detail);
+                }
+                queue.remove(mutation);
+                record_success(config, state, &writer, mutation)?;
+                outcome.pushed += 1;
+            }
+            Err(e) if jira::is_offline(e.as_ref()) => {
+                let rest = &mutations[i..];
+                for mutation in rest {
+                    queue.enqueue(mutation);
+                }
+                warn!("📴 Jira'ya ulaşılamıyor ({}); {} değişiklik sırayla gönderilmek üzere kuyruğa alındı", e, rest.len());
+                outcome.failed += rest.len();
+                break;
+            }
+            Err(e) => {
+                error!("❌ {} (yeniden denemek için kuyruğa alındı)", e);
+                queue.record_failure(mutation, &e.to_string(), Utc::now().timestamp());
+                outcome.failed += 1;
+            }
+        }
+    }
+
+    cache.save(&config.vault_path)?;
+    state.save(&config.vault_path)?;
+    queue.save(&config.vault_path)?;
+    Ok(outcome)
+}
+
+/// Compares every managed note with what we last synced from Jira.
+pub fn collect(config: &Config, state: &State) -> Result<Vec<Mutation>, Box<dyn Error>> {
+    let vault = Path::new(&config.vault_path);
+    let labels = Labels::from_config(config);
+    let mut mutations = Vec::new();
+    for (key, note) in &state.notes {
+        let path = vault.join(&note.path);
+        if !path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)?;
+        let fm = frontmatter::parse(&content);
+        if let Some(status) = fm.get("jira_status") {
+            if !status.is_empty() && *status != note.vars.status {
+                mutations.push(Mutation::Transition {
+                    key: key.clone(),
+                    from: note.vars.status.clone(),
+                    to: status.clone(),
+                });
+            }
+        }
+        if let Some(priority) = fm.get("jira_priority") {
+            // Önceliksiz işlerde notta "Belirsiz" yazar; bu bir düzenleme değil.
+            let untouched = note.vars.priority.is_empty() && *priority == labels.unknown_priority;
+            if !priority.is_empty() && *priority != note.vars.priority && !untouched {
+                mutations.push(Mutation::Edit {
+                    key: key.clone(),
+                    field: "priority".to_string(),
+                    from: note.vars.priority.clone(),
+                    to: priority.clone(),
+                });
+            }
+        }
+        for body in outbox_comments(&content) {
+            mutations.push(Mutation::Comment { key: key.clone(), body });
+        }
+    }
+    Ok(mutations)
+}
+
+/// Bodies of the unsent ```` ```jira-comment ```` blocks in a note.
+fn outbox_comments(content: &str) -> Vec<String> {
+    let mut comments = Vec::new();
+    let mut rest = content;
+    while let Some(start) = rest.find(OUTBOX_FENCE) {
+        let after = &rest[start + OUTBOX_FENCE.len()..];
+        let Some(end) = after.find("\n```") else { break };
+        let body = after[..end].trim();
+        if !body.is_empty() {
+            comments.push(body.to_string());
+        }
+        rest = &after[end + 4..];
+    }
+    comments
+}
+
+/// Jira Cloud wants comment bodies as ADF; blank lines separate paragraphs.
+fn comment_adf(body: &str) -> serde_json::Value {
+    let paragraphs: Vec<serde_json::Value> = body
+        .split("\n\n")
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| serde_json::json!({ "type": "paragraph", "content": [{ "type": "text", "text": p.trim() }] }))
+        .collect();
+    serde_json::json!({ "body": { "type": "doc", "version": 1, "content": paragraphs } })
+}
+
+async fn preview(client: &JiraClient, cache: &mut TransitionCache, config: &Config, mutation: &Mutation) {
+    println!("🔁 {}", mutation.describe());
+    match mutation {
+        Mutation::Comment { .. } | Mutation::Edit { .. } | Mutation::Assign { .. } | Mutation::Labels { .. } => {}
+        Mutation::Transition { key, to, .. } => {
+            match transitions::preview(client, cache, &config.transitions, key, to).await {
+                Ok((current, Some(path))) if path.is_empty() => println!("   Jira'da zaten '{}'", current),
+                Ok((current, Some(path))) => {
+                    let steps: Vec<String> = path.iter().map(|t| format!("{} ({} -> {})", t.name, t.id, t.to)).collect();
+                    println!("   Jira'daki durum '{}', geçişler: {}", current, steps.join(", "));
+                }
+                Ok((current, None)) => println!("   ⚠️  '{}' durumundan bilinen bir yol yok", current),
+                Err(e) => println!("   ❌ {}", e),
+            }
+        }
+    }
+}
+
+/// Performs the mutation and returns a short description of what was done.
+async fn execute(
+    client: &JiraClient,
+    cache: &mut TransitionCache,
+    config: &Config,
+    mutation: &Mutation,
+) -> Result<String, Box<dyn Error>> {
+    match mutation {
+        Mutation::Transition { key, to, .. } => {
+            let steps = transitions::transition_to(client, cache, &config.transitions, key, to).await?;
+            Ok(steps.join(" → "))
+        }
+        Mutation::Comment { key, body } => {
+            // API v2 (Server/Data Center) takes the comment as plain text.
+            let payload = match config.api_version() {
+                2 => serde_json::json!({ "body": body }),
+                _ => comment_adf(body),
+            };
+            client.post_json(&format!("/rest/api/3/issue/{}/comment", key), &payload).await?;
+            Ok("yorum eklendi".to_string())
+        }
+        Mutation::Edit { key, field, to, .. } => {
+            editmeta::update_field(client, key, field, to).await?;
+            Ok(String::new())
+        }
+        Mutation::Assign { key, account_id, .. } => {
+            let body = serde_json::json!({ "accountId": account_id });
+            client.put_json(&format!("/rest/api/3/issue/{}/assignee", key), &body).await?;
+            Ok(String::new())
+        }
+        Mutation::Labels { key, add, remove } => {
+            let ops: Vec<serde_json::Value> = add
+                .iter()
+                .map(|l| serde_json::json!({ "add": l }))
+                .chain(remove.iter().map(|l| serde_json::json!({ "remove": l })))
+                .collect();
+            client.put_json(&format!("/rest/api/3/issue/{}", key), &serde_json::json!({ "update": { "labels": ops } })).await?;
+            Ok(String::new())
+        }
+    }
+}
+
+/// Records a successful mutation so it isn't detected again on the next push:
+/// a new status goes into the state, a posted comment block is marked as sent.
+fn record_success(
+    config: &Config,
+    state: &mut State,
+    writer: &VaultWriter,
+    mutation: &Mutation,
+) -> Result<(), Box<dyn Error>> {
+    match mutation {
+        Mutation::Transition { key, to, .. } => {
+            if let Some(note) = state.notes.get_mut(key) {
+                note.vars.status = to.clone();
+            }
+        }
+        Mutation::Edit { key, field, to, .. } => {
+            if let Some(note) = state.notes.get_mut(key) {
+                if field == "priority" {
+                    note.vars.priority = to.clone();
+                }
+            }
+        }
+        Mutation::Labels { key, add, remove } => {
+            if let Some(note) = state.notes.get_mut(key) {
+                note.labels.retain(|l| !remove.contains(l));
+                note.labels.extend(add.iter().filter(|l| !note.labels.contains(l)).cloned().collect::<Vec<_>>());
+            }
+        }
+        Mutation::Assign { .. } => {}
+        Mutation::Comment { key, body } => {
+            let Some(note) = state.notes.get(key) else { return Ok(()) };
+            let path = Path::new(&config.vault_path).join(&note.path);
+            let Ok(content) = fs::read_to_string(&path) else { return Ok(()) };
+            if let Some(updated) = mark_sent(&content, body) {
+                writer.write(&path, &updated)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Renames the fence of the outbox block holding `body`; `None` if it's gone.
+fn mark_sent(content: &str, body: &str) -> Option<String> {
+    let mut offset = 0;
+    while let Some(start) = content[offset..].find(OUTBOX_FENCE).map(|i| i + offset) {
+        let after = &content[start + OUTBOX_FENCE.len()..];
+        let end = after.find("\n```")?;
+        if after[..end].trim() == body {
+            return Some(format!("{}{}{}", &content[..start], SENT_FENCE, after));
+        }
+        offset = start + OUTBOX_FENCE.len();
+    }
+    None
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_answer() {
+    assert_eq!(parse_answer("y\n"), Some(Answer::Yes));
+    assert_eq!(parse_answer(" ALL "), Some(Answer::All));
+    assert_eq!(parse_answer("s"), Some(Answer::SkipRest));
+    assert_eq!(parse_answer("n"), Some(Answer::No));
+    assert_eq!(parse_answer("maybe"), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_outbox_comments() {
+    let note = "## Notes\n```jira-comment\nDeployed to staging.\n\nPlease verify.\n```\n```jira-comment-sent\nOld one\n```\n```jira-comment\nSecond\n```\n";
+    assert_eq!(outbox_comments(note), ["Deployed to staging.\n\nPlease verify.", "Second"]);
+
+    let sent = mark_sent(note, "Second").unwrap();
+    assert_eq!(outbox_comments(&sent), ["Deployed to staging.\n\nPlease verify."]);
+    assert!(sent.contains("```jira-comment-sent\nSecond\n```"));
+    assert_eq!(mark_sent(note, "Missing"), None);
+
+    let adf = comment_adf("Deployed to staging.\n\nPlease verify.");
+    assert_eq!(adf["body"]["content"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,187 @@
+use std::error::Error;
+use std::fs;
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use crate::config::Config;
+use crate::push::Mutation;
+use crate::state::State;
+use tracing::{info, warn};
+
+const QUEUE_FILE: &str = "queue.json";
+/// First retry after a minute, doubling up to six hours.
+const BASE_DELAY_SECS: i64 = 60;
+const MAX_DELAY_SECS: i64 = 6 * 60 * 60;
+/// After this many failed attempts an item is reported as stuck.
+const STUCK_AFTER: u32 = 5;
+
+/// Outbound changes that failed or were made while Jira was unreachable, kept
+/// in the state folder so a later sync overwriting the note doesn't lose them.
+/// Items are replayed in the order they were queued.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RetryQueue {
+    #[serde(default)]
+    pub items: Vec<QueuedMutation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedMutation {
+    pub mutation: Mutation,
+    /// Failed attempts; 0 for changes queued while offline.
+    pub attempts: u32,
+    pub last_error: String,
+    /// Unix time (seconds) before which the item is not retried.
+    pub next_attempt: i64,
+}
+
+impl QueuedMutation {
+    pub fn is_due(&self, now: i64) -> bool {
+        self.next_attempt <= now
+    }
+
+    pub fn is_stuck(&self) -> bool {
+        self.attempts >= STUCK_AFTER
+    }
+}
+
+impl RetryQueue {
+    pub fn load(vault_path: &str) -> Result<RetryQueue, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(QUEUE_FILE);
+        if !path.exists() {
+            return Ok(RetryQueue::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(QUEUE_FILE), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn contains(&self, mutation: &Mutation) -> bool {
+        self.items.iter().any(|item| item.mutation == *mutation)
+    }
+
+    pub fn due(&self, now: i64) -> Vec<Mutation> {
+        self.items.iter().filter(|item| item.is_due(now)).map(|item| item.mutation.clone()).collect()
+    }
+
+    pub fn remove(&mut self, mutation: &Mutation) {
+        self.items.retain(|item| item.mutation != *mutation);
+    }
+
+    /// Queues a mutation that couldn't be sent because Jira was unreachable;
+    /// it is due again right away.
+    pub fn enqueue(&mut self, mutation: &Mutation) {
+        if !self.contains(mutation) {
+            self.items.push(QueuedMutation {
+                mutation: mutation.clone(),
+                attempts: 0,
+                last_error: String::new(),
+                next_attempt: 0,
+            });
+        }
+    }
+
+    /// Queues a failed mutation, or pushes an already queued one further back
+    /// without changing its place in line.
+    pub fn record_failure(&mut self, mutation: &Mutation, error: &str, now: i64) {
+        self.enqueue(mutation);
+        if let Some(item) = self.items.iter_mut().find(|item| item.mutation == *mutation) {
+            item.attempts += 1;
+            item.last_error = error.to_string();
+            item.next_attempt = now + backoff(item.attempts);
+        }
+    }
+
+    /// Lines for the sync/push summary: how much is waiting and what is stuck.
+    pub fn print_summary(&self) {
+        if self.is_empty() {
+            return;
+        }
+        info!("⏳ Yeniden denenecek {} değişiklik kuyrukta bekliyor.", self.items.len());
+        for item in self.items.iter().filter(|item| item.is_stuck()) {
+            warn!(
+                "⚠️  Takıldı ({} deneme): {} — {}",
+                item.attempts,
+                item.mutation.describe(),
+                item.last_error
+            );
+        }
+    }
+}
+
+/// `queue list`
+pub fn list(config: &Config) -> Result<(), Box<dyn Error>> {
+    let queue = RetryQueue::load(&config.vault_path)?;
+    if queue.is_empty() {
+        println!("✨ Kuyruk boş.");
+        return Ok(());
+    }
+    for (i, item) in queue.items.iter().enumerate() {
+        println!("{}. {}", i + 1, item.mutation.describe());
+        if item.attempts == 0 {
+            println!("   çevrimdışıyken kuyruğa alındı");
+        } else {
+            let next = Local
+                .timestamp_opt(item.next_attempt, 0)
+                .single()
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default();
+            println!("   {} deneme, son hata: {}", item.attempts, item.last_error);
+            println!("   sonraki deneme: {}", next);
+        }
+    }
+    Ok(())
+}
+
+/// `queue clear`: drops every queued change without sending it.
+pub fn clear(config: &Config) -> Result<(), Box<dyn Error>> {
+    let mut queue = RetryQueue::load(&config.vault_path)?;
+    let count = queue.items.len();
+    queue.items.clear();
+    queue.save(&config.vault_path)?;
+    info!("🧹 Kuyruktan {} değişiklik silindi.", count);
+    Ok(())
+}
+
+fn backoff(attempts: u32) -> i64 {
+    let factor = 1i64 << attempts.saturating_sub(1).min(20);
+    (BASE_DELAY_SECS * factor).min(MAX_DELAY_SECS)
+}
+
+#[cfg(test)]
+#[test]
+fn test_record_failure_backs_off() {
+    let mutation = Mutation::Transition { key: "A-1".into(), from: "To Do".into(), to: "Done".into() };
+    let mut queue = RetryQueue::default();
+
+    queue.record_failure(&mutation, "403", 1000);
+    assert_eq!(queue.items[0].next_attempt, 1060);
+    assert!(queue.due(1059).is_empty());
+    assert_eq!(queue.due(1060), vec![mutation.clone()]);
+
+    for _ in 0..4 {
+        queue.record_failure(&mutation, "403", 1000);
+    }
+    assert_eq!(queue.items.len(), 1);
+    assert_eq!(queue.items[0].attempts, 5);
+    assert_eq!(queue.items[0].next_attempt, 1000 + 16 * 60);
+    assert!(queue.items[0].is_stuck());
+    assert_eq!(backoff(30), MAX_DELAY_SECS);
+
+    let offline = Mutation::Comment { key: "A-2".into(), body: "hi".into() };
+    queue.enqueue(&offline);
+    queue.record_failure(&mutation, "403", 2000);
+    assert_eq!(queue.items[0].mutation, mutation);
+    assert_eq!(queue.due(0), vec![offline.clone()]);
+
+    queue.remove(&mutation);
+    queue.remove(&offline);
+    assert!(queue.is_empty());
+}
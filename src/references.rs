@@ -0,0 +1,72 @@
+use serde_json::Value;
+use crate::jira::JiraClient;
+use crate::Issue;
+use tracing::warn;
+
+/// Adds `remoteLinks` (Confluence pages, web links) from
+/// `/issue/{key}/remotelink` to every issue that has any. Failures only cost
+/// that issue's section.
+pub async fn resolve(client: &JiraClient, issues: impl IntoIterator<Item = &mut Value>) {
+    for issue in issues {
+        let Some(key) = issue["key"].as_str().map(str::to_string) else { continue };
+        match client.get_json::<Value>(&format!("/rest/api/3/issue/{}/remotelink", key)).await {
+            Ok(links) if links.as_array().is_some_and(|l| !l.is_empty()) => issue["remoteLinks"] = links,
+            Ok(_) => {}
+            Err(e) => warn!("⚠️  {} için uzak bağlantılar alınamadı: {}", key, e),
+        }
+    }
+}
+
+/// The `## 📚` section of a ticket note: one line per remote link, with the
+/// application it lives in (Confluence, ...) and how it relates to the issue.
+pub fn section(issue: &Issue, title: &str) -> String {
+    let Some(links) = issue.remote_links.as_ref().and_then(Value::as_array) else {
+        return String::new();
+    };
+    let lines: Vec<String> = links.iter().filter_map(line).collect();
+    if lines.is_empty() {
+        return String::new();
+    }
+    format!("\n## 📚 {}\n{}", title, lines.concat())
+}
+
+fn line(link: &Value) -> Option<String> {
+    let object = &link["object"];
+    let url = object["url"].as_str()?;
+    let title = object["title"].as_str().filter(|t| !t.is_empty()).unwrap_or(url);
+    let details: Vec<&str> = [link["application"]["name"].as_str(), link["relationship"].as_str()]
+        .into_iter()
+        .flatten()
+        .filter(|d| !d.is_empty())
+        .collect();
+    let mut line = format!("- [{}]({})", title.replace(['[', ']'], ""), url);
+    if !details.is_empty() {
+        line.push_str(&format!(" — {}", details.join(" · ")));
+    }
+    if object["status"]["resolved"].as_bool() == Some(true) {
+        line.push_str(" ✅");
+    }
+    line.push('\n');
+    Some(line)
+}
+
+#[cfg(test)]
+#[test]
+fn test_references_section() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": { "summary": "s", "status": { "name": "To Do" } },
+        "remoteLinks": [
+            { "application": { "type": "com.atlassian.confluence", "name": "Confluence" }, "relationship": "mentioned in",
+              "object": { "url": "https://wiki/pages/1", "title": "Login [spec]" } },
+            { "object": { "url": "https://status.example.com/incident/9", "title": "", "status": { "resolved": true } } },
+            { "object": { "title": "no url" } }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(
+        section(&issue, "References"),
+        "\n## 📚 References\n- [Login spec](https://wiki/pages/1) — Confluence · mentioned in\n\
+         - [https://status.example.com/incident/9](https://status.example.com/incident/9) ✅\n"
+    );
+}
@@ -0,0 +1,142 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::path::Path;
+use chrono::{Local, NaiveDate};
+use crate::config::Config;
+use crate::i18n::Labels;
+use crate::jira::JiraClient;
+use crate::layout::{link_target, sanitize};
+use crate::metrics::parse_time;
+use crate::people::Directory;
+use crate::restricted;
+use crate::state::State;
+use crate::vault::VaultWriter;
+use crate::Issue;
+use tracing::info;
+
+/// Completed issues younger than this show up under recent completions.
+const RECENT_DAYS: i64 = 14;
+
+/// `report person <name>`: a note on one teammate's open, overdue and recently
+/// completed issues, e.g. to prepare a 1:1. The name is looked up in the people
+/// directory, so the person must have appeared in a synced issue before.
+pub async fn person(config: &Config, name: &str) -> Result<(), Box<dyn Error>> {
+    let vault_path = &config.vault_path;
+    let people = Directory::load(vault_path, &config.people)?;
+    let id = people
+        .find(name)
+        .ok_or_else(|| format!("'{}' kişisi tanınmıyor; önce bu kişinin işlerini içeren bir sync çalıştırın", name))?;
+    let display_name = people.name(&id);
+
+    let client = JiraClient::new(config)?;
+    let jql = format!(
+        "assignee = \"{}\" AND (resolution = Unresolved OR resolved >= -{}d) ORDER BY priority DESC, updated DESC",
+        id, RECENT_DAYS
+    );
+    let fields = ["summary", "status", "priority", "duedate", "resolutiondate"];
+    let Some(raw) = crate::search(&client, &jql, &fields).await? else {
+        return Ok(());
+    };
+    let issues = raw.into_iter().map(restricted::from_raw).collect::<Result<Vec<Issue>, _>>()?;
+
+    let note_links: HashMap<String, String> = State::load(vault_path)?
+        .notes
+        .into_iter()
+        .map(|(key, note)| (key, link_target(Path::new(&note.path))))
+        .collect();
+    let labels = Labels::from_config(config);
+    let note = render(&labels, &display_name, &issues, &note_links, Local::now().date_naive());
+
+    let file = format!("Report - {}.md", sanitize(&display_name));
+    VaultWriter::new(config).write(&Path::new(vault_path).join(&file), &note)?;
+    info!("📝 {} için rapor oluşturuldu: {} ({} iş)", display_name, file, issues.len());
+    Ok(())
+}
+
+fn render(labels: &Labels, name: &str, issues: &[Issue], note_links: &HashMap<String, String>, today: NaiveDate) -> String {
+    let link = |issue: &Issue| match note_links.get(&issue.key) {
+        Some(target) if *target != issue.key => format!("[[{}|{}]] {}", target, issue.key, issue.fields.summary),
+        _ => format!("[[{}]] {}", issue.key, issue.fields.summary),
+    };
+    let done = |issue: &&Issue| issue.fields.status.status_category.as_ref().is_some_and(|c| c.key == "done");
+    let (completed, open): (Vec<&Issue>, Vec<&Issue>) = issues.iter().partition(done);
+
+    let mut note = format!(
+        "---\ntags: [jira, report]\njira_person: \"{}\"\n---\n# {}\n\n> {}: {}\n",
+        name.replace('"', "'"),
+        name,
+        labels.last_sync,
+        today
+    );
+
+    let mut by_status: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    let mut by_priority: BTreeMap<&str, usize> = BTreeMap::new();
+    for issue in &open {
+        by_status.entry(issue.fields.status.name.as_str()).or_default().push(issue);
+        let priority = issue.fields.priority.as_ref().map_or(labels.unknown_priority.as_str(), |p| p.name.as_str());
+        *by_priority.entry(priority).or_default() += 1;
+    }
+    note.push_str(&format!("\n## {} ({})\n", labels.open_issues, open.len()));
+    for (status, issues) in &by_status {
+        note.push_str(&format!("\n### {} ({})\n\n", status, issues.len()));
+        for issue in issues {
+            note.push_str(&format!("- {}\n", link(issue)));
+        }
+    }
+
+    note.push_str(&format!("\n## {}\n\n| {} | # |\n|---|---|\n", labels.priority, labels.priority));
+    for (priority, count) in &by_priority {
+        note.push_str(&format!("| {} | {} |\n", priority, count));
+    }
+
+    let overdue: Vec<(&Issue, NaiveDate)> = open
+        .iter()
+        .filter_map(|i| Some((*i, NaiveDate::parse_from_str(i.fields.duedate.as_deref()?, "%Y-%m-%d").ok()?)))
+        .filter(|(_, due)| *due < today)
+        .collect();
+    note.push_str(&format!("\n## {} ({})\n\n", labels.overdue_issues, overdue.len()));
+    for (issue, due) in overdue {
+        note.push_str(&format!("- {} · 📅 {}\n", link(issue), due));
+    }
+
+    note.push_str(&format!("\n## {} ({})\n\n", labels.recently_completed, completed.len()));
+    for issue in completed {
+        let resolved = issue.fields.extra.get("resolutiondate").and_then(|v| v.as_str()).and_then(parse_time);
+        match resolved {
+            Some(resolved) => note.push_str(&format!("- {} · ✅ {}\n", link(issue), resolved.date_naive())),
+            None => note.push_str(&format!("- {}\n", link(issue))),
+        }
+    }
+    note
+}
+
+#[cfg(test)]
+#[test]
+fn test_person_report() {
+    let issue = |key: &str, status: &str, category: &str, priority: &str, due: Option<&str>| -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "key": key,
+            "fields": {
+                "summary": format!("{} summary", key),
+                "status": { "name": status, "statusCategory": { "key": category, "name": category } },
+                "priority": { "name": priority },
+                "duedate": due,
+                "resolutiondate": "2025-05-28T10:00:00.000+0000"
+            }
+        }))
+        .unwrap()
+    };
+    let issues = vec![
+        issue("A-1", "In Progress", "indeterminate", "High", Some("2025-05-20")),
+        issue("A-2", "To Do", "new", "High", None),
+        issue("A-3", "Done", "done", "Low", None),
+    ];
+    let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let note = render(&Labels::for_locale("en"), "Ayşe", &issues, &HashMap::new(), today);
+
+    assert!(note.contains("## Open Issues (2)\n\n### In Progress (1)\n\n- [[A-1]] A-1 summary\n"));
+    assert!(note.contains("| High | 2 |\n"));
+    assert!(!note.contains("| Low |"));
+    assert!(note.contains("## Overdue Issues (1)\n\n- [[A-1]] A-1 summary · 📅 2025-05-20\n"));
+    assert!(note.contains("## Recently Completed (1)\n\n- [[A-3]] A-3 summary · ✅ 2025-05-28\n"));
+}
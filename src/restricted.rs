@@ -0,0 +1,65 @@
+use serde_json::{json, Value};
+use crate::i18n::Labels;
+use crate::Issue;
+
+/// Flags a raw issue the token could only partly read, e.g. because the
+/// comments are restricted to a role it lacks.
+pub fn mark(raw: &mut Value) {
+    raw["restricted"] = Value::Bool(true);
+}
+
+/// Makes a raw issue whose summary or status Jira withheld (field security,
+/// hidden fields) into a stub that still gets a note: the key stands in for
+/// the summary and `?` for the status. Returns whether anything was missing.
+pub fn stub(raw: &mut Value) -> bool {
+    let key = raw["key"].as_str().unwrap_or_default().to_string();
+    if !raw["fields"].is_object() {
+        raw["fields"] = json!({});
+    }
+    let fields = &mut raw["fields"];
+    let mut missing = false;
+    if !fields["summary"].is_string() {
+        fields["summary"] = Value::String(key);
+        missing = true;
+    }
+    if !fields["status"]["name"].is_string() {
+        fields["status"] = json!({ "name": "?" });
+        missing = true;
+    }
+    if missing {
+        mark(raw);
+    }
+    missing
+}
+
+/// A raw issue as an [`Issue`], stubbed first so a withheld summary or
+/// status doesn't fail the whole command.
+pub fn from_raw(mut raw: Value) -> serde_json::Result<Issue> {
+    stub(&mut raw);
+    serde_json::from_value(raw)
+}
+
+/// A warning right under the details box of a restricted issue's note.
+pub fn callout(issue: &Issue, labels: &Labels) -> String {
+    if !issue.restricted {
+        return String::new();
+    }
+    format!("\n> [!warning] 🔒\n> {}\n", labels.restricted)
+}
+
+#[cfg(test)]
+#[test]
+fn test_restricted_stub() {
+    let mut readable = json!({ "key": "A-1", "fields": { "summary": "s", "status": { "name": "To Do" } } });
+    assert!(!stub(&mut readable));
+    assert!(readable.get("restricted").is_none());
+
+    let hidden = json!({ "key": "SEC-4", "fields": { "labels": ["x"] } });
+    let issue = from_raw(hidden).unwrap();
+    assert!(issue.restricted);
+    assert_eq!((issue.fields.summary.as_str(), issue.fields.status.name.as_str()), ("SEC-4", "?"));
+    assert_eq!(
+        callout(&issue, &Labels::for_locale("en")),
+        "\n> [!warning] 🔒\n> Jira did not return all of this issue to this account (security level, hidden fields or restricted comments); this note may be incomplete.\n"
+    );
+}
@@ -0,0 +1,123 @@
+use std::error::Error;
+use rhai::{Dynamic, Engine, Map, Scope, AST};
+
+/// A user Rhai script run once per issue after its note is rendered.
+///
+/// The script sees `issue` (key, summary, status, priority, description and
+/// the extra `fields`), may change `note`, add entries to the `frontmatter`
+/// map, or set `skip = true` to leave the issue out of the vault:
+///
+/// ```rhai
+/// if issue.status == "Won't Do" { skip = true; }
+/// frontmatter.team = "platform";
+/// note = replace_section(note, "## 📄 Açıklama", "See the spec.");
+/// ```
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+}
+
+/// What the script decided for one issue.
+pub enum ScriptResult {
+    Write(String),
+    Skip,
+}
+
+impl Script {
+    pub fn load(path: &str) -> Result<Script, Box<dyn Error>> {
+        let mut engine = Engine::new();
+        engine.register_fn("replace_section", replace_section);
+        let ast = engine.compile_file(path.into()).map_err(|e| format!("{}: {}", path, e))?;
+        Ok(Script { engine, ast })
+    }
+
+    pub fn run(&self, issue: &serde_json::Value, note: String) -> Result<ScriptResult, Box<dyn Error>> {
+        let mut scope = Scope::new();
+        scope.push("issue", rhai::serde::to_dynamic(issue)?);
+        scope.push("note", note);
+        scope.push("frontmatter", Map::new());
+        scope.push("skip", false);
+
+        self.engine.run_ast_with_scope(&mut scope, &self.ast)?;
+
+        if scope.get_value::<bool>("skip").unwrap_or(false) {
+            return Ok(ScriptResult::Skip);
+        }
+        let note = scope.get_value::<String>("note").ok_or("`note` metin olmalı")?;
+        let extra = scope.get_value::<Map>("frontmatter").unwrap_or_default();
+        Ok(ScriptResult::Write(add_frontmatter(&note, &extra)))
+    }
+}
+
+/// Appends `key: value` lines to the note's leading YAML block.
+fn add_frontmatter(note: &str, extra: &Map) -> String {
+    if extra.is_empty() {
+        return note.to_string();
+    }
+    let lines: String = extra.iter().map(|(k, v)| format!("{}: {}\n", k, dynamic_to_yaml(v))).collect();
+    match note.strip_prefix("---\n").and_then(|rest| rest.find("\n---\n").map(|end| end + 4)) {
+        Some(end) => format!("{}\n{}{}", &note[..end], lines, &note[end + 1..]),
+        None => format!("---\n{}---\n{}", lines, note),
+    }
+}
+
+fn dynamic_to_yaml(value: &Dynamic) -> String {
+    if value.is_array() {
+        let items: Vec<String> = value.clone().into_array().unwrap_or_default().iter().map(|v| v.to_string()).collect();
+        format!("[{}]", items.join(", "))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Replaces the body under `heading` up to the next heading or `---` rule.
+fn replace_section(note: &str, heading: &str, body: &str) -> String {
+    let mut out = String::new();
+    let mut lines = note.lines().peekable();
+    let mut found = false;
+    while let Some(line) = lines.next() {
+        out.push_str(line);
+        out.push('\n');
+        if !found && line.trim_end() == heading {
+            found = true;
+            out.push_str(body.trim_end());
+            out.push_str("\n\n");
+            while let Some(next) = lines.peek() {
+                if next.starts_with('#') || next.trim_end() == "---" {
+                    break;
+                }
+                lines.next();
+            }
+        }
+    }
+    if !note.ends_with('\n') {
+        out.pop();
+    }
+    out
+}
+
+#[cfg(test)]
+#[test]
+fn test_script_edits_note() {
+    // Aynı anda çalışan test süreçleri birbirinin dosyasını ezmesin.
+    let path = std::env::temp_dir().join(format!("jira_sync_test_script_{}.rhai", std::process::id()));
+    std::fs::write(&path, r###"
+        if issue.status == "Done" { skip = true; }
+        frontmatter.team = "platform";
+        note = replace_section(note, "## Desc", "Rewritten.");
+    "###).unwrap();
+    let script = Script::load(&path.to_string_lossy());
+    std::fs::remove_file(&path).unwrap();
+    let script = script.unwrap();
+    let note = "---\njira_key: A-1\n---\n# A-1\n\n## Desc\nold text\nmore\n\n---\nnotes\n".to_string();
+
+    match script.run(&serde_json::json!({"key": "A-1", "status": "To Do"}), note).unwrap() {
+        ScriptResult::Write(note) => assert_eq!(
+            note,
+            "---\njira_key: A-1\nteam: platform\n---\n# A-1\n\n## Desc\nRewritten.\n\n---\nnotes\n"
+        ),
+        ScriptResult::Skip => panic!("should not skip"),
+    }
+    let skipped = script.run(&serde_json::json!({"key": "A-1", "status": "Done"}), String::new()).unwrap();
+    assert!(matches!(skipped, ScriptResult::Skip));
+}
@@ -0,0 +1,189 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use crate::i18n::Labels;
+use crate::jira::JiraClient;
+use crate::Issue;
+use tracing::debug;
+
+/// `[service_desk]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServiceDeskConfig {
+    /// Fetch request types and SLAs from the Service Management API.
+    pub enabled: bool,
+    /// Project keys that are service desks; empty asks for every issue.
+    pub projects: Vec<String>,
+}
+
+/// One SLA of a request, as far as the note and the board care.
+#[derive(Debug)]
+struct Sla {
+    name: String,
+    breached: bool,
+    /// Whether the clock still runs (an ongoing, unpaused cycle).
+    running: bool,
+    /// Jira's own wording, e.g. `-1h 20m`; empty for completed SLAs.
+    remaining: String,
+    remaining_ms: Option<i64>,
+    goal: String,
+}
+
+/// Adds `serviceDesk` (request type and SLA values) from
+/// `/servicedeskapi/request/{key}` to the issues of service desk projects.
+pub async fn resolve(client: &JiraClient, config: &ServiceDeskConfig, issues: &mut [Value]) {
+    for issue in issues {
+        let key = issue["key"].as_str().unwrap_or_default().to_string();
+        let project = key.rsplit_once('-').map_or(key.as_str(), |(project, _)| project);
+        if !config.projects.is_empty() && !config.projects.iter().any(|p| p.eq_ignore_ascii_case(project)) {
+            continue;
+        }
+        let path = format!("/rest/servicedeskapi/request/{}?expand=requestType,sla", key);
+        match client.get_json::<Value>(&path).await {
+            Ok(request) => {
+                issue["serviceDesk"] = json!({
+                    "requestType": request["requestType"]["name"],
+                    "sla": request["sla"]["values"],
+                });
+            }
+            // Servis masası olmayan projelerin işleri 404 döner; bu beklenen bir durum.
+            Err(e) => debug!("{} servis masası talebi değil: {}", key, e),
+        }
+    }
+}
+
+fn slas(issue: &Issue) -> Vec<Sla> {
+    let Some(values) = issue.service_desk.as_ref().and_then(|s| s["sla"].as_array()) else {
+        return Vec::new();
+    };
+    values
+        .iter()
+        .filter_map(|value| {
+            let name = value["name"].as_str()?.to_string();
+            let ongoing = &value["ongoingCycle"];
+            if ongoing.is_object() {
+                return Some(Sla {
+                    name,
+                    breached: ongoing["breached"].as_bool().unwrap_or(false),
+                    running: !ongoing["paused"].as_bool().unwrap_or(false),
+                    remaining: ongoing["remainingTime"]["friendly"].as_str().unwrap_or_default().to_string(),
+                    remaining_ms: ongoing["remainingTime"]["millis"].as_i64(),
+                    goal: ongoing["goalDuration"]["friendly"].as_str().unwrap_or_default().to_string(),
+                });
+            }
+            let last = value["completedCycles"].as_array()?.last()?;
+            Some(Sla {
+                name,
+                breached: last["breached"].as_bool().unwrap_or(false),
+                running: false,
+                remaining: String::new(),
+                remaining_ms: None,
+                goal: last["goalDuration"]["friendly"].as_str().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The running SLA closest to (or furthest past) its breach time.
+fn most_urgent(slas: &[Sla]) -> Option<&Sla> {
+    slas.iter().filter(|sla| sla.running && sla.remaining_ms.is_some()).min_by_key(|sla| sla.remaining_ms)
+}
+
+/// `jira_request_type`, `jira_sla_breached` and, while a clock runs,
+/// `jira_sla_remaining_minutes` (negative once breached) for Dataview sorting.
+pub fn frontmatter(issue: &Issue) -> String {
+    let Some(service_desk) = &issue.service_desk else {
+        return String::new();
+    };
+    let mut lines = String::new();
+    if let Some(request_type) = service_desk["requestType"].as_str() {
+        lines.push_str(&format!("jira_request_type: \"{}\"\n", request_type.replace('"', "'")));
+    }
+    let slas = slas(issue);
+    if !slas.is_empty() {
+        lines.push_str(&format!("jira_sla_breached: {}\n", slas.iter().any(|sla| sla.breached)));
+    }
+    if let Some(ms) = most_urgent(&slas).and_then(|sla| sla.remaining_ms) {
+        lines.push_str(&format!("jira_sla_remaining_minutes: {}\n", ms / 60_000));
+    }
+    lines
+}
+
+/// A callout right under the details box: the request type and one line per
+/// SLA, red once any of them is breached.
+pub fn callout(issue: &Issue, labels: &Labels) -> String {
+    let Some(service_desk) = &issue.service_desk else {
+        return String::new();
+    };
+    let slas = slas(issue);
+    if slas.is_empty() && service_desk["requestType"].is_null() {
+        return String::new();
+    }
+    let kind = if slas.iter().any(|sla| sla.breached) {
+        "danger"
+    } else if slas.iter().any(|sla| sla.running) {
+        "warning"
+    } else {
+        "success"
+    };
+    let mut callout = format!("\n> [!{}] SLA\n", kind);
+    if let Some(request_type) = service_desk["requestType"].as_str() {
+        callout.push_str(&format!("> **{}:** {}\n", labels.request_type, request_type));
+    }
+    for sla in &slas {
+        let icon = match (sla.breached, sla.running) {
+            (true, _) => "🔥",
+            (false, true) => "⏳",
+            (false, false) => "✅",
+        };
+        let time = match (sla.remaining.is_empty(), sla.goal.is_empty()) {
+            (false, false) => format!(" {} / {}", sla.remaining, sla.goal),
+            (false, true) => format!(" {}", sla.remaining),
+            (true, _) => String::new(),
+        };
+        callout.push_str(&format!("> {} **{}**{}\n", icon, sla.name, time));
+    }
+    callout
+}
+
+/// Prefix for the issue's board card: 🔥 while a breached SLA is still
+/// running, otherwise ⏳ and the time left on the most urgent one.
+pub fn card_marker(issue: &Issue) -> String {
+    let slas = slas(issue);
+    if slas.iter().any(|sla| sla.breached && sla.running) {
+        return "🔥 ".to_string();
+    }
+    match most_urgent(&slas) {
+        Some(sla) if !sla.remaining.is_empty() => format!("⏳ {} ", sla.remaining),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_sla_rendering() {
+    let issue: Issue = serde_json::from_value(json!({
+        "key": "SUP-7",
+        "fields": { "summary": "s", "status": { "name": "Waiting for support" } },
+        "serviceDesk": {
+            "requestType": "Get IT help",
+            "sla": [
+                { "name": "Time to first response", "completedCycles": [{ "breached": false, "goalDuration": { "friendly": "4h" } }] },
+                { "name": "Time to resolution", "ongoingCycle": {
+                    "breached": false, "paused": false,
+                    "goalDuration": { "friendly": "8h" },
+                    "remainingTime": { "millis": 4_800_000, "friendly": "1h 20m" }
+                } }
+            ]
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        frontmatter(&issue),
+        "jira_request_type: \"Get IT help\"\njira_sla_breached: false\njira_sla_remaining_minutes: 80\n"
+    );
+    assert_eq!(
+        callout(&issue, &Labels::for_locale("en")),
+        "\n> [!warning] SLA\n> **Request Type:** Get IT help\n> ✅ **Time to first response**\n> ⏳ **Time to resolution** 1h 20m / 8h\n"
+    );
+    assert_eq!(card_marker(&issue), "⏳ 1h 20m ");
+}
@@ -0,0 +1,180 @@
+use std::collections::{BTreeSet, HashMap};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::frontmatter;
+use crate::jira::JiraClient;
+use crate::Issue;
+use tracing::{debug, warn};
+
+/// `[sprints]` in the config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SprintsConfig {
+    pub enabled: bool,
+    /// The "Sprint" field; found by `capabilities` when unset.
+    pub field: Option<String>,
+}
+
+/// Issue keys asked about per Agile request in [`resolve_scope`]; one page holds them all.
+const SCOPE_BATCH: usize = 50;
+
+/// Sprint ids in a raw Sprint field value: objects on Cloud, strings like
+/// `com.atlassian.greenhopper.service.sprint.Sprint@1f[id=12,state=ACTIVE,...]`
+/// on older Server versions.
+fn sprint_ids(value: &Value) -> Vec<u64> {
+    let id = Regex::new(r"\bid=(\d+)").unwrap();
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| match item {
+                    Value::String(s) => id.captures(s).and_then(|c| c[1].parse().ok()),
+                    other => other["id"].as_u64(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Replaces the Sprint field of every issue with the sprints as the Agile API
+/// describes them (`id`, `name`, `state`, `startDate`, `endDate`), asking once
+/// per sprint. Sprints that can't be read keep what the search returned.
+pub async fn resolve(client: &JiraClient, field: &str, issues: &mut [Value]) {
+    let ids: BTreeSet<u64> = issues.iter().flat_map(|issue| sprint_ids(&issue["fields"][field])).collect();
+    let mut sprints: HashMap<u64, Value> = HashMap::new();
+    for id in ids {
+        match client.get_json::<Value>(&format!("/rest/agile/1.0/sprint/{}", id)).await {
+            Ok(sprint) => {
+                sprints.insert(id, sprint);
+            }
+            Err(e) => warn!("⚠️  {} numaralı sprint alınamadı: {}", id, e),
+        }
+    }
+    for issue in issues {
+        let ids = sprint_ids(&issue["fields"][field]);
+        if !ids.is_empty() && ids.iter().all(|id| sprints.contains_key(id)) {
+            issue["fields"][field] = ids.iter().map(|id| sprints[id].clone()).collect();
+        }
+    }
+}
+
+/// Marks the issues of the Agile board `board_id` with `sprintScope`:
+/// `active` when they are in one of its active sprints, `backlog` when they
+/// sit in its backlog. Issues in neither (a future sprint, another board) stay
+/// unmarked, and so does everything when the board has no active sprint, as
+/// on kanban boards.
+pub async fn resolve_scope(client: &JiraClient, board_id: u64, issues: &mut [Value]) {
+    let sprints = match client.get_json::<Value>(&format!("/rest/agile/1.0/board/{}/sprint?state=active", board_id)).await {
+        Ok(sprints) => sprints,
+        // Kanban panoları sprint desteklemez; bu beklenen bir durum.
+        Err(e) => {
+            debug!("{} numaralı panonun aktif sprinti okunamadı: {}", board_id, e);
+            return;
+        }
+    };
+    let sprint_ids: Vec<u64> = sprints["values"].as_array().into_iter().flatten().filter_map(|s| s["id"].as_u64()).collect();
+    if sprint_ids.is_empty() {
+        return;
+    }
+    let keys: Vec<String> = issues.iter().filter_map(|issue| issue["key"].as_str().map(str::to_string)).collect();
+    let mut scopes: HashMap<String, &str> = HashMap::new();
+    for batch in keys.chunks(SCOPE_BATCH) {
+        let query = format!("fields=status&maxResults={}&jql=key%20in%20({})", SCOPE_BATCH, batch.join(","));
+        let lists = sprint_ids
+            .iter()
+            .map(|id| (format!("/rest/agile/1.0/sprint/{}/issue?{}", id, query), "active"))
+            .chain([(format!("/rest/agile/1.0/board/{}/backlog?{}", board_id, query), "backlog")]);
+        for (path, scope) in lists {
+            match client.get_json::<Value>(&path).await {
+                Ok(page) => {
+                    for key in page["issues"].as_array().into_iter().flatten().filter_map(|issue| issue["key"].as_str()) {
+                        scopes.entry(key.to_string()).or_insert(scope);
+                    }
+                }
+                Err(e) => warn!("⚠️  {} numaralı panonun sprint/backlog işleri alınamadı: {}", board_id, e),
+            }
+        }
+    }
+    for issue in issues {
+        if let Some(scope) = issue["key"].as_str().and_then(|key| scopes.get(key)) {
+            issue["sprintScope"] = Value::from(*scope);
+        }
+    }
+}
+
+/// Whether [`resolve_scope`] found the issue in its board's backlog.
+pub fn in_backlog(issue: &Issue) -> bool {
+    issue.sprint_scope.as_deref() == Some("backlog")
+}
+
+/// `jira_backlog`, for issues of the board: `false` in the active sprint.
+pub fn scope_frontmatter(issue: &Issue) -> String {
+    match &issue.sprint_scope {
+        Some(scope) => format!("jira_backlog: {}\n", scope == "backlog"),
+        None => String::new(),
+    }
+}
+
+/// Frontmatter for the issue's current sprint (the active one, else the
+/// most recent): `jira_sprint`, `jira_sprint_state`, `jira_sprint_start`,
+/// `jira_sprint_end`, plus `jira_sprints` with every sprint it has been in.
+pub fn frontmatter(issue: &Issue, field: &str) -> String {
+    let Some(Value::Array(sprints)) = issue.fields.extra.get(field) else {
+        return String::new();
+    };
+    let sprints: Vec<&Value> = sprints.iter().filter(|s| s.is_object()).collect();
+    let current = sprints
+        .iter()
+        .find(|s| s["state"].as_str().is_some_and(|state| state.eq_ignore_ascii_case("active")))
+        .or_else(|| sprints.iter().max_by_key(|s| s["id"].as_u64()));
+    let Some(current) = current else {
+        return String::new();
+    };
+    let mut lines = String::new();
+    if let Some(name) = frontmatter::value(&current["name"]) {
+        lines.push_str(&format!("jira_sprint: {}\n", name));
+    }
+    if let Some(state) = current["state"].as_str() {
+        lines.push_str(&format!("jira_sprint_state: {}\n", state.to_lowercase()));
+    }
+    for (key, date) in [("jira_sprint_start", "startDate"), ("jira_sprint_end", "endDate")] {
+        if let Some(day) = current[date].as_str().and_then(|d| d.get(..10)) {
+            lines.push_str(&format!("{}: {}\n", key, day));
+        }
+    }
+    let names: Vec<String> = sprints.iter().filter_map(|s| frontmatter::value(&s["name"])).collect();
+    lines.push_str(&format!("jira_sprints: [{}]\n", names.join(", ")));
+    lines
+}
+
+#[cfg(test)]
+#[test]
+fn test_sprint_frontmatter() {
+    let server = serde_json::json!(["com.atlassian.greenhopper.service.sprint.Sprint@1f[id=12,rapidViewId=3,state=CLOSED,name=S1]"]);
+    assert_eq!(sprint_ids(&server), [12]);
+
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "customfield_10020": [
+                { "id": 12, "name": "Sprint 12", "state": "closed", "startDate": "2026-09-28T09:00:00.000Z", "endDate": "2026-10-09T17:00:00.000Z" },
+                { "id": 13, "name": "Sprint 13", "state": "active", "startDate": "2026-10-12T09:00:00.000Z", "endDate": "2026-10-23T17:00:00.000Z" }
+            ]
+        }
+    }))
+    .unwrap();
+    assert_eq!(sprint_ids(&issue.fields.extra["customfield_10020"]), [12, 13]);
+    assert_eq!(
+        frontmatter(&issue, "customfield_10020"),
+        "jira_sprint: \"Sprint 13\"\njira_sprint_state: active\njira_sprint_start: 2026-10-12\njira_sprint_end: 2026-10-23\n\
+         jira_sprints: [\"Sprint 12\", \"Sprint 13\"]\n"
+    );
+    assert_eq!(scope_frontmatter(&issue), "");
+    let backlog = Issue { sprint_scope: Some("backlog".into()), ..issue };
+    assert!(in_backlog(&backlog));
+    assert_eq!(scope_frontmatter(&backlog), "jira_backlog: true\n");
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::layout::NoteVars;
+
+/// Folder inside the vault where the tool keeps its own bookkeeping.
+pub const STATE_DIR: &str = ".jira-sync";
+const STATE_FILE: &str = "state.json";
+
+/// What we know about the notes we manage, keyed by issue key.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct State {
+    #[serde(default)]
+    pub notes: BTreeMap<String, NoteState>,
+    /// When the notes were last written (RFC 3339), for `status`.
+    #[serde(default)]
+    pub last_sync: Option<String>,
+    /// Issues whose note couldn't be written last time, with the error.
+    #[serde(default)]
+    pub failed: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteState {
+    /// Vault-relative path of the note, always with `/` separators.
+    pub path: String,
+    /// Template values the path was rendered from.
+    pub vars: NoteVars,
+    /// Cached for `list`, which answers without asking Jira.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Jira's `updated` timestamp.
+    #[serde(default)]
+    pub updated: String,
+}
+
+impl State {
+    pub fn dir(vault_path: &str) -> PathBuf {
+        Path::new(vault_path).join(STATE_DIR)
+    }
+
+    pub fn load(vault_path: &str) -> Result<State, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(STATE_FILE);
+        if !path.exists() {
+            return Ok(State::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        // Write next to the real file first so a crash never leaves half a state file behind.
+        let tmp = dir.join(format!("{}.tmp", STATE_FILE));
+        fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&tmp, dir.join(STATE_FILE))?;
+        Ok(())
+    }
+}
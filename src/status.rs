@@ -0,0 +1,66 @@
+use std::error::Error;
+use chrono::{DateTime, Local};
+use crate::config::Config;
+use crate::push;
+use crate::queue::RetryQueue;
+use crate::state::State;
+
+/// `status`: what the last sync left behind, read from the state folder
+/// without asking Jira.
+pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
+    let state = State::load(&config.vault_path)?;
+    match state.last_sync.as_deref().and_then(|t| DateTime::parse_from_rfc3339(t).ok()) {
+        Some(time) => println!(
+            "🕒 Son senkronizasyon: {} ({})",
+            time.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            ago((Local::now().fixed_offset() - time).num_seconds())
+        ),
+        None => println!("🕒 Bu kasada henüz senkronizasyon yapılmamış"),
+    }
+    println!("📝 {} not izleniyor", state.notes.len());
+
+    if state.failed.is_empty() {
+        println!("✅ Son senkronizasyonda hata yok");
+    } else {
+        println!("❌ Son senkronizasyonda yazılamayan {} iş:", state.failed.len());
+        for (key, error) in &state.failed {
+            println!("   {}: {}", key, error);
+        }
+    }
+
+    let pending = push::collect(config, &state)?;
+    if pending.is_empty() {
+        println!("✨ Gönderilmeyi bekleyen yerel değişiklik yok");
+    } else {
+        println!("📤 `push` ile gönderilecek {} yerel değişiklik:", pending.len());
+        for mutation in &pending {
+            println!("   {}", mutation.describe());
+        }
+    }
+    let queue = RetryQueue::load(&config.vault_path)?;
+    if !queue.is_empty() {
+        println!("⏳ Yeniden denenmek üzere kuyrukta {} değişiklik (`queue list`)", queue.items.len());
+    }
+    Ok(())
+}
+
+fn ago(seconds: i64) -> String {
+    match seconds {
+        ..=59 => "az önce".to_string(),
+        60..=3599 => format!("{} dakika önce", seconds / 60),
+        3600..=86399 => format!("{} saat önce", seconds / 3600),
+        _ => format!("{} gün önce", seconds / 86400),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_status_ago() {
+    assert_eq!(ago(12), "az önce");
+    assert_eq!(ago(5 * 60 + 30), "5 dakika önce");
+    assert_eq!(ago(3 * 3600), "3 saat önce");
+    assert_eq!(ago(2 * 86400 + 10), "2 gün önce");
+
+    let state: State = serde_json::from_str(r#"{ "notes": {} }"#).unwrap();
+    assert!(state.last_sync.is_none() && state.failed.is_empty());
+}
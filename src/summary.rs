@@ -0,0 +1,110 @@
+use std::error::Error;
+use std::process;
+use serde::Serialize;
+use crate::cli::OutputFormat;
+
+/// Exit code of a `--cron` run that wrote changes.
+pub const CHANGED_EXIT_CODE: i32 = 3;
+
+/// What a sync did; printed as JSON with `--output json`.
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    /// Issues Jira returned.
+    pub fetched: usize,
+    /// Note paths, relative to their vault.
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: Vec<String>,
+    /// Keys a script chose not to write.
+    pub skipped: Vec<String>,
+    /// Keys Jira returned only in part; their notes may be stubs.
+    pub restricted: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl Summary {
+    /// Adds another run's results, e.g. an extra vault or instance.
+    pub fn merge(&mut self, other: Summary) {
+        self.fetched += other.fetched;
+        self.created.extend(other.created);
+        self.updated.extend(other.updated);
+        self.unchanged.extend(other.unchanged);
+        self.skipped.extend(other.skipped);
+        self.restricted.extend(other.restricted);
+        self.errors.extend(other.errors);
+    }
+
+    /// Whether any note was created or rewritten.
+    pub fn changed(&self) -> bool {
+        !self.created.is_empty() || !self.updated.is_empty()
+    }
+
+    /// Sorts a written note into created, updated or unchanged.
+    pub fn record(&mut self, path: &str, before: Option<&str>, after: &str) {
+        match before {
+            None => self.created.push(path.to_string()),
+            Some(before) if before == after => self.unchanged.push(path.to_string()),
+            Some(_) => self.updated.push(path.to_string()),
+        }
+    }
+}
+
+/// Prints the summary in the requested format and passes the outcome on, so
+/// a failed sync still exits non-zero after its JSON has been printed; so
+/// does one that couldn't write some notes. With `cron`, the changed notes
+/// are listed and a run that changed anything (without errors) exits with
+/// [`CHANGED_EXIT_CODE`].
+pub fn emit(format: OutputFormat, cron: bool, result: Result<Summary, Box<dyn Error>>) -> Result<(), Box<dyn Error>> {
+    if format == OutputFormat::Json {
+        let summary = match &result {
+            Ok(summary) => serde_json::to_string(summary)?,
+            Err(e) => serde_json::to_string(&Summary { errors: vec![e.to_string()], ..Default::default() })?,
+        };
+        println!("{}", summary);
+    }
+    let summary = result?;
+    if cron && format == OutputFormat::Text {
+        for path in &summary.created {
+            println!("➕ {}", path);
+        }
+        for path in &summary.updated {
+            println!("✏️  {}", path);
+        }
+    }
+    if !summary.errors.is_empty() {
+        return Err(format!("{} iş yazılamadı: {}", summary.errors.len(), summary.errors.join("; ")).into());
+    }
+    if cron && summary.changed() {
+        process::exit(CHANGED_EXIT_CODE);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn test_summary() {
+    let mut summary = Summary { fetched: 2, ..Default::default() };
+    summary.record("A/A-1.md", None, "new");
+    summary.record("A/A-2.md", Some("old"), "new");
+    let mut other = Summary { fetched: 1, ..Default::default() };
+    other.record("B/B-1.md", Some("same"), "same");
+    other.skipped.push("B-2".into());
+    other.restricted.push("B-3".into());
+    assert!(!other.changed());
+    summary.merge(other);
+    assert!(summary.changed());
+
+    let json: serde_json::Value = serde_json::to_value(&summary).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "fetched": 3,
+            "created": ["A/A-1.md"],
+            "updated": ["A/A-2.md"],
+            "unchanged": ["B/B-1.md"],
+            "skipped": ["B-2"],
+            "restricted": ["B-3"],
+            "errors": []
+        })
+    );
+}
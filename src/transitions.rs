@@ -0,0 +1,194 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fs;
+use serde::{Deserialize, Serialize};
+use crate::jira::JiraClient;
+use crate::state::State;
+
+const CACHE_FILE: &str = "transitions.json";
+/// Longest workflow path we are willing to walk for one status change.
+const MAX_STEPS: usize = 6;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Transition {
+    pub id: String,
+    pub name: String,
+    /// Status the transition leads to.
+    pub to: String,
+}
+
+/// Transitions seen per project and source status, kept in the state folder so
+/// multi-step paths can be planned without asking Jira for every hop.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct TransitionCache {
+    #[serde(default)]
+    projects: BTreeMap<String, BTreeMap<String, Vec<Transition>>>,
+}
+
+impl TransitionCache {
+    pub fn load(vault_path: &str) -> Result<TransitionCache, Box<dyn Error>> {
+        let path = State::dir(vault_path).join(CACHE_FILE);
+        if !path.exists() {
+            return Ok(TransitionCache::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, vault_path: &str) -> Result<(), Box<dyn Error>> {
+        let dir = State::dir(vault_path);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(CACHE_FILE), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn insert(&mut self, project: &str, from: &str, transitions: Vec<Transition>) {
+        self.projects.entry(project.to_string()).or_default().insert(from.to_lowercase(), transitions);
+    }
+}
+
+#[derive(Deserialize)]
+struct TransitionsResponse {
+    transitions: Vec<RawTransition>,
+}
+
+#[derive(Deserialize)]
+struct RawTransition {
+    id: String,
+    name: String,
+    to: StatusRef,
+}
+
+#[derive(Deserialize)]
+struct StatusRef {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct IssueStatus {
+    fields: StatusFields,
+}
+
+#[derive(Deserialize)]
+struct StatusFields {
+    status: StatusRef,
+}
+
+/// Moves `key` to the status named `target`, chaining transitions through
+/// intermediate statuses when the workflow has no direct edge. `mapping` maps
+/// target status names to the transition name that should be used to reach them.
+/// Returns the names of the transitions executed.
+pub async fn transition_to(
+    client: &JiraClient,
+    cache: &mut TransitionCache,
+    mapping: &HashMap<String, String>,
+    key: &str,
+    target: &str,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut executed = Vec::new();
+    for _ in 0..MAX_STEPS {
+        let (current, path) = preview(client, cache, mapping, key, target).await?;
+        let step = match path {
+            Some(path) if path.is_empty() => return Ok(executed),
+            Some(path) => path[0].clone(),
+            None => {
+                return Err(format!("{}: '{}' durumundan '{}' durumuna geçiş yolu bulunamadı", key, current, target).into())
+            }
+        };
+        client
+            .post_json(
+                &format!("/rest/api/3/issue/{}/transitions", key),
+                &serde_json::json!({ "transition": { "id": step.id } }),
+            )
+            .await?;
+        executed.push(step.name);
+    }
+
+    Err(format!("{}: '{}' durumuna {} adımda ulaşılamadı", key, target, MAX_STEPS).into())
+}
+
+/// Read-only: the issue's current status in Jira and the planned chain of
+/// transitions to `target` (empty when already there, `None` when no known path).
+/// Refreshes the cache with the transitions available from the current status.
+pub async fn preview(
+    client: &JiraClient,
+    cache: &mut TransitionCache,
+    mapping: &HashMap<String, String>,
+    key: &str,
+    target: &str,
+) -> Result<(String, Option<Vec<Transition>>), Box<dyn Error>> {
+    let project = key.split_once('-').map(|(p, _)| p).unwrap_or(key);
+    let issue: IssueStatus = client.get_json(&format!("/rest/api/3/issue/{}?fields=status", key)).await?;
+    let current = issue.fields.status.name;
+    if current.eq_ignore_ascii_case(target) {
+        return Ok((current, Some(Vec::new())));
+    }
+
+    let available: TransitionsResponse = client.get_json(&format!("/rest/api/3/issue/{}/transitions", key)).await?;
+    let available = available
+        .transitions
+        .into_iter()
+        .map(|t| Transition { id: t.id, name: t.name, to: t.to.name })
+        .collect();
+    cache.insert(project, &current, available);
+
+    let path = plan(&cache.projects[project], mapping, &current, target);
+    Ok((current, path))
+}
+
+/// Shortest chain of known transitions from `from` to `target`. When a mapping
+/// names the transition for a status, edges with that name are preferred.
+pub fn plan(
+    graph: &BTreeMap<String, Vec<Transition>>,
+    mapping: &HashMap<String, String>,
+    from: &str,
+    target: &str,
+) -> Option<Vec<Transition>> {
+    let preferred = |t: &Transition| {
+        mapping
+            .iter()
+            .any(|(status, name)| status.eq_ignore_ascii_case(&t.to) && name.eq_ignore_ascii_case(&t.name))
+    };
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(from.to_lowercase());
+    queue.push_back((from.to_lowercase(), Vec::<Transition>::new()));
+
+    while let Some((status, path)) = queue.pop_front() {
+        let mut edges: Vec<&Transition> = graph.get(&status).map(|e| e.iter().collect()).unwrap_or_default();
+        edges.sort_by_key(|t| !preferred(t));
+        for edge in edges {
+            let mut next_path = path.clone();
+            next_path.push(edge.clone());
+            if edge.to.eq_ignore_ascii_case(target) {
+                return Some(next_path);
+            }
+            if seen.insert(edge.to.to_lowercase()) {
+                queue.push_back((edge.to.to_lowercase(), next_path));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[test]
+fn test_plan_chains_and_prefers_mapping() {
+    let t = |id: &str, name: &str, to: &str| Transition { id: id.into(), name: name.into(), to: to.into() };
+    let mut graph = BTreeMap::new();
+    graph.insert("to do".to_string(), vec![t("11", "Start", "In Progress")]);
+    graph.insert(
+        "in progress".to_string(),
+        vec![t("21", "Quick review", "In Review"), t("22", "Submit for review", "In Review")],
+    );
+
+    let path = plan(&graph, &HashMap::new(), "To Do", "In Review").unwrap();
+    let ids: Vec<&str> = path.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["11", "21"]);
+
+    let mut mapping = HashMap::new();
+    mapping.insert("In Review".to_string(), "Submit for review".to_string());
+    let path = plan(&graph, &mapping, "To Do", "In Review").unwrap();
+    assert_eq!(path[1].id, "22");
+
+    assert!(plan(&graph, &mapping, "To Do", "Done").is_none());
+}
@@ -0,0 +1,109 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use reqwest::Client;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/rvoidex7/jira-obsidian-sync/releases/latest";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<Asset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// Release asset name for this platform, e.g. `jira_obsidian_sync-linux-x86_64`.
+/// Each binary is published next to a `<name>.sha256` checksum file.
+fn asset_name() -> String {
+    let ext = if cfg!(windows) { ".exe" } else { "" };
+    format!("jira_obsidian_sync-{}-{}{}", env::consts::OS, env::consts::ARCH, ext)
+}
+
+/// Checks the latest GitHub release and replaces the running binary if it is newer.
+pub async fn run(check_only: bool) -> Result<(), Box<dyn Error>> {
+    let client = Client::builder()
+        .user_agent(concat!("jira_obsidian_sync/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+
+    let release: Release = client.get(RELEASES_URL).send().await?.error_for_status()?.json().await?;
+    let current = env!("CARGO_PKG_VERSION");
+    let latest = release.tag_name.trim_start_matches('v');
+
+    if !is_newer(latest, current) {
+        info!("✅ Zaten güncel: v{}", current);
+        return Ok(());
+    }
+    info!("⬆️  Yeni sürüm var: v{} -> v{}", current, latest);
+    if check_only {
+        return Ok(());
+    }
+
+    let name = asset_name();
+    let find = |asset_name: &str| {
+        release.assets.iter().find(|a| a.name == asset_name).map(|a| a.browser_download_url.clone())
+    };
+    let binary_url = find(&name).ok_or(format!("Bu platform için paket yok: {}", name))?;
+    let checksum_url = find(&format!("{}.sha256", name)).ok_or(format!("Sağlama dosyası yok: {}.sha256", name))?;
+
+    let binary = client.get(&binary_url).send().await?.error_for_status()?.bytes().await?;
+    let checksum_file = client.get(&checksum_url).send().await?.error_for_status()?.text().await?;
+
+    // `sha256sum` formatı: "<hex>  <dosya>"
+    let expected = checksum_file.split_whitespace().next().unwrap_or_default().to_lowercase();
+    let actual: String = Sha256::digest(&binary).iter().map(|b| format!("{:02x}", b)).collect();
+    if expected != actual {
+        return Err(format!("Sağlama toplamı uyuşmuyor (beklenen {}, gelen {})", expected, actual).into());
+    }
+
+    replace_current_exe(&binary)?;
+    info!("🏁 v{} kuruldu.", latest);
+    Ok(())
+}
+
+/// Swaps the running executable for `binary`. The old file is renamed out of the
+/// way first, which also works on Windows where a running exe can't be overwritten.
+fn replace_current_exe(binary: &[u8]) -> Result<(), Box<dyn Error>> {
+    let exe = env::current_exe()?;
+    let new_path = exe.with_extension("new");
+    let old_path = exe.with_extension("old");
+
+    fs::write(&new_path, binary)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&new_path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    let _ = fs::remove_file(&old_path);
+    fs::rename(&exe, &old_path)?;
+    if let Err(e) = fs::rename(&new_path, &exe) {
+        fs::rename(&old_path, &exe)?;
+        return Err(e.into());
+    }
+    let _ = fs::remove_file(&old_path);
+    Ok(())
+}
+
+fn is_newer(latest: &str, current: &str) -> bool {
+    fn parse(v: &str) -> Vec<u64> {
+        v.split(['.', '-']).take(3).map(|p| p.parse().unwrap_or(0)).collect()
+    }
+    parse(latest) > parse(current)
+}
+
+#[cfg(test)]
+#[test]
+fn test_is_newer() {
+    assert!(is_newer("0.2.0", "0.1.0"));
+    assert!(is_newer("0.10.0", "0.9.3"));
+    assert!(!is_newer("0.1.0", "0.1.0"));
+    assert!(!is_newer("0.0.9", "0.1.0"));
+}
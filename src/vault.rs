@@ -0,0 +1,192 @@
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use crate::config::Config;
+use tracing::{debug, warn};
+
+/// Writes files into the vault in a way that plays well with Obsidian Sync,
+/// iCloud, Dropbox or Syncthing touching the same files.
+pub struct VaultWriter {
+    retries: u32,
+    delay: Duration,
+    dry_run: bool,
+}
+
+impl VaultWriter {
+    pub fn new(config: &Config) -> VaultWriter {
+        VaultWriter {
+            retries: config.write_retries.unwrap_or(3),
+            delay: Duration::from_millis(config.write_retry_delay_ms.unwrap_or(500)),
+            dry_run: config.dry_run,
+        }
+    }
+
+    /// Writes `content` via a temp file + fsync + rename, then reads it back.
+    /// Retries when the file is locked by another process or the check fails.
+    /// With `--dry-run`, prints what would change instead.
+    pub fn write(&self, path: &Path, content: &str) -> io::Result<()> {
+        if self.dry_run {
+            print!("{}", preview(path, fs::read_to_string(path).ok().as_deref(), content));
+            return Ok(());
+        }
+        let mut attempt = 0;
+        loop {
+            match write_verified(path, content) {
+                Ok(()) => {
+                    debug!(path = %path.display(), bytes = content.len(), attempts = attempt + 1, "yazıldı");
+                    return Ok(());
+                }
+                Err(e) if attempt < self.retries => {
+                    attempt += 1;
+                    warn!("⏳ {} yazılamadı ({}), tekrar deneniyor {}/{}", path.display(), e, attempt, self.retries);
+                    thread::sleep(self.delay);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Whether `path` would be created, updated or left alone, with a unified
+/// diff for updates.
+fn preview(path: &Path, existing: Option<&str>, content: &str) -> String {
+    let name = path.display().to_string();
+    match existing {
+        None => format!("➕ {} oluşturulacak\n", name),
+        Some(old) if old == content => format!("⏸️  {} değişmeyecek\n", name),
+        Some(old) => {
+            let diff = similar::TextDiff::from_lines(old, content);
+            format!("✏️  {} güncellenecek\n{}", name, diff.unified_diff().context_radius(2).header(&name, &name))
+        }
+    }
+}
+
+fn write_verified(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    // Nokta ile başlayan dosyaları Obsidian ve çoğu senkron aracı görmezden gelir.
+    let tmp = path.with_file_name(format!(".{}.tmp", file_name));
+
+    let result = (|| {
+        let mut file = File::create(&tmp)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result?;
+
+    #[cfg(unix)]
+    if let Some(parent) = path.parent() {
+        // Yeniden adlandırmanın da diske inmesi için klasörü de fsync'le.
+        let _ = File::open(parent).and_then(|dir| dir.sync_all());
+    }
+
+    if fs::read(path)? != content.as_bytes() {
+        return Err(io::Error::other("yazılan içerik geri okunduğunda farklı çıktı"));
+    }
+    Ok(())
+}
+
+/// Every Markdown file in the vault, skipping hidden folders such as
+/// `.obsidian` and `.jira-sync`.
+pub fn markdown_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if path.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.')) {
+            continue;
+        }
+        if path.is_dir() {
+            files.extend(markdown_files(&path));
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Temp files `write` left behind when the process died mid-write
+/// (`.PROJ-1.md.tmp`), anywhere outside hidden folders.
+pub fn leftover_temp_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') {
+                files.extend(leftover_temp_files(&path));
+            }
+        } else if name.starts_with('.') && name.ends_with(".md.tmp") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Conflict copies that sync tools leave next to `path`, e.g.
+/// `PROJ-1 (conflicted copy 2024-05-01).md`, `PROJ-1.sync-conflict-20240501-1234.md`
+/// or iCloud's `PROJ-1 2.md`.
+pub fn conflict_siblings(path: &Path) -> Vec<PathBuf> {
+    let (Some(dir), Some(stem)) = (path.parent(), path.file_stem().map(|s| s.to_string_lossy().into_owned())) else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut conflicts: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p != path && p.extension().is_some_and(|ext| ext == "md"))
+        .filter(|p| {
+            let other = p.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+            is_conflict_name(&stem, &other)
+        })
+        .collect();
+    conflicts.sort();
+    conflicts
+}
+
+fn is_conflict_name(stem: &str, other: &str) -> bool {
+    let Some(rest) = other.strip_prefix(stem) else {
+        return false;
+    };
+    if rest.to_lowercase().contains("conflict") {
+        return true;
+    }
+    // iCloud: "Note 2", "Note 3"
+    rest.strip_prefix(' ').is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+#[test]
+fn test_conflict_names() {
+    assert!(is_conflict_name("PROJ-1", "PROJ-1 (conflicted copy 2024-05-01)"));
+    assert!(is_conflict_name("PROJ-1", "PROJ-1.sync-conflict-20240501-123456-ABCDEF"));
+    assert!(is_conflict_name("PROJ-1", "PROJ-1 2"));
+    assert!(!is_conflict_name("PROJ-1", "PROJ-12"));
+    assert!(!is_conflict_name("PROJ-1", "PROJ-1 Login bug"));
+}
+
+#[cfg(test)]
+#[test]
+fn test_dry_run_preview() {
+    let path = Path::new("A-1.md");
+    assert_eq!(preview(path, None, "new\n"), "➕ A-1.md oluşturulacak\n");
+    assert_eq!(preview(path, Some("same\n"), "same\n"), "⏸️  A-1.md değişmeyecek\n");
+    let updated = preview(path, Some("jira_status: To Do\nbody\n"), "jira_status: Done\nbody\n");
+    assert!(updated.contains("-jira_status: To Do\n+jira_status: Done\n body\n"));
+}
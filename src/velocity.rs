@@ -0,0 +1,159 @@
+use std::error::Error;
+use std::path::Path;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::i18n::Labels;
+use crate::jira::JiraClient;
+use crate::NoteContext;
+use tracing::info;
+
+/// `[velocity]` in the config file. Nothing is generated without `board_id`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct VelocityConfig {
+    /// Agile board whose closed sprints are compared.
+    pub board_id: Option<u64>,
+    /// How many recent sprints to show (default 6).
+    pub sprints: Option<usize>,
+    /// Note file in the vault (default "Sprint Velocity.md").
+    pub file: Option<String>,
+    /// Story points field; filled from `capabilities` when unset.
+    pub points_field: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SprintPage {
+    #[serde(default)]
+    values: Vec<Sprint>,
+    #[serde(rename = "isLast", default = "yes")]
+    is_last: bool,
+}
+
+fn yes() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct Sprint {
+    id: u64,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssuePage {
+    #[serde(default)]
+    issues: Vec<Value>,
+    #[serde(default)]
+    total: usize,
+}
+
+/// Committed vs completed work of one sprint.
+#[derive(Debug, Default, PartialEq)]
+struct Row {
+    sprint: String,
+    committed_issues: usize,
+    completed_issues: usize,
+    committed_points: f64,
+    completed_points: f64,
+}
+
+/// Regenerates the velocity note from the board's last closed sprints.
+pub async fn write(ctx: &NoteContext<'_>, client: &JiraClient) -> Result<(), Box<dyn Error>> {
+    let settings = &ctx.config.velocity;
+    let Some(board) = settings.board_id else { return Ok(()) };
+
+    let mut sprints = Vec::new();
+    let mut start = 0;
+    loop {
+        let page: SprintPage = client
+            .get_json(&format!("/rest/agile/1.0/board/{}/sprint?state=closed&startAt={}&maxResults=50", board, start))
+            .await?;
+        start += page.values.len();
+        let done = page.is_last || page.values.is_empty();
+        sprints.extend(page.values);
+        if done {
+            break;
+        }
+    }
+    let keep = settings.sprints.unwrap_or(6);
+    let recent = &sprints[sprints.len().saturating_sub(keep)..];
+
+    let mut rows = Vec::new();
+    for sprint in recent {
+        rows.push(sprint_row(client, board, sprint, settings.points_field.as_deref()).await?);
+    }
+
+    let file = settings.file.clone().unwrap_or_else(|| "Sprint Velocity.md".to_string());
+    ctx.writer.write(&Path::new(&ctx.config.vault_path).join(&file), &render(&ctx.labels, &rows))?;
+    info!("📈 Sprint hızı notu güncellendi: {}", file);
+    Ok(())
+}
+
+async fn sprint_row(client: &JiraClient, board: u64, sprint: &Sprint, points_field: Option<&str>) -> Result<Row, Box<dyn Error>> {
+    let fields = format!("status{}", points_field.map(|f| format!(",{}", f)).unwrap_or_default());
+    let mut row = Row { sprint: sprint.name.clone(), ..Default::default() };
+    let mut start = 0;
+    loop {
+        let page: IssuePage = client
+            .get_json(&format!(
+                "/rest/agile/1.0/board/{}/sprint/{}/issue?fields={}&startAt={}&maxResults=100",
+                board, sprint.id, fields, start
+            ))
+            .await?;
+        for issue in &page.issues {
+            let points = points_field.and_then(|f| issue["fields"][f].as_f64()).unwrap_or(0.0);
+            let done = issue["fields"]["status"]["statusCategory"]["key"] == "done";
+            row.committed_issues += 1;
+            row.committed_points += points;
+            if done {
+                row.completed_issues += 1;
+                row.completed_points += points;
+            }
+        }
+        start += page.issues.len();
+        if page.issues.is_empty() || start >= page.total {
+            return Ok(row);
+        }
+    }
+}
+
+fn render(labels: &Labels, rows: &[Row]) -> String {
+    let mut note = format!("---\ntags: [jira, velocity]\n---\n# {}\n\n", labels.velocity);
+    note.push_str(&format!(
+        "| Sprint | {c} | {d} | {c} (pt) | {d} (pt) |\n|---|---|---|---|---|\n",
+        c = labels.committed,
+        d = labels.completed
+    ));
+    for row in rows {
+        note.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            row.sprint, row.committed_issues, row.completed_issues, row.committed_points, row.completed_points
+        ));
+    }
+
+    let names: Vec<String> = rows.iter().map(|r| format!("\"{}\"", r.sprint.replace('"', "'"))).collect();
+    let committed: Vec<String> = rows.iter().map(|r| r.committed_points.to_string()).collect();
+    let completed: Vec<String> = rows.iter().map(|r| r.completed_points.to_string()).collect();
+    note.push_str(&format!(
+        "\n```mermaid\nxychart-beta\n    title \"{}\"\n    x-axis [{}]\n    y-axis \"pt\"\n    bar [{}]\n    line [{}]\n```\n",
+        labels.velocity,
+        names.join(", "),
+        committed.join(", "),
+        completed.join(", ")
+    ));
+    note
+}
+
+#[cfg(test)]
+#[test]
+fn test_render_velocity() {
+    let rows = vec![
+        Row { sprint: "S1".into(), committed_issues: 5, completed_issues: 4, committed_points: 13.0, completed_points: 8.0 },
+        Row { sprint: "S2".into(), committed_issues: 6, completed_issues: 6, committed_points: 15.0, completed_points: 15.0 },
+    ];
+    let note = render(&Labels::for_locale("en"), &rows);
+    assert!(note.contains("| Sprint | Committed | Completed | Committed (pt) | Completed (pt) |"));
+    assert!(note.contains("| S1 | 5 | 4 | 13 | 8 |"));
+    assert!(note.contains("x-axis [\"S1\", \"S2\"]"));
+    assert!(note.contains("bar [13, 15]\n    line [8, 15]"));
+}
@@ -0,0 +1,180 @@
+//! WASM plugins, available when built with `--features wasm-plugins`.
+//!
+//! A plugin module exports `memory` and `alloc(len: i32) -> i32`, plus any of
+//! these hooks, each taking `(ptr: i32, len: i32)` of UTF-8 input and returning
+//! an `i64` packed as `(ptr << 32) | len` (`0` leaves the input unchanged):
+//!
+//! - `transform_issue`: issue JSON as returned by Jira -> issue JSON
+//! - `render_adf_node`: one ADF node as JSON -> Markdown
+//! - `post_process_note`: the finished note -> the note to write
+//!
+//! Plugins run in order; a failing hook is reported and skipped.
+
+use std::error::Error;
+use serde_json::Value;
+use tracing::warn;
+
+pub struct WasmPlugins {
+    #[cfg(feature = "wasm-plugins")]
+    plugins: Vec<std::sync::Mutex<engine::Plugin>>,
+}
+
+impl WasmPlugins {
+    #[cfg(feature = "wasm-plugins")]
+    pub fn load(paths: &[String]) -> Result<WasmPlugins, Box<dyn Error>> {
+        let engine = wasmtime::Engine::default();
+        let mut plugins = Vec::new();
+        for path in paths {
+            let plugin = engine::Plugin::load(&engine, path).map_err(|e| format!("{}: {}", path, e))?;
+            plugins.push(std::sync::Mutex::new(plugin));
+        }
+        Ok(WasmPlugins { plugins })
+    }
+
+    #[cfg(not(feature = "wasm-plugins"))]
+    pub fn load(paths: &[String]) -> Result<WasmPlugins, Box<dyn Error>> {
+        if !paths.is_empty() {
+            warn!("⚠️  WASM eklentileri yok sayıldı: program `wasm-plugins` özelliği olmadan derlenmiş");
+        }
+        Ok(WasmPlugins {})
+    }
+
+    pub fn transform_issue(&self, issue: Value) -> Value {
+        if self.is_empty() {
+            return issue;
+        }
+        let output = self.chain("transform_issue", issue.to_string());
+        serde_json::from_str(&output).unwrap_or_else(|e| {
+            warn!("⚠️  transform_issue geçersiz JSON döndürdü: {}", e);
+            issue
+        })
+    }
+
+    /// First plugin that renders the node wins.
+    pub fn render_adf_node(&self, node: &Value) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        self.first("render_adf_node", &node.to_string())
+    }
+
+    pub fn post_process_note(&self, note: String) -> String {
+        self.chain("post_process_note", note)
+    }
+
+    #[cfg(feature = "wasm-plugins")]
+    fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    #[cfg(not(feature = "wasm-plugins"))]
+    fn is_empty(&self) -> bool {
+        true
+    }
+
+    /// Passes `input` through every plugin implementing `hook`, each one
+    /// seeing the previous result.
+    #[cfg(feature = "wasm-plugins")]
+    fn chain(&self, hook: &str, input: String) -> String {
+        let mut current = input;
+        for plugin in &self.plugins {
+            let mut plugin = plugin.lock().unwrap();
+            match plugin.call(hook, current.as_bytes()) {
+                Ok(Some(output)) => current = String::from_utf8_lossy(&output).into_owned(),
+                Ok(None) => {}
+                Err(e) => warn!("⚠️  {} / {}: {}", plugin.name, hook, e),
+            }
+        }
+        current
+    }
+
+    #[cfg(not(feature = "wasm-plugins"))]
+    fn chain(&self, _hook: &str, input: String) -> String {
+        input
+    }
+
+    #[cfg(feature = "wasm-plugins")]
+    fn first(&self, hook: &str, input: &str) -> Option<String> {
+        for plugin in &self.plugins {
+            let mut plugin = plugin.lock().unwrap();
+            match plugin.call(hook, input.as_bytes()) {
+                Ok(Some(output)) => return Some(String::from_utf8_lossy(&output).into_owned()),
+                Ok(None) => {}
+                Err(e) => warn!("⚠️  {} / {}: {}", plugin.name, hook, e),
+            }
+        }
+        None
+    }
+
+    #[cfg(not(feature = "wasm-plugins"))]
+    fn first(&self, _hook: &str, _input: &str) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(feature = "wasm-plugins")]
+mod engine {
+    use wasmtime::{Engine, Instance, Memory, Module, Store, TypedFunc};
+
+    pub struct Plugin {
+        pub name: String,
+        store: Store<()>,
+        instance: Instance,
+        memory: Memory,
+        alloc: TypedFunc<i32, i32>,
+    }
+
+    impl Plugin {
+        pub fn load(engine: &Engine, path: &str) -> wasmtime::Result<Plugin> {
+            let module = Module::from_file(engine, path)?;
+            let mut store = Store::new(engine, ());
+            let instance = Instance::new(&mut store, &module, &[])?;
+            let memory = instance
+                .get_memory(&mut store, "memory")
+                .ok_or_else(|| wasmtime::Error::msg("`memory` dışa aktarılmamış"))?;
+            let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+            Ok(Plugin { name: path.to_string(), store, instance, memory, alloc })
+        }
+
+        /// `Ok(None)` when the plugin doesn't implement the hook or returned 0.
+        pub fn call(&mut self, hook: &str, input: &[u8]) -> wasmtime::Result<Option<Vec<u8>>> {
+            if self.instance.get_export(&mut self.store, hook).is_none() {
+                return Ok(None);
+            }
+            let func = self.instance.get_typed_func::<(i32, i32), i64>(&mut self.store, hook)?;
+
+            let ptr = self.alloc.call(&mut self.store, input.len() as i32)?;
+            self.memory.write(&mut self.store, ptr as usize, input)?;
+            let packed = func.call(&mut self.store, (ptr, input.len() as i32))?;
+            if packed == 0 {
+                return Ok(None);
+            }
+
+            let out_ptr = (packed >> 32) as u32 as usize;
+            let out_len = packed as u32 as usize;
+            let mut output = vec![0; out_len];
+            self.memory.read(&self.store, out_ptr, &mut output)?;
+            Ok(Some(output))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "wasm-plugins"))]
+#[test]
+fn test_post_process_hook() {
+    let wat = r#"(module
+        (memory (export "memory") 1)
+        (data (i32.const 1024) "rewritten")
+        (func (export "alloc") (param i32) (result i32) i32.const 2048)
+        (func (export "post_process_note") (param i32 i32) (result i64)
+            i64.const 4398046511113))"#; // (1024 << 32) | 9
+    // Aynı anda çalışan test süreçleri birbirinin dosyasını ezmesin.
+    let path = std::env::temp_dir().join(format!("jira_sync_test_plugin_{}.wat", std::process::id()));
+    std::fs::write(&path, wat).unwrap();
+
+    let plugins = WasmPlugins::load(&[path.to_string_lossy().into_owned()]);
+    std::fs::remove_file(&path).unwrap();
+    let plugins = plugins.unwrap();
+    assert_eq!(plugins.post_process_note("original".to_string()), "rewritten");
+    assert_eq!(plugins.render_adf_node(&serde_json::json!({"type": "panel"})), None);
+}
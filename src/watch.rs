@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{Event, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use crate::config::Config;
+use crate::push;
+use tracing::{error, info};
+
+/// Never worth a push: Obsidian's own settings and our bookkeeping.
+const ALWAYS_IGNORED: [&str; 2] = [".obsidian/**", ".jira-sync/**"];
+
+/// Decides which changed files should trigger a push.
+pub struct WatchFilter {
+    ignore: GlobSet,
+}
+
+impl WatchFilter {
+    pub fn new(patterns: &[String]) -> Result<WatchFilter, Box<dyn Error>> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in ALWAYS_IGNORED.iter().copied().chain(patterns.iter().map(String::as_str)) {
+            builder.add(Glob::new(pattern).map_err(|e| format!("Geçersiz yoksayma kalıbı '{}': {}", pattern, e))?);
+        }
+        Ok(WatchFilter { ignore: builder.build()? })
+    }
+
+    /// `rel_path` is relative to the vault.
+    pub fn is_relevant(&self, rel_path: &Path) -> bool {
+        let is_note = rel_path.extension().is_some_and(|ext| ext == "md");
+        // Geçici dosyalarımız (.PROJ-1.md.tmp) ve gizli dosyalar
+        let hidden = rel_path.components().any(|c| c.as_os_str().to_string_lossy().starts_with('.'));
+        is_note && !hidden && !self.ignore.is_match(rel_path)
+    }
+}
+
+/// Watches the vault and pushes once edits have settled: changes are collected
+/// until nothing happened for the debounce window, so a burst of saves while
+/// typing results in a single push.
+pub async fn run(config: &Config) -> Result<(), Box<dyn Error>> {
+    let vault = PathBuf::from(&config.vault_path);
+    let filter = WatchFilter::new(&config.watch_ignore)?;
+    let debounce = Duration::from_millis(config.watch_debounce_ms.unwrap_or(2000));
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        if let Ok(event) = event {
+            for path in event.paths {
+                let _ = tx.send(path);
+            }
+        }
+    })?;
+    watcher.watch(&vault, RecursiveMode::Recursive)?;
+    info!("👀 Kasa izleniyor: {} (Ctrl+C ile çıkın)", vault.display());
+
+    let relevant = |path: &Path| path.strip_prefix(&vault).is_ok_and(|rel| filter.is_relevant(rel));
+    while let Some(path) = rx.recv().await {
+        if !relevant(&path) {
+            continue;
+        }
+        let mut changed = HashSet::from([path]);
+        loop {
+            match tokio::time::timeout(debounce, rx.recv()).await {
+                Ok(Some(path)) => {
+                    if relevant(&path) {
+                        changed.insert(path);
+                    }
+                }
+                Ok(None) => return Ok(()),
+                Err(_) => break,
+            }
+        }
+
+        info!("💾 {} not değişti, gönderiliyor...", changed.len());
+        if let Err(e) = push::run(config, false, false).await {
+            error!("❌ Gönderim başarısız: {}", e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[test]
+fn test_watch_filter() {
+    let filter = WatchFilter::new(&["Templates/**".to_string()]).unwrap();
+    assert!(filter.is_relevant(Path::new("PROJ/PROJ-1.md")));
+    assert!(!filter.is_relevant(Path::new("Templates/Ticket.md")));
+    assert!(!filter.is_relevant(Path::new(".obsidian/workspace.json")));
+    assert!(!filter.is_relevant(Path::new("PROJ/.PROJ-1.md.tmp")));
+    assert!(!filter.is_relevant(Path::new("image.png")));
+}
@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use serde_json::Value;
+use crate::config::{Capability, Config};
+use crate::frontmatter;
+use crate::jira::JiraClient;
+use crate::people::Directory;
+use crate::state::State;
+use crate::vault::VaultWriter;
+use crate::Issue;
+use tracing::{info, warn};
+
+/// `watch` / `unwatch`: adds or removes the current user as a watcher of
+/// `key` and updates `jira_watching` in the issue's note right away.
+pub async fn set(config: &Config, key: &str, watching: bool) -> Result<(), Box<dyn Error>> {
+    if !config.allows(Capability::Edits) {
+        return Err("İzleyici değiştirmek Jira'ya yazmaktır: allow_edits (ya da allow_writes) = true gerekli".into());
+    }
+    let client = JiraClient::new(config)?;
+    let me: Value = client.get_json("/rest/api/3/myself").await?;
+    // Server/DC'de accountId yok; izleyiciler kullanıcı adıyla (name) yönetilir.
+    let (param, id) = match me["accountId"].as_str() {
+        Some(account_id) => ("accountId", account_id),
+        None => ("username", me["name"].as_str().ok_or("Hesap kimliği (accountId ya da name) okunamadı")?),
+    };
+
+    let path = format!("/rest/api/3/issue/{}/watchers", key);
+    if watching {
+        client.post_json(&path, &Value::String(id.to_string())).await?;
+        info!("👁️  {} izleniyor", key);
+    } else {
+        client.delete(&format!("{}?{}={}", path, param, id)).await?;
+        info!("🙈 {} artık izlenmiyor", key);
+    }
+
+    let state = State::load(&config.vault_path)?;
+    if let Some(note) = state.notes.get(key) {
+        let file = Path::new(&config.vault_path).join(&note.path);
+        let content = fs::read_to_string(&file)?;
+        VaultWriter::new(config).write(&file, &frontmatter::set(&content, "jira_watching", &watching.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Fills `watches.watchers` of every watched issue from `/issue/{key}/watchers`;
+/// searches only return the count. Failures only cost that issue's list.
+pub async fn resolve(client: &JiraClient, issues: impl IntoIterator<Item = &mut Value>) {
+    for issue in issues {
+        if issue["fields"]["watches"]["watchCount"].as_u64().unwrap_or(0) == 0 {
+            continue;
+        }
+        let key = issue["key"].as_str().unwrap_or_default().to_string();
+        match client.get_json::<Value>(&format!("/rest/api/3/issue/{}/watchers", key)).await {
+            Ok(watches) => issue["fields"]["watches"]["watchers"] = watches["watchers"].clone(),
+            Err(e) => warn!("⚠️  {} izleyicileri alınamadı: {}", key, e),
+        }
+    }
+}
+
+/// `jira_watching`, `jira_watchers` (count), `jira_watcher_names` when the
+/// list was fetched, and `jira_votes` / `jira_voted`.
+pub fn frontmatter(issue: &Issue, people: &Directory) -> String {
+    let mut lines = String::new();
+    if let Some(watches) = issue.fields.extra.get("watches") {
+        lines.push_str(&format!("jira_watching: {}\n", watches["isWatching"].as_bool().unwrap_or(false)));
+        lines.push_str(&format!("jira_watchers: {}\n", watches["watchCount"].as_u64().unwrap_or(0)));
+        if let Some(watchers) = watches["watchers"].as_array() {
+            let names: Vec<String> = watchers
+                .iter()
+                .filter_map(|w| w["accountId"].as_str().or(w["name"].as_str()))
+                .map(|id| format!("\"{}\"", people.name(id).replace('"', "'")))
+                .collect();
+            lines.push_str(&format!("jira_watcher_names: [{}]\n", names.join(", ")));
+        }
+    }
+    if let Some(votes) = issue.fields.extra.get("votes") {
+        lines.push_str(&format!("jira_votes: {}\n", votes["votes"].as_u64().unwrap_or(0)));
+        lines.push_str(&format!("jira_voted: {}\n", votes["hasVoted"].as_bool().unwrap_or(false)));
+    }
+    lines
+}
+
+#[cfg(test)]
+#[test]
+fn test_watchers_and_votes_frontmatter() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "watches": { "isWatching": true, "watchCount": 2, "watchers": [{ "accountId": "abc" }, { "name": "bora" }] },
+            "votes": { "votes": 5, "hasVoted": false }
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        frontmatter(&issue, &Directory::default()),
+        "jira_watching: true\njira_watchers: 2\njira_watcher_names: [\"abc\", \"bora\"]\njira_votes: 5\njira_voted: false\n"
+    );
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+use chrono::FixedOffset;
+use serde_json::Value;
+use crate::i18n::Labels;
+use crate::metrics::parse_time;
+use crate::people::Directory;
+use crate::Issue;
+
+/// The `## ⏱️` section of a ticket note: the time-tracking summary (original
+/// estimate, time spent, remaining) and the worklogs summed per day in
+/// `offset`, with who logged them. Empty when nothing is estimated or logged.
+pub fn section(issue: &Issue, labels: &Labels, people: &Directory, offset: FixedOffset) -> String {
+    let tracking = issue.fields.extra.get("timetracking").unwrap_or(&Value::Null);
+    let summary: Vec<String> = [
+        (&labels.original_estimate, "originalEstimate"),
+        (&labels.time_spent, "timeSpent"),
+        (&labels.remaining_estimate, "remainingEstimate"),
+    ]
+    .iter()
+    .filter_map(|(label, field)| Some(format!("**{}:** {}", label, tracking[*field].as_str()?)))
+    .collect();
+
+    // Gün -> (saniye, kişiler)
+    let mut days: BTreeMap<String, (u64, Vec<String>)> = BTreeMap::new();
+    let worklogs = issue.fields.extra.get("worklog").and_then(|w| w["worklogs"].as_array());
+    for worklog in worklogs.into_iter().flatten() {
+        let Some(started) = worklog["started"].as_str().and_then(parse_time) else { continue };
+        let day = days.entry(started.with_timezone(&offset).format("%Y-%m-%d").to_string()).or_default();
+        day.0 += worklog["timeSpentSeconds"].as_u64().unwrap_or(0);
+        let author = &worklog["author"];
+        let name = match author["accountId"].as_str().or(author["name"].as_str()) {
+            Some(id) => people.name(id),
+            None => author["displayName"].as_str().unwrap_or("?").to_string(),
+        };
+        if !day.1.contains(&name) {
+            day.1.push(name);
+        }
+    }
+
+    if summary.is_empty() && days.is_empty() {
+        return String::new();
+    }
+    let mut section = format!("\n## ⏱️ {}\n", labels.time_tracking);
+    if !summary.is_empty() {
+        section.push_str(&format!("> {}\n", summary.join(" | ")));
+    }
+    if !days.is_empty() {
+        section.push_str(&format!("\n| {} | {} | {} |\n|---|---|---|\n", labels.date, labels.time_spent, labels.people));
+        for (day, (seconds, names)) in &days {
+            section.push_str(&format!("| {} | {} | {} |\n", day, duration(*seconds), names.join(", ")));
+        }
+    }
+    section
+}
+
+/// `1h 30m`, `45m`; whole hours without minutes.
+fn duration(seconds: u64) -> String {
+    let (hours, minutes) = (seconds / 3600, seconds % 3600 / 60);
+    match (hours, minutes) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_time_tracking_section() {
+    let issue: Issue = serde_json::from_value(serde_json::json!({
+        "key": "A-1",
+        "fields": {
+            "summary": "s",
+            "status": { "name": "To Do" },
+            "timetracking": { "originalEstimate": "2d", "remainingEstimate": "1d 2h", "timeSpent": "6h" },
+            "worklog": { "total": 3, "worklogs": [
+                { "author": { "displayName": "Ayşe" }, "started": "2026-10-12T09:00:00.000+0300", "timeSpentSeconds": 7200 },
+                { "author": { "displayName": "Bora" }, "started": "2026-10-12T14:00:00.000+0300", "timeSpentSeconds": 5400 },
+                { "author": { "displayName": "Ayşe" }, "started": "2026-10-13T23:30:00.000+0000", "timeSpentSeconds": 9000 }
+            ] }
+        }
+    }))
+    .unwrap();
+    let offset = FixedOffset::east_opt(3 * 3600).unwrap();
+    assert_eq!(
+        section(&issue, &Labels::for_locale("en"), &Directory::default(), offset),
+        "\n## ⏱️ Time Tracking\n> **Original Estimate:** 2d | **Time Spent:** 6h | **Remaining:** 1d 2h\n\n\
+         | Date | Time Spent | People |\n|---|---|---|\n| 2026-10-12 | 3h 30m | Ayşe, Bora |\n| 2026-10-14 | 2h 30m | Ayşe |\n"
+    );
+    assert_eq!(duration(45 * 60), "45m");
+}